@@ -0,0 +1,6 @@
+//! VVMを扱うツール向けの実装。
+//!
+//! `vvm_tool`クレートのためのものであり、安定したAPIではない。VVMの検証には`vvm inspect`を使う
+//! こと。
+
+pub use crate::vvm_check::{inspect, VvmProblem, VvmReport};
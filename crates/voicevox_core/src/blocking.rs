@@ -1,6 +1,9 @@
 //! ブロッキング版API。
 
 pub use crate::{
-    engine::open_jtalk::blocking::OpenJtalk, synthesizer::blocking::Synthesizer,
-    user_dict::dict::blocking::UserDict, voice_model::blocking::VoiceModel,
+    engine::open_jtalk::blocking::OpenJtalk,
+    synthesizer::blocking::{PerformInference, Synthesizer},
+    user_dict::dict::blocking::UserDict,
+    voice_model::blocking::VoiceModel,
+    voice_model_set::blocking::VoiceModelSet,
 };
@@ -1,5 +1,6 @@
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
 
 use crate::{infer::InferenceRuntime, synthesizer::InferenceRuntimeImpl, Result};
 
@@ -51,6 +52,58 @@ impl SupportedDevices {
     }
 }
 
+/// CUDAのデバイス数を数える。
+///
+/// ONNX Runtimeからはデバイス数が得られないため、CUDAドライバAPIを直接呼ぶ。ドライバが見つからない
+/// 等で数えられなかった場合は`None`を返す。
+pub(crate) fn count_cuda_devices() -> Option<usize> {
+    return match count_cuda_devices() {
+        Ok(count) => {
+            info!("検出されたCUDAデバイスの数: {count}");
+            Some(count)
+        }
+        Err(err) => {
+            debug!("CUDAデバイスの数を得られませんでした: {err}");
+            None
+        }
+    };
+
+    fn count_cuda_devices() -> anyhow::Result<usize> {
+        use std::ffi::c_int;
+
+        use anyhow::ensure;
+        use libloading::{Library, Symbol};
+
+        const CUDA_SUCCESS: c_int = 0;
+
+        #[cfg(windows)]
+        const LIBCUDA: &str = "nvcuda.dll";
+        #[cfg(not(windows))]
+        const LIBCUDA: &str = "libcuda.so.1";
+
+        unsafe {
+            let libcuda = Library::new(LIBCUDA)?;
+            let cu_init: Symbol<'_, unsafe extern "C" fn(u32) -> c_int> =
+                libcuda.get(b"cuInit\0")?;
+            let cu_device_get_count: Symbol<'_, unsafe extern "C" fn(*mut c_int) -> c_int> =
+                libcuda.get(b"cuDeviceGetCount\0")?;
+
+            let code = cu_init(0);
+            ensure!(
+                code == CUDA_SUCCESS,
+                "`cuInit`が失敗しました (CUresult: {code})"
+            );
+            let mut count = 0;
+            let code = cu_device_get_count(&mut count);
+            ensure!(
+                code == CUDA_SUCCESS,
+                "`cuDeviceGetCount`が失敗しました (CUresult: {code})",
+            );
+            Ok(count.try_into()?)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -63,4 +116,10 @@ mod tests {
         // 環境によって結果が変わるので、関数呼び出しが成功するかどうかの確認のみ行う
         assert!(result.is_ok(), "{result:?}");
     }
+
+    #[rstest]
+    fn count_cuda_devices_does_not_panic() {
+        // 環境によって結果が変わるので、パニックしないことのみ確認する
+        super::count_cuda_devices();
+    }
 }
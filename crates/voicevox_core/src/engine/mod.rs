@@ -13,3 +13,6 @@ pub(crate) use self::kana_parser::{create_kana, parse_kana, KanaParseError};
 pub use self::model::{AccentPhraseModel, AudioQueryModel, MoraModel};
 pub(crate) use self::mora_list::mora2text;
 pub use self::open_jtalk::FullcontextExtractor;
+pub(crate) use self::open_jtalk::{
+    attach_worker as attach_open_jtalk_worker, spec_of as open_jtalk_spec_of, OpenJtalkSpec,
+};
@@ -1,4 +1,16 @@
+use std::{
+    any::Any,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
 use ::open_jtalk::Text2MecabError;
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::worker::Worker;
 
 #[derive(thiserror::Error, Debug)]
 #[error("`{function}`の実行が失敗しました")]
@@ -12,20 +24,96 @@ pub trait FullcontextExtractor: Clone + Send + Sync + 'static {
     fn extract_fullcontext(&self, text: &str) -> anyhow::Result<Vec<String>>;
 }
 
+/// ワーカープロセスの中で同じテキスト解析を行うための、Open JTalkの設定。
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub(crate) struct OpenJtalkSpec {
+    dict_dir: Utf8PathBuf,
+    /// `use_user_dict`で設定されたユーザー辞書。
+    user_dict: Option<UserDictSpec>,
+}
+
+/// `use_user_dict`で設定されたユーザー辞書。
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+struct UserDictSpec {
+    /// ユーザー辞書が設定されるたびに振られる、このプロセスの中で一意な番号。
+    generation: u64,
+    /// MeCabの形式での内容。
+    words: String,
+}
+
+impl OpenJtalkSpec {
+    #[cfg(test)]
+    pub(crate) fn new(dict_dir: Utf8PathBuf, user_dict: Option<String>) -> Self {
+        Self {
+            dict_dir,
+            user_dict: user_dict.and_then(|words| UserDictSpec::new(&words)),
+        }
+    }
+
+    pub(crate) fn dict_dir(&self) -> &Utf8Path {
+        &self.dict_dir
+    }
+
+    /// ユーザー辞書を識別する番号。ユーザー辞書が無ければ`0`。
+    ///
+    /// ワーカープロセスとのやりとりでは、ユーザー辞書の内容の代わりにこれを送る。
+    pub(crate) fn user_dict_generation(&self) -> u64 {
+        self.user_dict
+            .as_ref()
+            .map_or(0, |user_dict| user_dict.generation)
+    }
+}
+
+impl UserDictSpec {
+    /// 新しい番号を振る。`words`が空の場合はユーザー辞書が無いものとし、`None`を返す。
+    fn new(words: &str) -> Option<Self> {
+        static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
+
+        (!words.is_empty()).then(|| Self {
+            generation: NEXT_GENERATION.fetch_add(1, Ordering::Relaxed),
+            words: words.to_owned(),
+        })
+    }
+}
+
+/// `open_jtalk`がこのクレートのOpen JTalkであれば、その[`OpenJtalkSpec`]を返す。
+pub(crate) fn spec_of(open_jtalk: &impl FullcontextExtractor) -> Option<OpenJtalkSpec> {
+    let open_jtalk = open_jtalk as &dyn Any;
+    if let Some(open_jtalk) = open_jtalk.downcast_ref::<blocking::OpenJtalk>() {
+        return Some(open_jtalk.spec());
+    }
+    open_jtalk
+        .downcast_ref::<tokio::OpenJtalk>()
+        .map(|open_jtalk| open_jtalk.0.spec())
+}
+
+/// `open_jtalk`がこのクレートのOpen JTalkであれば、以後のユーザー辞書のコンパイルを`worker`の中で
+/// 行わせる。
+pub(crate) fn attach_worker(open_jtalk: &dyn Any, worker: &Arc<Worker>) {
+    let inner = if let Some(open_jtalk) = open_jtalk.downcast_ref::<blocking::OpenJtalk>() {
+        &open_jtalk.0
+    } else if let Some(open_jtalk) = open_jtalk.downcast_ref::<tokio::OpenJtalk>() {
+        &open_jtalk.0 .0
+    } else {
+        return;
+    };
+    inner.workers.lock().unwrap().push(Arc::downgrade(worker));
+}
+
 pub(crate) mod blocking {
     use std::{
         io::Write as _,
-        sync::{Arc, Mutex},
+        sync::{Arc, Mutex, Weak},
     };
 
-    use anyhow::Context as _;
+    use anyhow::{anyhow, Context as _};
     use camino::{Utf8Path, Utf8PathBuf};
     use open_jtalk::{mecab_dict_index, text2mecab, JpCommon, ManagedResource, Mecab, Njd};
-    use tempfile::NamedTempFile;
+    use tempfile::{NamedTempFile, TempPath};
 
-    use crate::error::ErrorRepr;
+    use crate::{error::ErrorRepr, worker::Worker};
 
-    use super::{FullcontextExtractor, OpenjtalkFunctionError};
+    use super::{FullcontextExtractor, OpenJtalkSpec, OpenjtalkFunctionError, UserDictSpec};
 
     /// テキスト解析器としてのOpen JTalk。
     #[derive(Clone)]
@@ -41,6 +129,7 @@ pub(crate) mod blocking {
                     mecab: ManagedResource::initialize(),
                     njd: ManagedResource::initialize(),
                     jpcommon: ManagedResource::initialize(),
+                    user_dict: 0,
                 };
 
                 // FIXME: 「システム辞書を読もうとしたけど読めなかった」というエラーをちゃんと用意する
@@ -53,6 +142,8 @@ pub(crate) mod blocking {
                 Ok(Self(Arc::new(Inner {
                     resources: Mutex::new(resources),
                     dict_dir,
+                    user_dict: Mutex::new(None),
+                    workers: Mutex::new(Vec::new()),
                 })))
             }
         }
@@ -60,6 +151,9 @@ pub(crate) mod blocking {
         /// ユーザー辞書を設定する。
         ///
         /// この関数を呼び出した後にユーザー辞書を変更した場合は、再度この関数を呼ぶ必要がある。
+        ///
+        /// ワーカープロセスを使う`Synthesizer`に渡されている場合、ユーザー辞書のコンパイルはワーカー
+        /// プロセスの中でのみ行われ、この`OpenJtalk`でのテキスト解析もワーカープロセスに任される。
         pub fn use_user_dict(
             &self,
             user_dict: &crate::blocking::UserDict,
@@ -67,15 +161,51 @@ pub(crate) mod blocking {
             let words = &user_dict.to_mecab_format();
             self.0.use_user_dict(words)
         }
+
+        pub(crate) fn spec(&self) -> OpenJtalkSpec {
+            OpenJtalkSpec {
+                dict_dir: self.0.dict_dir.clone(),
+                user_dict: self.0.user_dict.lock().unwrap().clone(),
+            }
+        }
+
+        /// `spec`と同じテキスト解析を行う`OpenJtalk`を作る。
+        ///
+        /// ワーカープロセスの中で使われる。
+        pub(crate) fn from_spec(spec: &OpenJtalkSpec) -> crate::result::Result<Self> {
+            let this = Self::new(&spec.dict_dir)?;
+            if spec.user_dict.is_some() {
+                this.0.use_user_dict_locally(spec.user_dict.clone())?;
+            }
+            Ok(this)
+        }
     }
 
     impl FullcontextExtractor for self::OpenJtalk {
         fn extract_fullcontext(&self, text: &str) -> anyhow::Result<Vec<String>> {
+            let mut resources = self.0.resources.lock().unwrap();
+
+            // ユーザー辞書がワーカープロセスの中でのみ読み込まれている場合は、ここでコンパイルする
+            // ことはせず、テキスト解析をワーカープロセスに任せる
+            let generation = (self.0.user_dict.lock().unwrap().as_ref())
+                .map_or(0, |user_dict| user_dict.generation);
+            if resources.user_dict != generation {
+                drop(resources);
+                let worker = self.0.live_workers().into_iter().next().ok_or_else(|| {
+                    anyhow!(
+                        "ユーザー辞書を読み込んだワーカープロセスが既にありません。\
+                         `use_user_dict`を再度呼んでください",
+                    )
+                })?;
+                return worker.extract_fullcontext(&self.spec(), text);
+            }
+
             let Resources {
                 mecab,
                 njd,
                 jpcommon,
-            } = &mut *self.0.resources.lock().unwrap();
+                ..
+            } = &mut *resources;
 
             jpcommon.refresh();
             njd.refresh();
@@ -122,57 +252,114 @@ pub(crate) mod blocking {
     pub(super) struct Inner {
         resources: std::sync::Mutex<Resources>,
         dict_dir: Utf8PathBuf,
+        /// 設定されているユーザー辞書。
+        user_dict: std::sync::Mutex<Option<UserDictSpec>>,
+        /// この`OpenJtalk`を使う`Synthesizer`のワーカープロセス。
+        pub(super) workers: std::sync::Mutex<Vec<Weak<Worker>>>,
     }
 
     impl Inner {
+        /// ユーザー辞書を設定する。
+        ///
+        /// ワーカープロセスが存在すれば、コンパイルはそちらでのみ行う。このプロセスのMeCabには
+        /// 読み込まない。
         // TODO: 中断可能にする
         pub(super) fn use_user_dict(&self, words: &str) -> crate::result::Result<()> {
-            // 空の辞書を読み込もうとするとクラッシュするのでユーザー辞書なしでロード
-            if words.is_empty() {
-                self.load_with_userdic(None)
-            } else {
-                // ユーザー辞書用のcsvを作成
-                let mut temp_csv =
-                    NamedTempFile::new().map_err(|e| ErrorRepr::UseUserDict(e.into()))?;
-                temp_csv
-                    .write_all(words.as_ref())
-                    .map_err(|e| ErrorRepr::UseUserDict(e.into()))?;
-                let temp_csv_path = temp_csv.into_temp_path();
-                let temp_dict =
-                    NamedTempFile::new().map_err(|e| ErrorRepr::UseUserDict(e.into()))?;
-                let temp_dict_path = temp_dict.into_temp_path();
-
-                // FIXME: `.unwrap()`ではなく、エラーとして回収する
-                let temp_csv_path = Utf8Path::from_path(temp_csv_path.as_ref()).unwrap();
-                let temp_dict_path = Utf8Path::from_path(temp_dict_path.as_ref()).unwrap();
-
-                // Mecabでユーザー辞書をコンパイル
-                // TODO: エラー（SEGV）が出るパターンを把握し、それをRust側で防ぐ。
-                mecab_dict_index(&[
-                    "mecab-dict-index",
-                    "-d",
-                    self.dict_dir.as_ref(),
-                    "-u",
-                    temp_dict_path.as_ref(),
-                    "-f",
-                    "utf-8",
-                    "-t",
-                    "utf-8",
-                    temp_csv_path.as_ref(),
-                    "-q",
-                ]);
-
-                self.load_with_userdic(Some(temp_dict_path))
+            let user_dict = UserDictSpec::new(words);
+            let workers = self.live_workers();
+            if workers.is_empty() {
+                return self.use_user_dict_locally(user_dict);
             }
+
+            let spec = OpenJtalkSpec {
+                dict_dir: self.dict_dir.clone(),
+                user_dict: user_dict.clone(),
+            };
+            for worker in workers {
+                worker
+                    .use_user_dict(&spec)
+                    .map_err(ErrorRepr::UseUserDict)?;
+            }
+            *self.user_dict.lock().unwrap() = user_dict;
+            Ok(())
+        }
+
+        /// この`OpenJtalk`を使う`Synthesizer`のワーカープロセスのうち、まだ存在するもの。
+        fn live_workers(&self) -> Vec<Arc<Worker>> {
+            let workers = &mut *self.workers.lock().unwrap();
+            workers.retain(|worker| worker.strong_count() > 0);
+            workers.iter().filter_map(Weak::upgrade).collect()
+        }
+
+        /// このプロセスでユーザー辞書をコンパイルし、読み込む。
+        fn use_user_dict_locally(
+            &self,
+            user_dict: Option<UserDictSpec>,
+        ) -> crate::result::Result<()> {
+            let resources = &mut *self.resources.lock().unwrap();
+            self.load_user_dict(resources, user_dict.as_ref())?;
+            *self.user_dict.lock().unwrap() = user_dict;
+            Ok(())
         }
-        fn load_with_userdic(&self, dict_path: Option<&Utf8Path>) -> crate::result::Result<()> {
-            let Resources { mecab, .. } = &mut *self.resources.lock().unwrap();
 
-            mecab
+        fn load_user_dict(
+            &self,
+            resources: &mut Resources,
+            user_dict: Option<&UserDictSpec>,
+        ) -> crate::result::Result<()> {
+            let dict_path = user_dict
+                .map(|user_dict| self.compile_user_dict(&user_dict.words))
+                .transpose()?;
+            // FIXME: `.unwrap()`ではなく、エラーとして回収する
+            let dict_path = dict_path
+                .as_ref()
+                .map(|path| Utf8Path::from_path(path.as_ref()).unwrap());
+
+            resources
+                .mecab
                 .load_with_userdic(self.dict_dir.as_ref(), dict_path)
                 .context("辞書を読み込めませんでした。")
-                .map_err(ErrorRepr::UseUserDict)
-                .map_err(Into::into)
+                .map_err(ErrorRepr::UseUserDict)?;
+            resources.user_dict = user_dict.map_or(0, |user_dict| user_dict.generation);
+            Ok(())
+        }
+
+        /// ユーザー辞書をコンパイルし、その一時ファイルを返す。
+        ///
+        /// 空の辞書を読み込もうとするとクラッシュするため、`words`は空であってはならない。
+        fn compile_user_dict(&self, words: &str) -> crate::result::Result<TempPath> {
+            // ユーザー辞書用のcsvを作成
+            let mut temp_csv =
+                NamedTempFile::new().map_err(|e| ErrorRepr::UseUserDict(e.into()))?;
+            temp_csv
+                .write_all(words.as_ref())
+                .map_err(|e| ErrorRepr::UseUserDict(e.into()))?;
+            let temp_csv = temp_csv.into_temp_path();
+            let temp_dict = NamedTempFile::new()
+                .map_err(|e| ErrorRepr::UseUserDict(e.into()))?
+                .into_temp_path();
+
+            // FIXME: `.unwrap()`ではなく、エラーとして回収する
+            let temp_csv_path = Utf8Path::from_path(temp_csv.as_ref()).unwrap();
+            let temp_dict_path = Utf8Path::from_path(temp_dict.as_ref()).unwrap();
+
+            // Mecabでユーザー辞書をコンパイル
+            // TODO: エラー（SEGV）が出るパターンを把握し、それをRust側で防ぐ。
+            mecab_dict_index(&[
+                "mecab-dict-index",
+                "-d",
+                self.dict_dir.as_ref(),
+                "-u",
+                temp_dict_path.as_ref(),
+                "-f",
+                "utf-8",
+                "-t",
+                "utf-8",
+                temp_csv_path.as_ref(),
+                "-q",
+            ]);
+
+            Ok(temp_dict)
         }
     }
 
@@ -180,6 +367,8 @@ pub(crate) mod blocking {
         mecab: ManagedResource<Mecab>,
         njd: ManagedResource<Njd>,
         jpcommon: ManagedResource<JpCommon>,
+        /// `mecab`に読み込まれているユーザー辞書の番号。無ければ`0`。
+        user_dict: u64,
     }
 
     // FIXME: open_jtalk-rs側で宣言する
@@ -193,7 +382,7 @@ pub(crate) mod tokio {
 
     /// テキスト解析器としてのOpen JTalk。
     #[derive(Clone)]
-    pub struct OpenJtalk(super::blocking::OpenJtalk);
+    pub struct OpenJtalk(pub(super) super::blocking::OpenJtalk);
 
     impl self::OpenJtalk {
         pub async fn new(open_jtalk_dict_dir: impl AsRef<Utf8Path>) -> crate::result::Result<Self> {
@@ -1,11 +1,14 @@
 use crate::{
     engine::{FullContextLabelError, KanaParseError},
+    manifest::ManifestVersion,
     user_dict::InvalidWordError,
-    StyleId, VoiceModelId,
+    worker::WorkerCrashed,
+    StyleId, StyleType, VoiceModelId,
 };
 //use engine::
 use duplicate::duplicate_item;
-use std::path::PathBuf;
+use itertools::Itertools as _;
+use std::{iter, path::PathBuf};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -30,20 +33,42 @@ impl From<E> for Error {
 impl Error {
     /// 対応する[`ErrorKind`]を返す。
     pub fn kind(&self) -> ErrorKind {
+        // ワーカープロセスの異常終了は、それがどの処理の途中で起きたかによらず区別する
+        if iter::successors(Some(&self.0 as &(dyn std::error::Error + 'static)), |e| {
+            e.source()
+        })
+        .any(|e| e.is::<WorkerCrashed>())
+        {
+            return ErrorKind::WorkerCrashed;
+        }
+
         match &self.0 {
             ErrorRepr::NotLoadedOpenjtalkDict => ErrorKind::NotLoadedOpenjtalkDict,
             ErrorRepr::GpuSupport => ErrorKind::GpuSupport,
+            ErrorRepr::GpuDeviceNotFound { .. } => ErrorKind::GpuDeviceNotFound,
             ErrorRepr::LoadModel(LoadModelError { context, .. }) => match context {
                 LoadModelErrorKind::OpenZipFile => ErrorKind::OpenZipFile,
                 LoadModelErrorKind::ReadZipEntry { .. } => ErrorKind::ReadZipEntry,
                 LoadModelErrorKind::ModelAlreadyLoaded { .. } => ErrorKind::ModelAlreadyLoaded,
                 LoadModelErrorKind::StyleAlreadyLoaded { .. } => ErrorKind::StyleAlreadyLoaded,
                 LoadModelErrorKind::InvalidModelData => ErrorKind::InvalidModelData,
+                LoadModelErrorKind::UnsupportedManifestVersion { .. } => {
+                    ErrorKind::UnsupportedManifestVersion
+                }
+                LoadModelErrorKind::UntrustedVoiceModel => ErrorKind::UntrustedVoiceModel,
             },
+            ErrorRepr::ReadModelDir { .. } => ErrorKind::ReadModelDir,
             ErrorRepr::GetSupportedDevices(_) => ErrorKind::GetSupportedDevices,
-            ErrorRepr::StyleNotFound { .. } => ErrorKind::StyleNotFound,
+            ErrorRepr::StyleNotFound { .. }
+            | ErrorRepr::StyleNameNotFound { .. }
+            | ErrorRepr::StyleNotFoundInModelSet { .. } => ErrorKind::StyleNotFound,
             ErrorRepr::ModelNotFound { .. } => ErrorKind::ModelNotFound,
+            ErrorRepr::SpeakerNotFound { .. } => ErrorKind::SpeakerNotFound,
+            ErrorRepr::OperationNotSupported { .. } | ErrorRepr::StyleTypeNotSupported { .. } => {
+                ErrorKind::OperationNotSupported
+            }
             ErrorRepr::InferenceFailed { .. } => ErrorKind::InferenceFailed,
+            ErrorRepr::WarmUpFailed { .. } => ErrorKind::WarmUpFailed,
             ErrorRepr::ExtractFullContextLabel(_) => ErrorKind::ExtractFullContextLabel,
             ErrorRepr::ParseKana(_) => ErrorKind::ParseKana,
             ErrorRepr::LoadUserDict(_) => ErrorKind::LoadUserDict,
@@ -51,6 +76,7 @@ impl Error {
             ErrorRepr::WordNotFound(_) => ErrorKind::WordNotFound,
             ErrorRepr::UseUserDict(_) => ErrorKind::UseUserDict,
             ErrorRepr::InvalidWord(_) => ErrorKind::InvalidWord,
+            ErrorRepr::PackVvm { .. } => ErrorKind::PackVvm,
         }
     }
 }
@@ -63,9 +89,19 @@ pub(crate) enum ErrorRepr {
     #[error("GPU機能をサポートすることができません")]
     GpuSupport,
 
+    #[error("{index}番のGPUが見つかりません")]
+    GpuDeviceNotFound { index: u16 },
+
     #[error(transparent)]
     LoadModel(#[from] LoadModelError),
 
+    #[error("`{path}`のディレクトリを読めませんでした", path = path.display())]
+    ReadModelDir {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
     #[error("サポートされているデバイス情報取得中にエラーが発生しました")]
     GetSupportedDevices(#[source] anyhow::Error),
 
@@ -75,15 +111,51 @@ pub(crate) enum ErrorRepr {
     )]
     StyleNotFound { style_id: StyleId },
 
+    #[error(
+        "話者`{speaker_uuid}`に`{style_name}`という名前のスタイルが見つかりませんでした。候補: {}",
+        display_candidates(candidates)
+    )]
+    StyleNameNotFound {
+        speaker_uuid: String,
+        style_name: String,
+        candidates: Vec<String>,
+    },
+
+    #[error("`{style_id}`に対するスタイルを含む音声モデルが見つかりませんでした")]
+    StyleNotFoundInModelSet { style_id: StyleId },
+
     #[error(
         "`{model_id}`に対する音声モデルが見つかりませんでした。読み込まれていないか、読み込みが既\
          に解除されています"
     )]
     ModelNotFound { model_id: VoiceModelId },
 
+    #[error("`{speaker_uuid}`に対する話者が見つかりませんでした")]
+    SpeakerNotFound { speaker_uuid: String },
+
+    #[error("音声モデル`{model_id}`は推論操作`{operation}`のためのモデルを含んでいません")]
+    OperationNotSupported {
+        model_id: VoiceModelId,
+        operation: &'static str,
+    },
+
+    #[error("スタイル`{style_id}`の種類`{style_type}`は、推論操作`{operation}`に対応していません")]
+    StyleTypeNotSupported {
+        style_id: StyleId,
+        style_type: StyleType,
+        operation: &'static str,
+    },
+
     #[error("推論に失敗しました")]
     InferenceFailed(#[source] anyhow::Error),
 
+    #[error("音声モデル`{model_id}`のウォームアップに失敗しました")]
+    WarmUpFailed {
+        model_id: VoiceModelId,
+        #[source]
+        source: anyhow::Error,
+    },
+
     #[error(transparent)]
     ExtractFullContextLabel(#[from] FullContextLabelError),
 
@@ -104,6 +176,17 @@ pub(crate) enum ErrorRepr {
 
     #[error(transparent)]
     InvalidWord(#[from] InvalidWordError),
+
+    #[error("`{path}`にVVMを作成できませんでした", path = path.display())]
+    PackVvm {
+        path: PathBuf,
+        #[source]
+        source: anyhow::Error,
+    },
+}
+
+fn display_candidates(candidates: &[String]) -> String {
+    candidates.iter().map(|name| format!("`{name}`")).join(", ")
 }
 
 /// エラーの種類。
@@ -113,6 +196,8 @@ pub enum ErrorKind {
     NotLoadedOpenjtalkDict,
     /// GPUモードがサポートされていない。
     GpuSupport,
+    /// 指定された番号のGPUが見つからなかった。
+    GpuDeviceNotFound,
     /// ZIPファイルを開くことに失敗した。
     OpenZipFile,
     /// ZIP内のファイルが読めなかった。
@@ -123,14 +208,27 @@ pub enum ErrorKind {
     StyleAlreadyLoaded,
     /// 無効なモデルデータ。
     InvalidModelData,
+    /// 音声モデルのマニフェストのバージョンに対応していない。
+    UnsupportedManifestVersion,
+    /// 音声モデルの署名を検証できなかった、または署名者が信頼されていない。
+    UntrustedVoiceModel,
+    /// 音声モデルを探すディレクトリを読めなかった。
+    ReadModelDir,
     /// サポートされているデバイス情報取得に失敗した。
     GetSupportedDevices,
-    /// スタイルIDに対するスタイルが見つからなかった。
+    /// スタイルIDもしくはスタイル名に対するスタイルが見つからなかった。
     StyleNotFound,
     /// 音声モデルIDに対する音声モデルが見つからなかった。
     ModelNotFound,
+    /// 話者のUUIDに対する話者が見つからなかった。
+    SpeakerNotFound,
+    /// 音声モデルが推論操作のためのモデルを含んでいなかった、またはスタイルの種類が推論操作に
+    /// 対応していなかった。
+    OperationNotSupported,
     /// 推論に失敗した。
     InferenceFailed,
+    /// 音声モデルのウォームアップに失敗した。推論結果の形状や値が不正だった場合も含む。
+    WarmUpFailed,
     /// コンテキストラベル出力に失敗した。
     ExtractFullContextLabel,
     /// AquesTalk風記法のテキストの解析に失敗した。
@@ -145,6 +243,10 @@ pub enum ErrorKind {
     UseUserDict,
     /// ユーザー辞書の単語のバリデーションに失敗した。
     InvalidWord,
+    /// ワーカープロセスが異常終了した。
+    WorkerCrashed,
+    /// VVMを作成できなかった。
+    PackVvm,
 }
 
 pub(crate) type LoadModelResult<T> = std::result::Result<T, LoadModelError>;
@@ -171,4 +273,11 @@ pub(crate) enum LoadModelErrorKind {
     StyleAlreadyLoaded { id: StyleId },
     #[display(fmt = "モデルデータを読むことができませんでした")]
     InvalidModelData,
+    #[display(
+        fmt = "マニフェストのバージョン`{version}`には対応していません。VOICEVOX COREを更新してく\
+               ださい"
+    )]
+    UnsupportedManifestVersion { version: ManifestVersion },
+    #[display(fmt = "音声モデルの署名を検証できないか、署名者が信頼されていません")]
+    UntrustedVoiceModel,
 }
@@ -1,17 +1,18 @@
 pub(crate) mod domain;
-mod model_file;
+pub(crate) mod model_file;
 pub(crate) mod runtimes;
 pub(crate) mod status;
 
-use std::{borrow::Cow, fmt::Debug};
+use std::{borrow::Cow, fmt::Debug, num::NonZeroUsize, sync::Arc};
 
 use derive_new::new;
 use duplicate::duplicate_item;
 use enum_map::{Enum, EnumMap};
 use ndarray::{Array, ArrayD, Dimension, ShapeError};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::SupportedDevices;
+use crate::{voice_model::VoiceModelHeader, worker::Worker, StyleType, SupportedDevices};
 
 pub(crate) trait InferenceRuntime: 'static {
     type Session: Sized + Send + 'static;
@@ -35,6 +36,12 @@ pub(crate) trait InferenceRuntime: 'static {
 /// ある`VoiceModel`が提供する推論操作の集合を示す。
 pub(crate) trait InferenceDomain {
     type Operation: InferenceOperation;
+
+    /// 音声モデルが、それぞれの推論操作のためのモデルを持っているか。
+    fn provided_operations(model_header: &VoiceModelHeader) -> EnumMap<Self::Operation, bool>;
+
+    /// それぞれの推論操作を行うことのできるスタイルの種類。
+    fn supported_style_types(operation: Self::Operation) -> &'static [StyleType];
 }
 
 /// `InferenceDomain`の推論操作を表す列挙型。
@@ -52,6 +59,9 @@ pub(crate) trait InferenceOperation: Copy + Enum {
             &'static [ParamInfo<OutputScalarKind>],
         ),
     >;
+
+    /// それぞれの推論操作の名前。
+    const NAMES: EnumMap<Self, &'static str>;
 }
 
 /// `InferenceDomain`の推論操作を表す列挙型。
@@ -85,6 +95,8 @@ pub(crate) trait InputScalar: Sized {
 #[duplicate_item(
     T       KIND_VAL                     push;
     [ i64 ] [ InputScalarKind::Int64 ]   [ push_int64 ];
+    [ i32 ] [ InputScalarKind::Int32 ]   [ push_int32 ];
+    [ i8 ]  [ InputScalarKind::Int8 ]    [ push_int8 ];
     [ f32 ] [ InputScalarKind::Float32 ] [ push_float32 ];
 )]
 impl InputScalar for T {
@@ -98,17 +110,44 @@ impl InputScalar for T {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, derive_more::Display)]
+#[derive(Clone, Copy, PartialEq, Debug, derive_more::Display, Serialize, Deserialize)]
 pub(crate) enum InputScalarKind {
     #[display(fmt = "int64_t")]
     Int64,
 
+    #[display(fmt = "int32_t")]
+    Int32,
+
+    #[display(fmt = "int8_t")]
+    Int8,
+
     #[display(fmt = "float")]
     Float32,
 }
 
+impl InputScalarKind {
+    fn is_integer(self) -> bool {
+        matches!(self, Self::Int64 | Self::Int32 | Self::Int8)
+    }
+}
+
+impl ScalarKind for InputScalarKind {
+    /// 入力はシグネチャ側の型からモデル側の型へ変換される。整数同士であれば、縮小変換も値の範囲
+    /// を検査した上で行われる。
+    fn accepts(self, actual: Self) -> bool {
+        self == actual || (self.is_integer() && actual.is_integer())
+    }
+}
+
+/// テンソルを`RunContext`に入力する。
+///
+/// 実装は、モデルが要求する要素型が入力と異なる場合(例: `int64_t`に対して`int32_t`)、入力を
+/// その型に変換しなければならない。変換先の型で値を表せない場合は、推論をエラーとしなければ
+/// ならない。
 pub(crate) trait PushInputTensor {
     fn push_int64(&mut self, tensor: Array<i64, impl Dimension + 'static>);
+    fn push_int32(&mut self, tensor: Array<i32, impl Dimension + 'static>);
+    fn push_int8(&mut self, tensor: Array<i8, impl Dimension + 'static>);
     fn push_float32(&mut self, tensor: Array<f32, impl Dimension + 'static>);
 }
 
@@ -121,6 +160,9 @@ pub(crate) trait InferenceOutputSignature:
     const PARAM_INFOS: &'static [ParamInfo<OutputScalarKind>];
 }
 
+/// 出力テンソルの要素型。
+///
+/// 値を失わない拡大変換(`int8_t`から`int32_t`)であれば、`extract`で変換される。
 pub(crate) trait OutputScalar: Sized {
     const KIND: OutputScalarKind;
     fn extract(tensor: OutputTensor) -> std::result::Result<ArrayD<Self>, ExtractError>;
@@ -132,18 +174,67 @@ impl OutputScalar for f32 {
     fn extract(tensor: OutputTensor) -> std::result::Result<ArrayD<Self>, ExtractError> {
         match tensor {
             OutputTensor::Float32(tensor) => Ok(tensor),
+            tensor => Err(ExtractError::Datatype(tensor.kind())),
+        }
+    }
+}
+
+impl OutputScalar for i32 {
+    const KIND: OutputScalarKind = OutputScalarKind::Int32;
+
+    fn extract(tensor: OutputTensor) -> std::result::Result<ArrayD<Self>, ExtractError> {
+        match tensor {
+            OutputTensor::Int32(tensor) => Ok(tensor),
+            OutputTensor::Int8(tensor) => Ok(tensor.mapv(Into::into)),
+            tensor => Err(ExtractError::Datatype(tensor.kind())),
+        }
+    }
+}
+
+impl OutputScalar for i8 {
+    const KIND: OutputScalarKind = OutputScalarKind::Int8;
+
+    fn extract(tensor: OutputTensor) -> std::result::Result<ArrayD<Self>, ExtractError> {
+        match tensor {
+            OutputTensor::Int8(tensor) => Ok(tensor),
+            tensor => Err(ExtractError::Datatype(tensor.kind())),
         }
     }
 }
 
-#[derive(Clone, Copy, PartialEq, derive_more::Display)]
+#[derive(Clone, Copy, PartialEq, Debug, derive_more::Display, Serialize, Deserialize)]
 pub(crate) enum OutputScalarKind {
     #[display(fmt = "float")]
     Float32,
+
+    #[display(fmt = "int32_t")]
+    Int32,
+
+    #[display(fmt = "int8_t")]
+    Int8,
+}
+
+impl ScalarKind for OutputScalarKind {
+    /// 出力はモデル側の型からシグネチャ側の型へ変換される。値を失わない拡大変換のみを受け入れる。
+    fn accepts(self, actual: Self) -> bool {
+        self == actual || (self, actual) == (Self::Int32, Self::Int8)
+    }
 }
 
 pub(crate) enum OutputTensor {
     Float32(ArrayD<f32>),
+    Int32(ArrayD<i32>),
+    Int8(ArrayD<i8>),
+}
+
+impl OutputTensor {
+    fn kind(&self) -> OutputScalarKind {
+        match self {
+            Self::Float32(_) => OutputScalarKind::Float32,
+            Self::Int32(_) => OutputScalarKind::Int32,
+            Self::Int8(_) => OutputScalarKind::Int8,
+        }
+    }
 }
 
 impl<A: OutputScalar, D: Dimension> TryFrom<OutputTensor> for Array<A, D> {
@@ -155,32 +246,118 @@ impl<A: OutputScalar, D: Dimension> TryFrom<OutputTensor> for Array<A, D> {
     }
 }
 
+#[derive(Serialize, Deserialize)]
 pub(crate) struct ParamInfo<D> {
     name: Cow<'static, str>,
     dt: D,
     ndim: Option<usize>,
 }
 
-impl<D: PartialEq> ParamInfo<D> {
+impl<D: ScalarKind> ParamInfo<D> {
+    /// `self`をシグネチャ側、`other`をモデル側として、両者が適合するかを判定する。
+    ///
+    /// 要素型は、[`ScalarKind::accepts`]に従いランタイムとの境界で変換できるものを受け入れる。
     fn accepts(&self, other: &Self) -> bool {
         self.name == other.name
-            && self.dt == other.dt
+            && self.dt.accepts(other.dt)
             && (self.ndim.is_none() || self.ndim == other.ndim)
     }
 }
 
-#[derive(new, Clone, Copy, PartialEq, Debug)]
+/// テンソルの要素型の種類。
+pub(crate) trait ScalarKind: Copy + PartialEq {
+    /// `self`をシグネチャ側、`actual`をモデル側の要素型として、ランタイムとの境界で安全に変換
+    /// できるかを判定する。
+    fn accepts(self, actual: Self) -> bool;
+}
+
+#[derive(new, Clone, PartialEq, Debug)]
 pub(crate) struct InferenceSessionOptions {
     pub(crate) cpu_num_threads: u16,
     pub(crate) use_gpu: bool,
+    /// 一つの推論操作につき同時に持つ`Session`の最大数。ランタイムには渡らず、`Status`が使う。
+    pub(crate) max_sessions: NonZeroUsize,
+    /// `use_gpu`のときに使うGPUの番号。
+    #[new(default)]
+    pub(crate) gpu_device_index: u16,
+    /// `use_gpu`のときのGPUメモリの使用量の上限(バイト)。0の場合は上限を設けない。
+    #[new(default)]
+    pub(crate) gpu_memory_limit: usize,
+    /// 指定されている場合、`Session`はこのワーカープロセスの中に作られる。
+    #[new(default)]
+    pub(crate) worker: Option<Arc<Worker>>,
 }
 
 #[derive(Error, Debug)]
 pub(crate) enum ExtractError {
     #[error(transparent)]
     Shape(#[from] ShapeError),
+
+    #[error("`{_0}`のテンソルを変換することはできません")]
+    Datatype(OutputScalarKind),
 }
 
 #[derive(Error, Debug)]
 #[error("不正なモデルファイルです")]
 pub(crate) struct DecryptModelError;
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use ndarray::{array, Array1};
+    use rstest::rstest;
+
+    use super::{InputScalarKind, OutputScalarKind, OutputTensor, ParamInfo};
+
+    #[rstest]
+    #[case(InputScalarKind::Int64, InputScalarKind::Int64, true)]
+    #[case(InputScalarKind::Int64, InputScalarKind::Int32, true)]
+    #[case(InputScalarKind::Int64, InputScalarKind::Int8, true)]
+    #[case(InputScalarKind::Int8, InputScalarKind::Int64, true)]
+    #[case(InputScalarKind::Int64, InputScalarKind::Float32, false)]
+    #[case(InputScalarKind::Float32, InputScalarKind::Int8, false)]
+    fn param_info_accepts_works(
+        #[case] expected: InputScalarKind,
+        #[case] actual: InputScalarKind,
+        #[case] accepts: bool,
+    ) {
+        let param_info = |dt| ParamInfo {
+            name: Cow::Borrowed("x"),
+            dt,
+            ndim: Some(1),
+        };
+        assert_eq!(accepts, param_info(expected).accepts(&param_info(actual)));
+    }
+
+    #[rstest]
+    #[case(OutputScalarKind::Float32, OutputScalarKind::Float32, true)]
+    #[case(OutputScalarKind::Int32, OutputScalarKind::Int8, true)]
+    #[case(OutputScalarKind::Int8, OutputScalarKind::Int32, false)]
+    #[case(OutputScalarKind::Float32, OutputScalarKind::Int32, false)]
+    fn param_info_accepts_works_for_outputs(
+        #[case] expected: OutputScalarKind,
+        #[case] actual: OutputScalarKind,
+        #[case] accepts: bool,
+    ) {
+        let param_info = |dt| ParamInfo {
+            name: Cow::Borrowed("x"),
+            dt,
+            ndim: Some(1),
+        };
+        assert_eq!(accepts, param_info(expected).accepts(&param_info(actual)));
+    }
+
+    #[rstest]
+    fn output_tensor_conversion_works() {
+        let i8_tensor = OutputTensor::Int8(array![-3].into_dyn());
+        let i32_tensor = Array1::<i32>::try_from(i8_tensor).unwrap();
+        assert_eq!(array![-3], i32_tensor);
+
+        let i32_tensor = OutputTensor::Int32(array![1].into_dyn());
+        assert!(Array1::<f32>::try_from(i32_tensor).is_err());
+
+        let i32_tensor = OutputTensor::Int32(array![1].into_dyn());
+        assert!(Array1::<i8>::try_from(i32_tensor).is_err());
+    }
+}
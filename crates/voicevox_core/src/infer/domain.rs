@@ -1,7 +1,9 @@
-use enum_map::Enum;
+use enum_map::{Enum, EnumMap};
 use macros::{InferenceInputSignature, InferenceOperation, InferenceOutputSignature};
 use ndarray::{Array0, Array1, Array2};
 
+use crate::{voice_model::VoiceModelHeader, StyleType};
+
 use super::{
     InferenceDomain, InferenceInputSignature as _, InferenceOutputSignature as _, OutputTensor,
 };
@@ -10,6 +12,18 @@ pub(crate) enum InferenceDomainImpl {}
 
 impl InferenceDomain for InferenceDomainImpl {
     type Operation = InferenceOperationImpl;
+
+    fn provided_operations(model_header: &VoiceModelHeader) -> EnumMap<Self::Operation, bool> {
+        EnumMap::from_fn(|op| model_header.inference_model_filename(op).is_some())
+    }
+
+    fn supported_style_types(operation: Self::Operation) -> &'static [StyleType] {
+        match operation {
+            InferenceOperationImpl::PredictDuration
+            | InferenceOperationImpl::PredictIntonation
+            | InferenceOperationImpl::Decode => &[StyleType::Talk],
+        }
+    }
 }
 
 #[derive(Clone, Copy, Enum, InferenceOperation)]
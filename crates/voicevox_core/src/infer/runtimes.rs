@@ -1,3 +1,4 @@
 mod onnxruntime;
+mod out_of_process;
 
-pub(crate) use self::onnxruntime::Onnxruntime;
+pub(crate) use self::{onnxruntime::Onnxruntime, out_of_process::OutOfProcess};
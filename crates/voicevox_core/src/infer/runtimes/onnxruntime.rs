@@ -1,6 +1,6 @@
 use std::{fmt::Debug, vec};
 
-use anyhow::anyhow;
+use anyhow::{anyhow, bail};
 use duplicate::duplicate_item;
 use ndarray::{Array, Dimension};
 use once_cell::sync::Lazy;
@@ -69,18 +69,30 @@ impl InferenceRuntime for Onnxruntime {
                 builder = builder
                     .with_disable_mem_pattern()?
                     .with_execution_mode(ExecutionMode::ORT_SEQUENTIAL)?
-                    .with_append_execution_provider_directml(0)?;
+                    .with_append_execution_provider_directml(options.gpu_device_index.into())?;
             }
 
             #[cfg(not(feature = "directml"))]
             {
-                builder = builder.with_append_execution_provider_cuda(Default::default())?;
+                use onnxruntime::CudaProviderOptions;
+
+                let default = CudaProviderOptions::default();
+                builder = builder.with_append_execution_provider_cuda(CudaProviderOptions {
+                    device_id: options.gpu_device_index.into(),
+                    gpu_mem_limit: match options.gpu_memory_limit {
+                        0 => default.gpu_mem_limit,
+                        limit => limit,
+                    },
+                    ..default
+                })?;
             }
         }
 
         let model = model()?;
         let sess = AssertSend::from(builder.with_model_from_memory(model)?);
 
+        // FIXME: onnxruntime-rsの`TensorElementDataType`には`FLOAT16`が無いため、半精度のモデルは
+        // `with_model_from_memory`の時点でエラーとなる。ortクレートに移行したら対応する。
         let input_param_infos = sess
             .inputs
             .iter()
@@ -88,10 +100,10 @@ impl InferenceRuntime for Onnxruntime {
                 let dt = match info.input_type {
                     TensorElementDataType::Float => Ok(InputScalarKind::Float32),
                     TensorElementDataType::Uint8 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT8"),
-                    TensorElementDataType::Int8 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT8"),
+                    TensorElementDataType::Int8 => Ok(InputScalarKind::Int8),
                     TensorElementDataType::Uint16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT16"),
                     TensorElementDataType::Int16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT16"),
-                    TensorElementDataType::Int32 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT32"),
+                    TensorElementDataType::Int32 => Ok(InputScalarKind::Int32),
                     TensorElementDataType::Int64 => Ok(InputScalarKind::Int64),
                     TensorElementDataType::String => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_STRING"),
                     TensorElementDataType::Double => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_DOUBLE"),
@@ -117,10 +129,10 @@ impl InferenceRuntime for Onnxruntime {
                 let dt = match info.output_type {
                     TensorElementDataType::Float => Ok(OutputScalarKind::Float32),
                     TensorElementDataType::Uint8 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT8"),
-                    TensorElementDataType::Int8 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT8"),
+                    TensorElementDataType::Int8 => Ok(OutputScalarKind::Int8),
                     TensorElementDataType::Uint16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT16"),
                     TensorElementDataType::Int16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT16"),
-                    TensorElementDataType::Int32 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT32"),
+                    TensorElementDataType::Int32 => Ok(OutputScalarKind::Int32),
                     TensorElementDataType::Int64 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT64"),
                     TensorElementDataType::String => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_STRING"),
                     TensorElementDataType::Double => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_DOUBLE"),
@@ -157,38 +169,103 @@ impl InferenceRuntime for Onnxruntime {
     }
 
     fn run(
-        OnnxruntimeRunContext { sess, mut inputs }: OnnxruntimeRunContext<'_>,
+        OnnxruntimeRunContext {
+            sess,
+            mut inputs,
+            error,
+        }: OnnxruntimeRunContext<'_>,
     ) -> anyhow::Result<Vec<OutputTensor>> {
-        // FIXME: 現状では`f32`のみ対応。実行時にsessionからdatatypeが取れるので、別の型の対応も
-        // おそらく可能ではあるが、それが必要になるよりもortクレートへの引越しが先になると思われる
-        // のでこのままにする。
+        if let Some(err) = error {
+            return Err(err);
+        }
 
-        if !sess
+        // FIXME: onnxruntime-rsの`Session::run`は出力の型を一つしか取れないため、出力の要素型が
+        // 混在するモデルには対応していない。ortクレートに移行したら対応する。
+        let output_types = sess
             .outputs
             .iter()
-            .all(|info| matches!(info.output_type, TensorElementDataType::Float))
-        {
-            unimplemented!(
-                "currently only `ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT` is supported for output",
-            );
+            .map(|info| match info.output_type {
+                TensorElementDataType::Float => Ok(OutputScalarKind::Float32),
+                TensorElementDataType::Int32 => Ok(OutputScalarKind::Int32),
+                TensorElementDataType::Int8 => Ok(OutputScalarKind::Int8),
+                ref output_type => Err(anyhow!("unsupported output datatype `{output_type:?}`")),
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let [output_type, ..] = *output_types else {
+            bail!("the model has no outputs");
+        };
+        if !output_types.iter().all(|&t| t == output_type) {
+            bail!("outputs with mixed datatypes are not supported");
         }
 
-        let outputs = sess.run::<f32>(inputs.iter_mut().map(|t| &mut **t as &mut _).collect())?;
+        let inputs = inputs.iter_mut().map(|t| &mut **t as &mut _).collect();
 
-        Ok(outputs
-            .iter()
-            .map(|o| OutputTensor::Float32((*o).clone().into_owned()))
-            .collect())
+        return match output_type {
+            OutputScalarKind::Float32 => run::<f32>(sess, inputs, OutputTensor::Float32),
+            OutputScalarKind::Int32 => run::<i32>(sess, inputs, OutputTensor::Int32),
+            OutputScalarKind::Int8 => run::<i8>(sess, inputs, OutputTensor::Int8),
+        };
+
+        fn run<T: TypeToTensorElementDataType + Debug + Clone>(
+            sess: &mut onnxruntime::session::Session<'static>,
+            inputs: Vec<&mut dyn onnxruntime::session::AnyArray>,
+            f: fn(ndarray::ArrayD<T>) -> OutputTensor,
+        ) -> anyhow::Result<Vec<OutputTensor>> {
+            let outputs = sess.run::<T>(inputs)?;
+            Ok(outputs
+                .iter()
+                .map(|o| f((*o).clone().into_owned()))
+                .collect())
+        }
     }
 }
 
 pub(crate) struct OnnxruntimeRunContext<'sess> {
     sess: &'sess mut AssertSend<onnxruntime::session::Session<'static>>,
     inputs: Vec<Box<dyn onnxruntime::session::AnyArray>>,
+    /// 入力の変換で最初に起きたエラー。`run`で返す。
+    error: Option<anyhow::Error>,
 }
 
 impl OnnxruntimeRunContext<'_> {
-    fn push_input(
+    /// モデルが要求する要素型に変換した上で、入力を追加する。
+    ///
+    /// 変換できなかった場合、そのエラーは`run`で返される。
+    fn push_input<T: CastScalar, D: Dimension + 'static>(&mut self, input: Array<T, D>) {
+        if self.error.is_some() {
+            return;
+        }
+        if let Err(err) = self.try_push_input(input) {
+            self.error = Some(err);
+        }
+    }
+
+    fn try_push_input<T: CastScalar, D: Dimension + 'static>(
+        &mut self,
+        input: Array<T, D>,
+    ) -> anyhow::Result<()> {
+        let Some(info) = self.sess.inputs.get(self.inputs.len()) else {
+            bail!("too many inputs");
+        };
+        match &info.input_type {
+            TensorElementDataType::Float => self.push_input_as_is(cast(input, T::to_f32)?),
+            TensorElementDataType::Int64 => self.push_input_as_is(cast(input, T::to_i64)?),
+            TensorElementDataType::Int32 => self.push_input_as_is(cast(input, T::to_i32)?),
+            TensorElementDataType::Int8 => self.push_input_as_is(cast(input, T::to_i8)?),
+            input_type => bail!("unsupported input datatype `{input_type:?}`"),
+        }
+        return Ok(());
+
+        fn cast<T: Copy, U, D: Dimension>(
+            input: Array<T, D>,
+            f: fn(T) -> anyhow::Result<U>,
+        ) -> anyhow::Result<Array<U, D>> {
+            let elems = input.iter().map(|&x| f(x)).collect::<anyhow::Result<_>>()?;
+            Ok(Array::from_shape_vec(input.raw_dim(), elems)?)
+        }
+    }
+
+    fn push_input_as_is(
         &mut self,
         input: Array<impl TypeToTensorElementDataType + Debug + 'static, impl Dimension + 'static>,
     ) {
@@ -197,6 +274,60 @@ impl OnnxruntimeRunContext<'_> {
     }
 }
 
+/// 入力の要素型の変換。
+///
+/// 整数同士の変換では値の範囲を検査し、変換先の型で表せない値はエラーとする。整数と浮動小数点数
+/// の間の変換は行わない。
+trait CastScalar: Copy + 'static {
+    fn to_i64(self) -> anyhow::Result<i64>;
+    fn to_i32(self) -> anyhow::Result<i32>;
+    fn to_i8(self) -> anyhow::Result<i8>;
+    fn to_f32(self) -> anyhow::Result<f32>;
+}
+
+#[duplicate_item(
+    T;
+    [ i64 ];
+    [ i32 ];
+    [ i8 ];
+)]
+#[allow(clippy::useless_conversion, clippy::unnecessary_fallible_conversions)]
+impl CastScalar for T {
+    fn to_i64(self) -> anyhow::Result<i64> {
+        Ok(self.into())
+    }
+
+    fn to_i32(self) -> anyhow::Result<i32> {
+        i32::try_from(self).map_err(|_| anyhow!("`{self}` is out of range for `int32_t`"))
+    }
+
+    fn to_i8(self) -> anyhow::Result<i8> {
+        i8::try_from(self).map_err(|_| anyhow!("`{self}` is out of range for `int8_t`"))
+    }
+
+    fn to_f32(self) -> anyhow::Result<f32> {
+        bail!("cannot convert integers into `float`");
+    }
+}
+
+impl CastScalar for f32 {
+    fn to_i64(self) -> anyhow::Result<i64> {
+        bail!("cannot convert `float` into `int64_t`");
+    }
+
+    fn to_i32(self) -> anyhow::Result<i32> {
+        bail!("cannot convert `float` into `int32_t`");
+    }
+
+    fn to_i8(self) -> anyhow::Result<i8> {
+        bail!("cannot convert `float` into `int8_t`");
+    }
+
+    fn to_f32(self) -> anyhow::Result<f32> {
+        Ok(self)
+    }
+}
+
 impl<'sess> From<&'sess mut AssertSend<onnxruntime::session::Session<'static>>>
     for OnnxruntimeRunContext<'sess>
 {
@@ -204,6 +335,7 @@ impl<'sess> From<&'sess mut AssertSend<onnxruntime::session::Session<'static>>>
         Self {
             sess,
             inputs: vec![],
+            error: None,
         }
     }
 }
@@ -212,6 +344,8 @@ impl PushInputTensor for OnnxruntimeRunContext<'_> {
     #[duplicate_item(
         method           T;
         [ push_int64 ]   [ i64 ];
+        [ push_int32 ]   [ i32 ];
+        [ push_int8 ]    [ i8 ];
         [ push_float32 ] [ f32 ];
     )]
     fn method(&mut self, tensor: Array<T, impl Dimension + 'static>) {
@@ -251,3 +385,21 @@ mod assert_send {
     // SAFETY: `Session` is probably "send"able.
     unsafe impl<T> Send for AssertSend<T> {}
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::CastScalar as _;
+
+    #[rstest]
+    fn cast_scalar_checks_range() {
+        assert_eq!(-3, (-3i64).to_i8().unwrap());
+        assert_eq!(300, 300i64.to_i32().unwrap());
+        assert_eq!(-3, (-3i8).to_i64().unwrap());
+        assert!(300i64.to_i8().is_err());
+        assert!(i64::MAX.to_i32().is_err());
+        assert!(1i64.to_f32().is_err());
+        assert!(0.5f32.to_i64().is_err());
+    }
+}
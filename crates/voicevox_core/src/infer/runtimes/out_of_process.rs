@@ -0,0 +1,103 @@
+use std::{convert::Infallible, marker::PhantomData};
+
+use duplicate::duplicate_item;
+use ndarray::{Array, Dimension};
+
+use crate::{
+    worker::{RemoteSession, Tensor},
+    SupportedDevices,
+};
+
+use super::super::{
+    DecryptModelError, InferenceRuntime, InferenceSessionOptions, InputScalarKind,
+    OutputScalarKind, OutputTensor, ParamInfo, PushInputTensor,
+};
+
+/// [`InferenceSessionOptions::worker`]が指定されていればワーカープロセスの中で、そうでなければ
+/// このプロセスの中で、`R`により推論を行う。
+pub(crate) struct OutOfProcess<R>(Infallible, PhantomData<fn() -> R>);
+
+impl<R: InferenceRuntime> InferenceRuntime for OutOfProcess<R> {
+    type Session = OutOfProcessSession<R>;
+    type RunContext<'a> = OutOfProcessRunContext<'a, R>;
+
+    fn supported_devices() -> crate::Result<SupportedDevices> {
+        R::supported_devices()
+    }
+
+    fn new_session(
+        model: impl FnOnce() -> std::result::Result<Vec<u8>, DecryptModelError>,
+        options: InferenceSessionOptions,
+    ) -> anyhow::Result<(
+        Self::Session,
+        Vec<ParamInfo<InputScalarKind>>,
+        Vec<ParamInfo<OutputScalarKind>>,
+    )> {
+        if let Some(worker) = &options.worker {
+            let (sess, input_param_infos, output_param_infos) =
+                worker.new_session(model()?, &options)?;
+            return Ok((
+                OutOfProcessSession::Remote(sess),
+                input_param_infos,
+                output_param_infos,
+            ));
+        }
+        let (sess, input_param_infos, output_param_infos) = R::new_session(model, options)?;
+        Ok((
+            OutOfProcessSession::Local(sess),
+            input_param_infos,
+            output_param_infos,
+        ))
+    }
+
+    fn run(ctx: Self::RunContext<'_>) -> anyhow::Result<Vec<OutputTensor>> {
+        match ctx {
+            OutOfProcessRunContext::Local(ctx) => R::run(ctx),
+            OutOfProcessRunContext::Remote { sess, inputs } => sess.run(inputs),
+        }
+    }
+}
+
+pub(crate) enum OutOfProcessSession<R: InferenceRuntime> {
+    Local(R::Session),
+    Remote(RemoteSession),
+}
+
+pub(crate) enum OutOfProcessRunContext<'sess, R: InferenceRuntime> {
+    Local(R::RunContext<'sess>),
+    Remote {
+        sess: &'sess mut RemoteSession,
+        inputs: Vec<Tensor>,
+    },
+}
+
+impl<'sess, R: InferenceRuntime> From<&'sess mut OutOfProcessSession<R>>
+    for OutOfProcessRunContext<'sess, R>
+{
+    fn from(sess: &'sess mut OutOfProcessSession<R>) -> Self {
+        match sess {
+            OutOfProcessSession::Local(sess) => Self::Local(sess.into()),
+            OutOfProcessSession::Remote(sess) => Self::Remote {
+                sess,
+                inputs: vec![],
+            },
+        }
+    }
+}
+
+impl<R: InferenceRuntime> PushInputTensor for OutOfProcessRunContext<'_, R> {
+    // ワーカープロセスに送られた入力は、そこでモデルが要求する要素型に変換される
+    #[duplicate_item(
+        method           T;
+        [ push_int64 ]   [ i64 ];
+        [ push_int32 ]   [ i32 ];
+        [ push_int8 ]    [ i8 ];
+        [ push_float32 ] [ f32 ];
+    )]
+    fn method(&mut self, tensor: Array<T, impl Dimension + 'static>) {
+        match self {
+            Self::Local(ctx) => ctx.method(tensor),
+            Self::Remote { inputs, .. } => inputs.push(tensor.into()),
+        }
+    }
+}
@@ -1,8 +1,14 @@
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     fmt::Display,
     marker::PhantomData,
-    sync::Arc,
+    num::NonZeroUsize,
+    ops::{Deref, DerefMut},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc, Condvar, Weak,
+    },
 };
 
 use anyhow::bail;
@@ -10,14 +16,16 @@ use educe::Educe;
 use enum_map::{Enum as _, EnumMap};
 use indexmap::IndexMap;
 use itertools::{iproduct, Itertools as _};
+use tracing::info;
 
 use crate::{
     error::{ErrorRepr, LoadModelError, LoadModelErrorKind, LoadModelResult},
-    infer::{InferenceOperation, ParamInfo},
+    infer::{InferenceOperation, ParamInfo, ScalarKind},
     manifest::ModelInnerId,
-    metas::{self, SpeakerMeta, StyleId, StyleMeta, VoiceModelMeta},
-    voice_model::{VoiceModelHeader, VoiceModelId},
-    Result,
+    metas::{self, SpeakerMeta, StyleId, StyleType, VoiceModelMeta},
+    voice_model::{VoiceModelHeader, VoiceModelId, VvmSource},
+    vvm_signature::SignatureTrust,
+    Result, SpeakerInfo, StyleIdRemapping,
 };
 
 use super::{
@@ -28,6 +36,10 @@ use super::{
 pub(crate) struct Status<R: InferenceRuntime, D: InferenceDomain> {
     loaded_models: std::sync::Mutex<LoadedModels<R, D>>,
     session_options: EnumMap<D::Operation, InferenceSessionOptions>,
+    budget: SessionBudget,
+    signature_trust: SignatureTrust,
+    /// 音声モデルが使われた順序を記録するためのカウンタ。
+    clock: AtomicU64,
 }
 
 impl<R: InferenceRuntime, D: InferenceDomain> Status<R, D> {
@@ -35,33 +47,160 @@ impl<R: InferenceRuntime, D: InferenceDomain> Status<R, D> {
         Self {
             loaded_models: Default::default(),
             session_options,
+            budget: SessionBudget::default(),
+            signature_trust: SignatureTrust::default(),
+            clock: AtomicU64::new(0),
         }
     }
 
+    pub(crate) fn with_budget(self, budget: SessionBudget) -> Self {
+        Self { budget, ..self }
+    }
+
+    pub(crate) fn with_signature_trust(self, signature_trust: SignatureTrust) -> Self {
+        Self {
+            signature_trust,
+            ..self
+        }
+    }
+
+    /// `Status::new`に与えられた設定による、音声モデルの`Session`の設定を返す。
+    pub(crate) fn default_model_session_options(&self) -> ModelSessionOptions<D> {
+        ModelSessionOptions {
+            sessions: EnumMap::from_fn(|op| self.session_options[op].clone()),
+            pool_owners: EnumMap::from_fn(|op| op),
+        }
+    }
+
+    /// 音声モデルの署名が、[`with_signature_trust`]で与えられた方針の上で受け入れられるかを確かめる。
+    ///
+    /// [`with_signature_trust`]: Self::with_signature_trust
+    fn ensure_trusted(&self, model_header: &VoiceModelHeader) -> LoadModelResult<()> {
+        self.signature_trust
+            .ensure_trusted(&model_header.signature)
+            .map_err(|source| LoadModelError {
+                path: model_header.source.display_path(),
+                context: LoadModelErrorKind::UntrustedVoiceModel,
+                source: Some(source),
+            })
+    }
+
+    /// 音声モデルを、音声モデルが提供するすべての推論操作の`Session`を作った上で登録する。
+    ///
+    /// `read_model`は、破棄された`Session`を作り直すときに使われる。スタイルIDは
+    /// `style_id_remapping`に従って付け替えられる。
     pub(crate) fn insert_model(
         &self,
         model_header: &VoiceModelHeader,
-        model_bytes: &EnumMap<D::Operation, Vec<u8>>,
+        model_bytes: &EnumMap<D::Operation, Option<Vec<u8>>>,
+        read_model: impl Fn(D::Operation) -> LoadModelResult<Vec<u8>> + Send + Sync + 'static,
+        session_options: &ModelSessionOptions<D>,
+        style_id_remapping: &StyleIdRemapping,
     ) -> Result<()> {
-        self.loaded_models
-            .lock()
-            .unwrap()
-            .ensure_acceptable(model_header)?;
-
-        let session_set =
-            SessionSet::new(model_bytes, &self.session_options).map_err(|source| {
-                LoadModelError {
-                    path: model_header.path.clone(),
-                    context: LoadModelErrorKind::InvalidModelData,
-                    source: Some(source),
-                }
-            })?;
+        self.ensure_trusted(model_header)?;
+        self.loaded_models.lock().unwrap().ensure_acceptable(
+            model_header,
+            style_id_remapping,
+            None,
+        )?;
+
+        let session_set = SessionSet::new(
+            &model_header.source,
+            model_bytes,
+            read_model,
+            session_options,
+        )?;
+
+        self.loaded_models.lock().unwrap().insert(
+            model_header,
+            session_set,
+            session_options,
+            style_id_remapping,
+        )?;
+        if !self.budget.is_unlimited() {
+            self.enforce_budget(&model_header.id);
+        }
+        Ok(())
+    }
+
+    /// 音声モデルを、`Session`を作らずに登録する。
+    ///
+    /// それぞれの推論操作の`Session`は、その推論操作が最初に実行されるときに`read_model`で
+    /// モデルを読んで作られる。
+    pub(crate) fn insert_model_lazily(
+        &self,
+        model_header: &VoiceModelHeader,
+        read_model: impl Fn(D::Operation) -> LoadModelResult<Vec<u8>> + Send + Sync + 'static,
+        session_options: &ModelSessionOptions<D>,
+        style_id_remapping: &StyleIdRemapping,
+    ) -> Result<()> {
+        self.ensure_trusted(model_header)?;
+        let session_set = SessionSet::new_lazy(
+            &model_header.source,
+            D::provided_operations(model_header),
+            read_model,
+            session_options,
+        );
+
+        self.loaded_models.lock().unwrap().insert(
+            model_header,
+            session_set,
+            session_options,
+            style_id_remapping,
+        )?;
+        Ok(())
+    }
+
+    /// 読み込まれている音声モデルを、`model_header`の内容で置き換える。
+    ///
+    /// 音声モデルIDは`voice_model_id`のまま変わらず、`Session`の設定とスタイルIDの付け替えの方針も
+    /// 引き継がれる。`model_bytes`が
+    /// `None`の場合、`Session`は遅延して作られる。
+    ///
+    /// 置き換えは一度に行われる。既に始まっている推論は、置き換え前の`Session`で最後まで行われる。
+    pub(crate) fn replace_model(
+        &self,
+        voice_model_id: &VoiceModelId,
+        model_header: &VoiceModelHeader,
+        model_bytes: Option<&EnumMap<D::Operation, Option<Vec<u8>>>>,
+        read_model: impl Fn(D::Operation) -> LoadModelResult<Vec<u8>> + Send + Sync + 'static,
+    ) -> Result<()> {
+        self.ensure_trusted(model_header)?;
+        let session_options = {
+            let loaded_models = self.loaded_models.lock().unwrap();
+            let LoadedModel {
+                session_options,
+                style_id_remapping,
+                ..
+            } = loaded_models.get(voice_model_id)?;
+            loaded_models.ensure_acceptable(
+                model_header,
+                style_id_remapping,
+                Some(voice_model_id),
+            )?;
+            session_options.clone()
+        };
+
+        // `Session`の作成には時間がかかりうるため、ロックの外で行う
+        let session_set = match model_bytes {
+            Some(model_bytes) => SessionSet::new(
+                &model_header.source,
+                model_bytes,
+                read_model,
+                &session_options,
+            )?,
+            None => SessionSet::new_lazy(
+                &model_header.source,
+                D::provided_operations(model_header),
+                read_model,
+                &session_options,
+            ),
+        };
 
         self.loaded_models
             .lock()
             .unwrap()
-            .insert(model_header, session_set)?;
-        Ok(())
+            .replace(voice_model_id, model_header, session_set)
     }
 
     pub(crate) fn unload_model(&self, voice_model_id: &VoiceModelId) -> Result<()> {
@@ -72,10 +211,87 @@ impl<R: InferenceRuntime, D: InferenceDomain> Status<R, D> {
         self.loaded_models.lock().unwrap().metas()
     }
 
+    /// 話者の追加情報を、その話者のスタイルを持つすべての音声モデルから読んで返す。
+    ///
+    /// スタイルIDは付け替え後のものとなる。
+    pub(crate) fn speaker_info(&self, speaker_uuid: &str) -> Result<SpeakerInfo> {
+        // VVMを読むのには時間がかかりうるため、ロックの外で行う
+        let (speaker, sources) = self.loaded_models.lock().unwrap().speakers(speaker_uuid)?;
+        let infos = sources
+            .iter()
+            .map(|(header, speaker)| header.read_speaker_info(speaker))
+            .collect::<LoadModelResult<Vec<_>>>()?;
+        Ok(SpeakerInfo::merge(infos, &speaker))
+    }
+
     pub(crate) fn ids_for(&self, style_id: StyleId) -> Result<(VoiceModelId, ModelInnerId)> {
         self.loaded_models.lock().unwrap().ids_for(style_id)
     }
 
+    /// [`ids_for`]と同様だが、スタイルの種類が`operation`に対応していなければエラーを返す。
+    ///
+    /// [`ids_for`]: Self::ids_for
+    pub(crate) fn ids_for_operation(
+        &self,
+        style_id: StyleId,
+        operation: D::Operation,
+    ) -> Result<(VoiceModelId, ModelInnerId)> {
+        let loaded_models = self.loaded_models.lock().unwrap();
+        let style_type = loaded_models.style_type_of(style_id)?;
+        if !D::supported_style_types(operation).contains(&style_type) {
+            return Err(ErrorRepr::StyleTypeNotSupported {
+                style_id,
+                style_type,
+                operation: D::Operation::NAMES[operation],
+            }
+            .into());
+        }
+        loaded_models.ids_for(style_id)
+    }
+
+    pub(crate) fn style_type_of(&self, style_id: StyleId) -> Result<StyleType> {
+        self.loaded_models.lock().unwrap().style_type_of(style_id)
+    }
+
+    /// 音声モデルが、それぞれの推論操作のためのモデルを持っているか。
+    pub(crate) fn provided_operations(
+        &self,
+        voice_model_id: &VoiceModelId,
+    ) -> Result<EnumMap<D::Operation, bool>> {
+        let loaded_models = self.loaded_models.lock().unwrap();
+        let provided = &loaded_models.get(voice_model_id)?.session_set.provided;
+        Ok(EnumMap::from_fn(|op| provided[op]))
+    }
+
+    pub(crate) fn model_source(&self, voice_model_id: &VoiceModelId) -> Result<VvmSource> {
+        let loaded_models = self.loaded_models.lock().unwrap();
+        Ok(loaded_models
+            .get(voice_model_id)?
+            .session_set
+            .source
+            .clone())
+    }
+
+    /// VVMファイルから読み込まれている音声モデルのIDと、そのVVMファイルのパスを列挙する。
+    pub(crate) fn model_paths(&self) -> Vec<(VoiceModelId, PathBuf)> {
+        self.loaded_models
+            .lock()
+            .unwrap()
+            .models
+            .iter()
+            .filter_map(|(model_id, LoadedModel { session_set, .. })| {
+                Some((model_id.clone(), session_set.source.path()?.to_owned()))
+            })
+            .collect()
+    }
+
+    pub(crate) fn style_ids_of(&self, voice_model_id: &VoiceModelId) -> Result<Vec<StyleId>> {
+        self.loaded_models
+            .lock()
+            .unwrap()
+            .style_ids_of(voice_model_id)
+    }
+
     pub(crate) fn is_loaded_model(&self, voice_model_id: &VoiceModelId) -> bool {
         self.loaded_models
             .lock()
@@ -87,10 +303,6 @@ impl<R: InferenceRuntime, D: InferenceDomain> Status<R, D> {
         self.loaded_models.lock().unwrap().contains_style(style_id)
     }
 
-    pub(crate) fn validate_speaker_id(&self, style_id: StyleId) -> bool {
-        self.is_loaded_model_by_style_id(style_id)
-    }
-
     /// 推論を実行する。
     ///
     /// # Performance
@@ -109,8 +321,104 @@ impl<R: InferenceRuntime, D: InferenceDomain> Status<R, D> {
         I: InferenceInputSignature,
         I::Signature: InferenceSignature<Domain = D>,
     {
-        let sess = self.loaded_models.lock().unwrap().get(model_id);
-        sess.run(input)
+        // `Session`の作成には時間がかかりうるため、ロックの外で行う
+        let session_set = self.loaded_models.lock().unwrap().session_set(model_id);
+        session_set.touch(self.clock.fetch_add(1, Ordering::Relaxed));
+        let cell = session_set
+            .get::<I>()?
+            .ok_or_else(|| ErrorRepr::OperationNotSupported {
+                model_id: model_id.clone(),
+                operation: D::Operation::NAMES[I::Signature::OPERATION],
+            })?;
+        let sess = cell.acquire()?;
+        // 予算を超えうるのは`Session`が作られたときのみ
+        if (cell.created || sess.created) && !self.budget.is_unlimited() {
+            self.enforce_budget(model_id);
+        }
+        cell.run(sess, input)
+    }
+
+    /// `Session`を持つ音声モデルが予算を超えていれば、最も長く使われていない音声モデルから順に
+    /// `Session`を破棄する。
+    ///
+    /// `Session`が作られたときにのみ呼ばれる。`current_id`の`Session`は破棄しない。
+    fn enforce_budget(&self, current_id: &VoiceModelId) {
+        let session_sets = self
+            .loaded_models
+            .lock()
+            .unwrap()
+            .models
+            .iter()
+            .map(|(model_id, LoadedModel { session_set, .. })| {
+                (model_id.clone(), session_set.clone())
+            })
+            .collect::<Vec<_>>();
+
+        // 破棄されたが推論中である`SessionPool`も、まだメモリを使っているため数える
+        let mut num_bytes = session_sets
+            .iter()
+            .map(|(_, session_set)| session_set.estimated_size())
+            .sum::<usize>();
+
+        let mut others = session_sets
+            .into_iter()
+            .filter(|(model_id, session_set)| model_id != current_id && session_set.is_resident())
+            .collect::<Vec<_>>();
+        others.sort_by_key(|(_, session_set)| session_set.last_used());
+        let mut num_models = others.len() + 1;
+
+        for (model_id, session_set) in others {
+            if self.budget.accepts(num_models, num_bytes) {
+                break;
+            }
+            num_models -= 1;
+            num_bytes -= session_set.estimated_size();
+            session_set.evict();
+            info!("予算を超えたため、音声モデル`{model_id}`の`Session`を破棄しました");
+        }
+    }
+}
+
+/// `Session`を持つ音声モデルについての予算。
+#[derive(Default, Clone, Copy, Debug)]
+pub(crate) struct SessionBudget {
+    /// `Session`を持つ音声モデルの数の上限。
+    pub(crate) max_models: Option<NonZeroUsize>,
+    /// `Session`が使うメモリの見積もりの上限(バイト)。
+    ///
+    /// 見積もりは、作られた`Session`の数とモデルの大きさから計算される。
+    pub(crate) max_bytes: Option<NonZeroUsize>,
+}
+
+impl SessionBudget {
+    fn is_unlimited(self) -> bool {
+        self.max_models.is_none() && self.max_bytes.is_none()
+    }
+
+    fn accepts(self, num_models: usize, num_bytes: usize) -> bool {
+        self.max_models.map_or(true, |max| num_models <= max.get())
+            && self.max_bytes.map_or(true, |max| num_bytes <= max.get())
+    }
+}
+
+/// 音声モデルごとの`Session`の設定。
+pub(crate) struct ModelSessionOptions<D: InferenceDomain> {
+    pub(crate) sessions: EnumMap<D::Operation, InferenceSessionOptions>,
+
+    /// それぞれの推論操作が、`Session`のプールをどの推論操作と共有するか。
+    ///
+    /// 共有しない推論操作は自分自身を指し、共有される推論操作も自分自身を指さなければならない。
+    /// 共有する推論操作の`Session`は組として作られ、同時に推論できる数の上限(指している推論操作の
+    /// `max_sessions`)、破棄、予算での見積もりも組単位となる。
+    pub(crate) pool_owners: EnumMap<D::Operation, D::Operation>,
+}
+
+impl<D: InferenceDomain> Clone for ModelSessionOptions<D> {
+    fn clone(&self) -> Self {
+        Self {
+            sessions: EnumMap::from_fn(|op| self.sessions[op].clone()),
+            pool_owners: EnumMap::from_fn(|op| self.pool_owners[op]),
+        }
     }
 }
 
@@ -119,62 +427,120 @@ impl<R: InferenceRuntime, D: InferenceDomain> Status<R, D> {
 /// この構造体のメソッドは、すべて一瞬で完了すべきである。
 #[derive(Educe)]
 #[educe(Default(bound = "R: InferenceRuntime, D: InferenceDomain"))]
-struct LoadedModels<R: InferenceRuntime, D: InferenceDomain>(
-    IndexMap<VoiceModelId, LoadedModel<R, D>>,
-);
+struct LoadedModels<R: InferenceRuntime, D: InferenceDomain> {
+    models: IndexMap<VoiceModelId, LoadedModel<R, D>>,
+    /// スタイルIDから、それを持つ音声モデルとその中でのIDへの索引。`models`の追加/削除の際に
+    /// 更新される。
+    style_index: HashMap<StyleId, (VoiceModelId, ModelInnerId)>,
+}
 
 struct LoadedModel<R: InferenceRuntime, D: InferenceDomain> {
+    /// 付け替え後のスタイルIDから、音声モデル内のIDへの対応。
     model_inner_ids: BTreeMap<StyleId, ModelInnerId>,
+    /// スタイルIDが付け替えられたメタ情報。
     metas: VoiceModelMeta,
-    session_set: SessionSet<R, D>,
+    /// 話者の追加情報を読むための、音声モデルのヘッダ。
+    header: VoiceModelHeader,
+    /// `Session`の集合。音声モデルごとの`Session`の設定も持つ。
+    session_set: Arc<SessionSet<R, D>>,
+    /// 再読み込みの際に引き継ぐ、`Session`の設定。
+    session_options: ModelSessionOptions<D>,
+    /// 再読み込みの際に引き継ぐ、スタイルIDの付け替えの方針。
+    style_id_remapping: StyleIdRemapping,
+    /// 元のスタイルIDから、付け替え後のスタイルIDへの対応。
+    ///
+    /// [`StyleIdRemapping::AutoAssign`]の場合、再読み込みの際にはなるべく同じ付け替えを行う。
+    style_id_map: BTreeMap<StyleId, StyleId>,
+}
+
+/// スタイルIDの付け替えの結果。
+struct RemappedStyles {
+    model_inner_ids: BTreeMap<StyleId, ModelInnerId>,
+    metas: VoiceModelMeta,
+    style_id_map: BTreeMap<StyleId, StyleId>,
 }
 
 impl<R: InferenceRuntime, D: InferenceDomain> LoadedModels<R, D> {
     fn metas(&self) -> VoiceModelMeta {
-        metas::merge(self.0.values().flat_map(|LoadedModel { metas, .. }| metas))
+        metas::merge(
+            self.models
+                .values()
+                .flat_map(|LoadedModel { metas, .. }| metas),
+        )
     }
 
-    fn ids_for(&self, style_id: StyleId) -> Result<(VoiceModelId, ModelInnerId)> {
-        let (
-            model_id,
-            LoadedModel {
-                model_inner_ids, ..
-            },
-        ) = self
-            .0
-            .iter()
-            .find(|(_, LoadedModel { metas, .. })| {
+    /// 話者のメタ情報を、[`metas::merge`]でまとめたものと音声モデルごとのものに分けて返す。
+    fn speakers(
+        &self,
+        speaker_uuid: &str,
+    ) -> Result<(SpeakerMeta, Vec<(VoiceModelHeader, SpeakerMeta)>)> {
+        let sources = self
+            .models
+            .values()
+            .flat_map(|LoadedModel { metas, header, .. }| {
                 metas
                     .iter()
-                    .flat_map(SpeakerMeta::styles)
-                    .any(|style| *style.id() == style_id)
+                    .filter(|speaker| speaker.speaker_uuid() == speaker_uuid)
+                    .map(|speaker| (header.clone(), speaker.clone()))
             })
-            .ok_or(ErrorRepr::StyleNotFound { style_id })?;
+            .collect::<Vec<_>>();
+        let speaker = metas::merge(sources.iter().map(|(_, speaker)| speaker))
+            .into_iter()
+            .next()
+            .ok_or_else(|| ErrorRepr::SpeakerNotFound {
+                speaker_uuid: speaker_uuid.to_owned(),
+            })?;
+        Ok((speaker, sources))
+    }
 
-        let model_inner_id = *model_inner_ids
+    fn ids_for(&self, style_id: StyleId) -> Result<(VoiceModelId, ModelInnerId)> {
+        let (model_id, model_inner_id) = self
+            .style_index
             .get(&style_id)
-            .expect("`model_inner_ids` should contains all of the style IDs in the model");
+            .ok_or(ErrorRepr::StyleNotFound { style_id })?;
+        Ok((model_id.clone(), *model_inner_id))
+    }
+
+    fn style_type_of(&self, style_id: StyleId) -> Result<StyleType> {
+        let (model_id, _) = self.ids_for(style_id)?;
+        let style = self.models[&model_id]
+            .metas
+            .iter()
+            .flat_map(SpeakerMeta::styles)
+            .find(|style| *style.id() == style_id)
+            .expect("`style_index`と`metas`は対応しているはず");
+        Ok(*style.r#type())
+    }
+
+    fn style_ids_of(&self, model_id: &VoiceModelId) -> Result<Vec<StyleId>> {
+        let LoadedModel {
+            model_inner_ids, ..
+        } = self.get(model_id)?;
+        Ok(model_inner_ids.keys().copied().collect())
+    }
 
-        Ok((model_id.clone(), model_inner_id))
+    fn get(&self, model_id: &VoiceModelId) -> Result<&LoadedModel<R, D>> {
+        self.models.get(model_id).ok_or_else(|| {
+            ErrorRepr::ModelNotFound {
+                model_id: model_id.clone(),
+            }
+            .into()
+        })
     }
 
     /// # Panics
     ///
     /// `self`が`model_id`を含んでいないとき、パニックする。
-    fn get<I>(&self, model_id: &VoiceModelId) -> SessionCell<R, I>
-    where
-        I: InferenceInputSignature,
-        I::Signature: InferenceSignature<Domain = D>,
-    {
-        self.0[model_id].session_set.get()
+    fn session_set(&self, model_id: &VoiceModelId) -> Arc<SessionSet<R, D>> {
+        self.models[model_id].session_set.clone()
     }
 
     fn contains_voice_model(&self, model_id: &VoiceModelId) -> bool {
-        self.0.contains_key(model_id)
+        self.models.contains_key(model_id)
     }
 
     fn contains_style(&self, style_id: StyleId) -> bool {
-        self.styles().any(|style| *style.id() == style_id)
+        self.style_index.contains_key(&style_id)
     }
 
     /// 音声モデルを受け入れ可能かをチェックする。
@@ -183,161 +549,713 @@ impl<R: InferenceRuntime, D: InferenceDomain> LoadedModels<R, D> {
     ///
     /// 次の場合にエラーを返す。
     ///
-    /// - 音声モデルIDかスタイルIDが`model_header`と重複するとき
-    fn ensure_acceptable(&self, model_header: &VoiceModelHeader) -> LoadModelResult<()> {
+    /// - 音声モデルIDが`model_header`と重複するとき
+    /// - `style_id_remapping`に従って付け替えたスタイルIDが重複するとき
+    ///
+    /// `replacing`が指定されている場合、その音声モデルを`model_header`で置き換えるものとしてチェック
+    /// する。
+    fn ensure_acceptable(
+        &self,
+        model_header: &VoiceModelHeader,
+        style_id_remapping: &StyleIdRemapping,
+        replacing: Option<&VoiceModelId>,
+    ) -> LoadModelResult<RemappedStyles> {
         let error = |context| LoadModelError {
-            path: model_header.path.clone(),
+            path: model_header.source.display_path(),
             context,
             source: None,
         };
 
-        let loaded = self.speakers();
+        let loaded_speakers = self
+            .models
+            .iter()
+            .filter(|(model_id, _)| Some(*model_id) != replacing)
+            .flat_map(|(_, LoadedModel { metas, .. })| metas);
+
         let external = model_header.metas.iter();
-        for (loaded, external) in iproduct!(loaded, external) {
+        for (loaded, external) in iproduct!(loaded_speakers, external) {
             if loaded.speaker_uuid() == external.speaker_uuid() {
                 loaded.warn_diff_except_styles(external);
             }
         }
 
-        let loaded = self.styles();
-        let external = model_header
-            .metas
-            .iter()
-            .flat_map(|speaker| speaker.styles());
-        if self.0.contains_key(&model_header.id) {
+        if replacing.is_none() && self.models.contains_key(&model_header.id) {
             return Err(error(LoadModelErrorKind::ModelAlreadyLoaded {
                 id: model_header.id.clone(),
             }));
         }
-        if let Some((style, _)) =
-            iproduct!(loaded, external).find(|(loaded, external)| loaded.id() == external.id())
+
+        let style_id_map = self
+            .remap_style_ids(model_header, style_id_remapping, replacing)
+            .map_err(|id| error(LoadModelErrorKind::StyleAlreadyLoaded { id }))?;
+
+        let model_inner_ids = model_header
+            .model_inner_ids()
+            .into_iter()
+            .map(|(style_id, model_inner_id)| (style_id_map[&style_id], model_inner_id))
+            .collect();
+        let metas = model_header
+            .metas
+            .iter()
+            .map(|speaker| speaker.map_style_ids(|style_id| style_id_map[&style_id]))
+            .collect();
+        Ok(RemappedStyles {
+            model_inner_ids,
+            metas,
+            style_id_map,
+        })
+    }
+
+    /// `model_header`の元のスタイルIDから、付け替え後のスタイルIDへの対応を決める。
+    ///
+    /// 付け替え後のスタイルIDが重複する場合、そのスタイルIDをエラーとして返す。
+    fn remap_style_ids(
+        &self,
+        model_header: &VoiceModelHeader,
+        style_id_remapping: &StyleIdRemapping,
+        replacing: Option<&VoiceModelId>,
+    ) -> std::result::Result<BTreeMap<StyleId, StyleId>, StyleId> {
+        let is_taken = |style_id: &StyleId| {
+            self.style_index
+                .get(style_id)
+                .is_some_and(|(model_id, _)| Some(model_id) != replacing)
+        };
+        let prev_style_id_map = replacing
+            .and_then(|model_id| self.models.get(model_id))
+            .map(|LoadedModel { style_id_map, .. }| style_id_map);
+
+        let mut style_id_map = BTreeMap::new();
+        let mut used = BTreeSet::new();
+        let mut unassigned = vec![];
+
+        for style in model_header
+            .metas
+            .iter()
+            .flat_map(|speaker| speaker.styles())
         {
-            return Err(error(LoadModelErrorKind::StyleAlreadyLoaded {
-                id: *style.id(),
-            }));
+            let original = *style.id();
+            let preferred = match style_id_remapping {
+                StyleIdRemapping::None => original,
+                StyleIdRemapping::Explicit(map) => *map.get(&original).unwrap_or(&original),
+                StyleIdRemapping::AutoAssign => *prev_style_id_map
+                    .and_then(|map| map.get(&original))
+                    .unwrap_or(&original),
+            };
+            if !is_taken(&preferred) && used.insert(preferred) {
+                style_id_map.insert(original, preferred);
+            } else if *style_id_remapping == StyleIdRemapping::AutoAssign {
+                unassigned.push(original);
+            } else {
+                return Err(preferred);
+            }
         }
-        Ok(())
+
+        // 使われているスタイルIDの最大値の次から順に割り当てる
+        let next = self
+            .style_index
+            .keys()
+            .chain(&used)
+            .map(|style_id| u64::from(style_id.raw_id()) + 1)
+            .max()
+            .unwrap_or(0);
+        for (original, raw_id) in unassigned.into_iter().zip(next..) {
+            let style_id = StyleId::new(raw_id.try_into().map_err(|_| original)?);
+            style_id_map.insert(original, style_id);
+        }
+        Ok(style_id_map)
     }
 
     fn insert(
         &mut self,
         model_header: &VoiceModelHeader,
         session_set: SessionSet<R, D>,
+        session_options: &ModelSessionOptions<D>,
+        style_id_remapping: &StyleIdRemapping,
     ) -> Result<()> {
-        self.ensure_acceptable(model_header)?;
+        let RemappedStyles {
+            model_inner_ids,
+            metas,
+            style_id_map,
+        } = self.ensure_acceptable(model_header, style_id_remapping, None)?;
 
-        let prev = self.0.insert(
+        self.index_styles(&model_header.id, &model_inner_ids);
+        let prev = self.models.insert(
             model_header.id.clone(),
             LoadedModel {
-                model_inner_ids: model_header.model_inner_ids(),
-                metas: model_header.metas.clone(),
-                session_set,
+                model_inner_ids,
+                metas,
+                header: model_header.clone(),
+                session_set: session_set.into(),
+                session_options: session_options.clone(),
+                style_id_remapping: style_id_remapping.clone(),
+                style_id_map,
             },
         );
         assert!(prev.is_none());
         Ok(())
     }
 
+    fn replace(
+        &mut self,
+        model_id: &VoiceModelId,
+        model_header: &VoiceModelHeader,
+        session_set: SessionSet<R, D>,
+    ) -> Result<()> {
+        // ロックを外していた間に読み込みが解除されたか、他の音声モデルが読み込まれた可能性がある
+        let style_id_remapping = self.get(model_id)?.style_id_remapping.clone();
+        let RemappedStyles {
+            model_inner_ids,
+            metas,
+            style_id_map,
+        } = self.ensure_acceptable(model_header, &style_id_remapping, Some(model_id))?;
+        let loaded_model = self
+            .models
+            .get_mut(model_id)
+            .expect("should have been checked");
+
+        let prev_model_inner_ids =
+            std::mem::replace(&mut loaded_model.model_inner_ids, model_inner_ids.clone());
+        loaded_model.metas = metas;
+        loaded_model.header = model_header.clone();
+        loaded_model.style_id_map = style_id_map;
+        loaded_model.session_set = session_set.into();
+
+        for style_id in prev_model_inner_ids.keys() {
+            self.style_index.remove(style_id);
+        }
+        self.index_styles(model_id, &model_inner_ids);
+        Ok(())
+    }
+
     fn remove(&mut self, model_id: &VoiceModelId) -> Result<()> {
-        if self.0.remove(model_id).is_none() {
+        let Some(LoadedModel {
+            model_inner_ids, ..
+        }) = self.models.remove(model_id)
+        else {
             return Err(ErrorRepr::ModelNotFound {
                 model_id: model_id.clone(),
             }
             .into());
+        };
+        for style_id in model_inner_ids.keys() {
+            self.style_index.remove(style_id);
         }
         Ok(())
     }
 
-    fn speakers(&self) -> impl Iterator<Item = &SpeakerMeta> + Clone {
-        self.0.values().flat_map(|LoadedModel { metas, .. }| metas)
+    fn index_styles(
+        &mut self,
+        model_id: &VoiceModelId,
+        model_inner_ids: &BTreeMap<StyleId, ModelInnerId>,
+    ) {
+        for (&style_id, &model_inner_id) in model_inner_ids {
+            self.style_index
+                .insert(style_id, (model_id.clone(), model_inner_id));
+        }
+    }
+}
+
+/// 音声モデルの`Session`の集合。
+///
+/// それぞれの推論操作の[`SessionPool`]は、最初から作られているか、遅延して作られる。遅延して作る
+/// 場合、同じ推論操作の`SessionPool`が同時に複数作られることは無い。
+///
+/// [`ModelSessionOptions::pool_owners`]によって`SessionPool`を共有する推論操作は、同じ`PoolCell`を
+/// 指す。
+///
+/// `SessionPool`は予算のために破棄されることがあり、その場合は次に使われるときに作り直される。ただし
+/// 破棄された`SessionPool`がまだ推論中であれば、作り直さずにそれを戻す。
+struct SessionSet<R: InferenceRuntime, D: InferenceDomain> {
+    pools: EnumMap<D::Operation, Arc<PoolCell<R>>>,
+    /// それぞれの推論操作が`SessionPool`を共有する相手。
+    pool_owners: EnumMap<D::Operation, D::Operation>,
+    slots: EnumMap<D::Operation, Arc<SessionSlots>>,
+    options: EnumMap<D::Operation, InferenceSessionOptions>,
+    /// それぞれの推論操作のためのモデルを、音声モデルが持っているか。
+    provided: EnumMap<D::Operation, bool>,
+    /// `SessionPool`を作る(あるいは作り直す)ときに、モデルを読むための関数。
+    read_model: ReadModel<D>,
+    source: VvmSource,
+    last_used: AtomicU64,
+}
+
+/// 破棄されうる[`SessionPool`]。
+struct PoolCell<R: InferenceRuntime> {
+    state: std::sync::Mutex<PoolState<R>>,
+    /// `SessionPool`を作っている間に取られるロック。
+    ///
+    /// 同じ`SessionPool`が同時に複数作られないようにするためのもので、`SessionPool`を作っている間
+    /// も`state`のロックは取られない。
+    building: std::sync::Mutex<()>,
+}
+
+struct PoolState<R: InferenceRuntime> {
+    resident: Option<Arc<SessionPool<R>>>,
+    /// 破棄された`SessionPool`。推論中のものがあれば、その推論が終わるまでは生きている。
+    evicted: Weak<SessionPool<R>>,
+}
+
+impl<R: InferenceRuntime> Default for PoolCell<R> {
+    fn default() -> Self {
+        Self {
+            state: std::sync::Mutex::new(PoolState {
+                resident: None,
+                evicted: Weak::new(),
+            }),
+            building: Default::default(),
+        }
+    }
+}
+
+impl<R: InferenceRuntime> PoolCell<R> {
+    fn resident(&self) -> Option<Arc<SessionPool<R>>> {
+        self.state.lock().unwrap().resident.clone()
+    }
+
+    /// `SessionPool`を得る。破棄されたがまだ推論中である`SessionPool`があれば、それを戻して返す。
+    fn get(&self) -> Option<Arc<SessionPool<R>>> {
+        let mut state = self.state.lock().unwrap();
+        if state.resident.is_none() {
+            state.resident = state.evicted.upgrade();
+        }
+        state.resident.clone()
+    }
+
+    fn insert(&self, pool: Arc<SessionPool<R>>) {
+        self.state.lock().unwrap().resident = Some(pool);
+    }
+
+    /// `SessionPool`を破棄する。推論中の`Session`は、その推論が終わった後に破棄される。
+    fn evict(&self) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(pool) = state.resident.take() {
+            state.evicted = Arc::downgrade(&pool);
+        }
     }
 
-    fn styles(&self) -> impl Iterator<Item = &StyleMeta> {
-        self.speakers().flat_map(|speaker| speaker.styles())
+    /// 破棄されたがまだ推論中である`SessionPool`も含めた、メモリの見積もり。
+    fn estimated_size(&self) -> usize {
+        let pool = {
+            let state = self.state.lock().unwrap();
+            state.resident.clone().or_else(|| state.evicted.upgrade())
+        };
+        pool.map_or(0, |pool| pool.estimated_size())
     }
 }
 
-struct SessionSet<R: InferenceRuntime, D: InferenceDomain>(
-    EnumMap<D::Operation, Arc<std::sync::Mutex<R::Session>>>,
-);
+type ReadModel<D> =
+    Box<dyn Fn(<D as InferenceDomain>::Operation) -> LoadModelResult<Vec<u8>> + Send + Sync>;
 
 impl<R: InferenceRuntime, D: InferenceDomain> SessionSet<R, D> {
     fn new(
-        model_bytes: &EnumMap<D::Operation, Vec<u8>>,
-        options: &EnumMap<D::Operation, InferenceSessionOptions>,
-    ) -> anyhow::Result<Self> {
-        let mut sessions = model_bytes
+        source: &VvmSource,
+        model_bytes: &EnumMap<D::Operation, Option<Vec<u8>>>,
+        read_model: impl Fn(D::Operation) -> LoadModelResult<Vec<u8>> + Send + Sync + 'static,
+        options: &ModelSessionOptions<D>,
+    ) -> LoadModelResult<Self> {
+        let provided = EnumMap::from_fn(|op| model_bytes[op].is_some());
+        let session_set = Self::new_lazy(source, provided, read_model, options);
+        for (op, _) in session_set
+            .provided
             .iter()
-            .map(|(op, model_bytes)| {
-                let (expected_input_param_infos, expected_output_param_infos) =
-                    <D::Operation as InferenceOperation>::PARAM_INFOS[op];
+            .filter(|&(_, &provided)| provided)
+        {
+            let pool = &session_set.pools[op];
+            if pool.resident().is_none() {
+                let models = session_set
+                    .pool_members(op)
+                    .into_iter()
+                    .map(|op| (op, model_bytes[op].as_deref().expect("should be provided")))
+                    .collect::<Vec<_>>();
+                pool.insert(session_set.new_pool(&models)?);
+            }
+        }
+        Ok(session_set)
+    }
 
-                let (sess, actual_input_param_infos, actual_output_param_infos) =
-                    R::new_session(|| model_file::decrypt(model_bytes), options[op])?;
+    fn new_lazy(
+        source: &VvmSource,
+        provided: EnumMap<D::Operation, bool>,
+        read_model: impl Fn(D::Operation) -> LoadModelResult<Vec<u8>> + Send + Sync + 'static,
+        options: &ModelSessionOptions<D>,
+    ) -> Self {
+        let ModelSessionOptions {
+            sessions: options,
+            pool_owners,
+        } = options;
 
-                check_param_infos(expected_input_param_infos, &actual_input_param_infos)?;
-                check_param_infos(expected_output_param_infos, &actual_output_param_infos)?;
+        let pools = EnumMap::<D::Operation, Arc<PoolCell<R>>>::from_fn(|_| Default::default());
+        let slots = EnumMap::<D::Operation, _>::from_fn(|op| {
+            Arc::new(SessionSlots::new(options[op].max_sessions))
+        });
 
-                Ok((op.into_usize(), std::sync::Mutex::new(sess).into()))
-            })
-            .collect::<anyhow::Result<HashMap<_, _>>>()?;
-
-        return Ok(Self(EnumMap::<D::Operation, _>::from_fn(|k| {
-            sessions.remove(&k.into_usize()).expect("should exist")
-        })));
-
-        fn check_param_infos<D: PartialEq + Display>(
-            expected: &[ParamInfo<D>],
-            actual: &[ParamInfo<D>],
-        ) -> anyhow::Result<()> {
-            if !(expected.len() == actual.len()
-                && itertools::zip_eq(expected, actual)
-                    .all(|(expected, actual)| expected.accepts(actual)))
-            {
-                let expected = display_param_infos(expected);
-                let actual = display_param_infos(actual);
-                bail!("expected {{{expected}}}, got {{{actual}}}")
-            }
-            Ok(())
+        Self {
+            pools: EnumMap::from_fn(|op| pools[pool_owners[op]].clone()),
+            pool_owners: EnumMap::from_fn(|op| pool_owners[op]),
+            slots: EnumMap::from_fn(|op| slots[pool_owners[op]].clone()),
+            options: EnumMap::from_fn(|op| options[op].clone()),
+            provided,
+            read_model: Box::new(read_model),
+            source: source.clone(),
+            last_used: AtomicU64::new(0),
         }
+    }
 
-        fn display_param_infos(infos: &[ParamInfo<impl Display>]) -> impl Display {
-            infos
+    /// `op`と`SessionPool`を共有する推論操作のうち、音声モデルが提供しているものを列挙する。`op`
+    /// 自身も含む。
+    ///
+    /// 順序は`SessionPool`の中での`Session`の順序となる。
+    fn pool_members(&self, op: D::Operation) -> Vec<D::Operation> {
+        let owner = self.pool_owners[op].into_usize();
+        self.provided
+            .iter()
+            .filter(|&(other, &provided)| provided && self.pool_owners[other].into_usize() == owner)
+            .map(|(other, _)| other)
+            .collect()
+    }
+
+    fn new_pool(&self, models: &[(D::Operation, &[u8])]) -> LoadModelResult<Arc<SessionPool<R>>> {
+        (|| {
+            let (&(op, _), _) = models.split_first().expect("should not be empty");
+            let members = models
                 .iter()
-                .map(|ParamInfo { name, dt, ndim }| {
-                    let brackets = match *ndim {
-                        Some(ndim) => "[]".repeat(ndim),
-                        None => "[]...".to_owned(),
-                    };
-                    format!("{name}: {dt}{brackets}")
+                .map(|&(op, model_bytes)| {
+                    let sess =
+                        new_checked_session::<R, D>(op, model_bytes, self.options[op].clone())?;
+                    Ok((sess, model_bytes, self.options[op].clone()))
                 })
-                .join(", ")
-        }
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            Ok(SessionPool::new(members, self.slots[op].clone()).into())
+        })()
+        .map_err(|source| LoadModelError {
+            path: self.source.display_path(),
+            context: LoadModelErrorKind::InvalidModelData,
+            source: Some(source),
+        })
     }
-}
 
-impl<R: InferenceRuntime, D: InferenceDomain> SessionSet<R, D> {
-    fn get<I>(&self) -> SessionCell<R, I>
+    /// 推論操作の`Session`を得る。音声モデルがその推論操作を提供していない場合は`None`を返す。
+    fn get<I>(&self) -> LoadModelResult<Option<SessionCell<R, I>>>
     where
         I: InferenceInputSignature,
         I::Signature: InferenceSignature<Domain = D>,
     {
-        SessionCell {
-            inner: self.0[I::Signature::OPERATION].clone(),
+        let op = I::Signature::OPERATION;
+        if !self.provided[op] {
+            return Ok(None);
+        }
+        let members = self.pool_members(op);
+        let member = members
+            .iter()
+            .position(|&other| other.into_usize() == op.into_usize())
+            .expect("should contain itself");
+
+        let cell = &self.pools[op];
+        let get_or_build = || {
+            if let Some(pool) = cell.get() {
+                return Ok((pool, false));
+            }
+            // モデルの読み込みと`Session`の作成には時間がかかりうるため、`state`のロックの外で行う
+            let _building = cell.building.lock().unwrap();
+            if let Some(pool) = cell.get() {
+                return Ok((pool, false));
+            }
+            let models = members
+                .into_iter()
+                .map(|op| Ok((op, (self.read_model)(op)?)))
+                .collect::<LoadModelResult<Vec<_>>>()?;
+            let models = models
+                .iter()
+                .map(|(op, model_bytes)| (*op, &**model_bytes))
+                .collect::<Vec<_>>();
+            let pool = self.new_pool(&models)?;
+            cell.insert(pool.clone());
+            Ok((pool, true))
+        };
+        let (pool, created) = get_or_build()?;
+        Ok(Some(SessionCell {
+            inner: pool,
+            member,
+            created,
             marker: PhantomData,
+        }))
+    }
+
+    /// 共有されているものは一度ずつ列挙する。
+    fn distinct_pools(&self) -> impl Iterator<Item = &PoolCell<R>> {
+        self.pools
+            .values()
+            .unique_by(|pool| Arc::as_ptr(pool))
+            .map(|pool| &**pool)
+    }
+
+    fn touch(&self, now: u64) {
+        self.last_used.fetch_max(now, Ordering::Relaxed);
+    }
+
+    fn last_used(&self) -> u64 {
+        self.last_used.load(Ordering::Relaxed)
+    }
+
+    fn is_resident(&self) -> bool {
+        self.distinct_pools().any(|pool| pool.resident().is_some())
+    }
+
+    fn estimated_size(&self) -> usize {
+        self.distinct_pools().map(PoolCell::estimated_size).sum()
+    }
+
+    /// すべての`SessionPool`を破棄する。推論中の`Session`は、その推論が終わった後に破棄される。
+    fn evict(&self) {
+        for pool in self.distinct_pools() {
+            pool.evict();
         }
     }
 }
 
+/// モデルから`Session`を作り、その入出力が推論操作のシグネチャと合っているかを確かめる。
+pub(crate) fn new_checked_session<R: InferenceRuntime, D: InferenceDomain>(
+    op: D::Operation,
+    model_bytes: &[u8],
+    options: InferenceSessionOptions,
+) -> anyhow::Result<R::Session> {
+    let (expected_input_param_infos, expected_output_param_infos) =
+        <D::Operation as InferenceOperation>::PARAM_INFOS[op];
+
+    let (sess, actual_input_param_infos, actual_output_param_infos) =
+        R::new_session(|| model_file::decrypt(model_bytes), options)?;
+
+    check_param_infos(expected_input_param_infos, &actual_input_param_infos)?;
+    check_param_infos(expected_output_param_infos, &actual_output_param_infos)?;
+    Ok(sess)
+}
+
+fn check_param_infos<D: ScalarKind + Display>(
+    expected: &[ParamInfo<D>],
+    actual: &[ParamInfo<D>],
+) -> anyhow::Result<()> {
+    if !(expected.len() == actual.len()
+        && itertools::zip_eq(expected, actual).all(|(expected, actual)| expected.accepts(actual)))
+    {
+        let expected = display_param_infos(expected);
+        let actual = display_param_infos(actual);
+        bail!("expected {{{expected}}}, got {{{actual}}}")
+    }
+    Ok(())
+}
+
+fn display_param_infos(infos: &[ParamInfo<impl Display>]) -> impl Display {
+    infos
+        .iter()
+        .map(|ParamInfo { name, dt, ndim }| {
+            let brackets = match *ndim {
+                Some(ndim) => "[]".repeat(ndim),
+                None => "[]...".to_owned(),
+            };
+            format!("{name}: {dt}{brackets}")
+        })
+        .join(", ")
+}
+
+/// ある推論操作(あるいは、プールを共有する推論操作の組)のための`Session`のプール。
+///
+/// プールは`Session`を、推論操作ごとに一つずつの`Session`から成る組として持つ。プールを共有しない
+/// 場合、組は一つの`Session`のみから成る。組は最初の1つを除いて必要になったときに作られる。同時に
+/// 使われる組の数は[`SessionSlots`]によって制限され、上限に達した後の要求は他の推論が終わるまで
+/// 待たされる。
+struct SessionPool<R: InferenceRuntime> {
+    idle: std::sync::Mutex<Vec<Vec<R::Session>>>,
+    /// 作られた組の数。
+    num_sessions: AtomicUsize,
+    members: Vec<PoolMember>,
+    slots: Arc<SessionSlots>,
+}
+
+/// [`SessionPool`]の組の中の、一つの推論操作についての情報。
+struct PoolMember {
+    model_size: usize,
+    /// 2つ目以降の組を作るためのモデル。上限が1の場合は持たない。
+    model_bytes: Option<Vec<u8>>,
+    options: InferenceSessionOptions,
+}
+
+impl<R: InferenceRuntime> SessionPool<R> {
+    fn new(
+        members: Vec<(R::Session, &[u8], InferenceSessionOptions)>,
+        slots: Arc<SessionSlots>,
+    ) -> Self {
+        let (sessions, members) = members
+            .into_iter()
+            .map(|(sess, model_bytes, options)| {
+                let member = PoolMember {
+                    model_size: model_bytes.len(),
+                    model_bytes: (slots.max.get() > 1).then(|| model_bytes.to_owned()),
+                    options,
+                };
+                (sess, member)
+            })
+            .unzip();
+        Self {
+            idle: std::sync::Mutex::new(vec![sessions]),
+            num_sessions: AtomicUsize::new(1),
+            members,
+            slots,
+        }
+    }
+
+    /// 空いている組を取り出し、その`member`番目の`Session`を使えるようにする。空きが無ければ新たに
+    /// 作る。
+    fn acquire(&self, member: usize) -> anyhow::Result<PooledSession<'_, R>> {
+        self.slots.acquire();
+        let sessions = self.idle.lock().unwrap().pop();
+        let (sessions, created) = match sessions {
+            Some(sessions) => (sessions, false),
+            None => (
+                self.new_sessions().inspect_err(|_| self.slots.release())?,
+                true,
+            ),
+        };
+        Ok(PooledSession {
+            pool: self,
+            member,
+            sessions: Some(sessions),
+            created,
+        })
+    }
+
+    fn new_sessions(&self) -> anyhow::Result<Vec<R::Session>> {
+        let sessions = self
+            .members
+            .iter()
+            .map(|member| {
+                let model_bytes = member
+                    .model_bytes
+                    .as_ref()
+                    .expect("should be present when the limit is greater than 1");
+                let (sess, _, _) =
+                    R::new_session(|| model_file::decrypt(model_bytes), member.options.clone())?;
+                Ok(sess)
+            })
+            .collect::<anyhow::Result<_>>()?;
+        self.num_sessions.fetch_add(1, Ordering::Relaxed);
+        Ok(sessions)
+    }
+
+    /// このプールが使うメモリの見積もり。
+    ///
+    /// ワーカープロセスの中の`Session`については、それを作り直すためにこのプロセスが保持する復号
+    /// 済みのモデルの分も数える。
+    fn estimated_size(&self) -> usize {
+        let num_sessions = self.num_sessions.load(Ordering::Relaxed);
+        self.members
+            .iter()
+            .map(|member| {
+                let retained = member.model_bytes.as_ref().map_or(0, Vec::len);
+                let per_session = if member.options.worker.is_some() {
+                    member.model_size * 2
+                } else {
+                    member.model_size
+                };
+                retained + per_session * num_sessions
+            })
+            .sum()
+    }
+}
+
+/// 同時に推論できる数の上限。複数の[`SessionPool`]で共有されうる。
+struct SessionSlots {
+    in_use: std::sync::Mutex<usize>,
+    released: Condvar,
+    max: NonZeroUsize,
+}
+
+impl SessionSlots {
+    fn new(max: NonZeroUsize) -> Self {
+        Self {
+            in_use: Default::default(),
+            released: Condvar::new(),
+            max,
+        }
+    }
+
+    fn acquire(&self) {
+        let mut in_use = self.in_use.lock().unwrap();
+        while *in_use >= self.max.get() {
+            in_use = self.released.wait(in_use).unwrap();
+        }
+        *in_use += 1;
+    }
+
+    fn release(&self) {
+        *self.in_use.lock().unwrap() -= 1;
+        self.released.notify_one();
+    }
+}
+
+/// [`SessionPool`]から取り出された`Session`の組。dropされるとプールに戻る。
+struct PooledSession<'a, R: InferenceRuntime> {
+    pool: &'a SessionPool<R>,
+    /// 組の中で使う`Session`の位置。
+    member: usize,
+    sessions: Option<Vec<R::Session>>,
+    /// この組が新たに作られたものか。
+    created: bool,
+}
+
+impl<R: InferenceRuntime> Deref for PooledSession<'_, R> {
+    type Target = R::Session;
+
+    fn deref(&self) -> &Self::Target {
+        &self
+            .sessions
+            .as_ref()
+            .expect("should be present until dropped")[self.member]
+    }
+}
+
+impl<R: InferenceRuntime> DerefMut for PooledSession<'_, R> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self
+            .sessions
+            .as_mut()
+            .expect("should be present until dropped")[self.member]
+    }
+}
+
+impl<R: InferenceRuntime> Drop for PooledSession<'_, R> {
+    fn drop(&mut self) {
+        let sessions = self
+            .sessions
+            .take()
+            .expect("should be present until dropped");
+        self.pool.idle.lock().unwrap().push(sessions);
+        self.pool.slots.release();
+    }
+}
+
 struct SessionCell<R: InferenceRuntime, I> {
-    inner: Arc<std::sync::Mutex<R::Session>>,
+    inner: Arc<SessionPool<R>>,
+    /// `inner`の組の中で使う`Session`の位置。
+    member: usize,
+    /// `inner`が新たに作られたものか。
+    created: bool,
     marker: PhantomData<fn(I)>,
 }
 
 impl<R: InferenceRuntime, I: InferenceInputSignature> SessionCell<R, I> {
-    fn run(self, input: I) -> crate::Result<<I::Signature as InferenceSignature>::Output> {
-        let inner = &mut self.inner.lock().unwrap();
-        let ctx = input.make_run_context::<R>(inner);
+    fn acquire(&self) -> crate::Result<PooledSession<'_, R>> {
+        self.inner
+            .acquire(self.member)
+            .map_err(|e| ErrorRepr::InferenceFailed(e).into())
+    }
+
+    fn run(
+        &self,
+        mut sess: PooledSession<'_, R>,
+        input: I,
+    ) -> crate::Result<<I::Signature as InferenceSignature>::Output> {
+        let ctx = input.make_run_context::<R>(&mut sess);
         R::run(ctx)
             .and_then(TryInto::try_into)
             .map_err(|e| ErrorRepr::InferenceFailed(e).into())
@@ -346,18 +1264,28 @@ impl<R: InferenceRuntime, I: InferenceInputSignature> SessionCell<R, I> {
 
 #[cfg(test)]
 mod tests {
+    use std::{
+        num::NonZeroUsize,
+        sync::{atomic::Ordering, Arc, Barrier},
+    };
+
     use enum_map::enum_map;
     use pretty_assertions::assert_eq;
     use rstest::rstest;
 
     use crate::{
-        infer::domain::{InferenceDomainImpl, InferenceOperationImpl},
+        infer::domain::{
+            DecodeInput, InferenceDomainImpl, InferenceOperationImpl, PredictDurationInput,
+            PredictIntonationInput,
+        },
         macros::tests::assert_debug_fmt_eq,
         synthesizer::InferenceRuntimeImpl,
         test_util::open_default_vvm_file,
+        voice_model::VoiceModelHeader,
+        ErrorKind, StyleId, StyleIdRemapping, StyleType, VoiceModelId,
     };
 
-    use super::{super::InferenceSessionOptions, Status};
+    use super::{super::InferenceSessionOptions, SessionBudget, Status};
 
     #[rstest]
     #[case(true, 0)]
@@ -368,12 +1296,14 @@ mod tests {
     #[case(false, 8)]
     #[case(false, 0)]
     fn status_new_works(#[case] use_gpu: bool, #[case] cpu_num_threads: u16) {
-        let light_session_options = InferenceSessionOptions::new(cpu_num_threads, false);
-        let heavy_session_options = InferenceSessionOptions::new(cpu_num_threads, use_gpu);
+        let light_session_options =
+            InferenceSessionOptions::new(cpu_num_threads, false, NonZeroUsize::MIN);
+        let heavy_session_options =
+            InferenceSessionOptions::new(cpu_num_threads, use_gpu, NonZeroUsize::MIN);
         let session_options = enum_map! {
             InferenceOperationImpl::PredictDuration
-            | InferenceOperationImpl::PredictIntonation => light_session_options,
-            InferenceOperationImpl::Decode => heavy_session_options,
+            | InferenceOperationImpl::PredictIntonation => light_session_options.clone(),
+            InferenceOperationImpl::Decode => heavy_session_options.clone(),
         };
         let status = Status::<InferenceRuntimeImpl, InferenceDomainImpl>::new(session_options);
 
@@ -390,27 +1320,33 @@ mod tests {
             status.session_options[InferenceOperationImpl::Decode],
         );
 
-        assert!(status.loaded_models.lock().unwrap().0.is_empty());
+        assert!(status.loaded_models.lock().unwrap().models.is_empty());
     }
 
     #[rstest]
     #[tokio::test]
     async fn status_load_model_works() {
         let status = Status::<InferenceRuntimeImpl, InferenceDomainImpl>::new(
-            enum_map!(_ => InferenceSessionOptions::new(0, false)),
+            enum_map!(_ => InferenceSessionOptions::new(0, false, NonZeroUsize::MIN)),
         );
         let model = &open_default_vvm_file().await;
         let model_bytes = &model.read_inference_models().await.unwrap();
-        let result = status.insert_model(model.header(), model_bytes);
+        let result = status.insert_model(
+            model.header(),
+            model_bytes,
+            model.header().model_reader(),
+            &status.default_model_session_options(),
+            &Default::default(),
+        );
         assert_debug_fmt_eq!(Ok(()), result);
-        assert_eq!(1, status.loaded_models.lock().unwrap().0.len());
+        assert_eq!(1, status.loaded_models.lock().unwrap().models.len());
     }
 
     #[rstest]
     #[tokio::test]
     async fn status_is_model_loaded_works() {
         let status = Status::<InferenceRuntimeImpl, InferenceDomainImpl>::new(
-            enum_map!(_ => InferenceSessionOptions::new(0, false)),
+            enum_map!(_ => InferenceSessionOptions::new(0, false, NonZeroUsize::MIN)),
         );
         let vvm = open_default_vvm_file().await;
         let model_header = vvm.header();
@@ -419,11 +1355,510 @@ mod tests {
             !status.is_loaded_model(&model_header.id),
             "model should  not be loaded"
         );
-        let result = status.insert_model(model_header, model_bytes);
+        let result = status.insert_model(
+            model_header,
+            model_bytes,
+            model_header.model_reader(),
+            &status.default_model_session_options(),
+            &Default::default(),
+        );
         assert_debug_fmt_eq!(Ok(()), result);
         assert!(
             status.is_loaded_model(&model_header.id),
             "model should be loaded",
         );
     }
+
+    #[rstest]
+    #[tokio::test]
+    async fn status_run_session_in_parallel_works() {
+        let max_sessions = NonZeroUsize::new(2).unwrap();
+        let status = Status::<InferenceRuntimeImpl, InferenceDomainImpl>::new(
+            enum_map!(_ => InferenceSessionOptions::new(0, false, max_sessions)),
+        );
+        let vvm = open_default_vvm_file().await;
+        let model_header = vvm.header();
+        let model_bytes = &vvm.read_inference_models().await.unwrap();
+        status
+            .insert_model(
+                model_header,
+                model_bytes,
+                model_header.model_reader(),
+                &status.default_model_session_options(),
+                &Default::default(),
+            )
+            .unwrap();
+
+        let run = || {
+            status.run_session(
+                &model_header.id,
+                PredictDurationInput {
+                    phoneme_list: ndarray::arr1(&[0, 37, 14, 35, 6, 37, 30, 0]),
+                    speaker_id: ndarray::arr1(&[0]),
+                },
+            )
+        };
+        let (result1, result2) = std::thread::scope(|s| {
+            let handle = s.spawn(run);
+            (run(), handle.join().unwrap())
+        });
+        assert!(result1.is_ok());
+        assert!(result2.is_ok());
+
+        let pool = status
+            .loaded_models
+            .lock()
+            .unwrap()
+            .session_set(&model_header.id)
+            .pools[InferenceOperationImpl::PredictDuration]
+            .resident()
+            .unwrap();
+
+        // 上限までの`Session`を同時に取り出させ、プールが上限まで大きくなることを確かめる
+        let barrier = Barrier::new(max_sessions.get());
+        std::thread::scope(|s| {
+            for _ in 0..max_sessions.get() {
+                s.spawn(|| {
+                    let _sess = pool.acquire(0).unwrap();
+                    barrier.wait();
+                });
+            }
+        });
+        assert_eq!(
+            max_sessions.get(),
+            pool.num_sessions.load(Ordering::Relaxed),
+        );
+        assert_eq!(max_sessions.get(), pool.idle.lock().unwrap().len());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn status_load_model_with_shared_pools_works() {
+        let status = Status::<InferenceRuntimeImpl, InferenceDomainImpl>::new(
+            enum_map!(_ => InferenceSessionOptions::new(0, false, NonZeroUsize::MIN)),
+        );
+        let mut session_options = status.default_model_session_options();
+        session_options.sessions[InferenceOperationImpl::Decode].cpu_num_threads = 1;
+        session_options.pool_owners[InferenceOperationImpl::PredictIntonation] =
+            InferenceOperationImpl::PredictDuration;
+
+        let vvm = open_default_vvm_file().await;
+        let model_header = vvm.header();
+        let model_bytes = &vvm.read_inference_models().await.unwrap();
+        let result = status.insert_model(
+            model_header,
+            model_bytes,
+            model_header.model_reader(),
+            &session_options,
+            &Default::default(),
+        );
+        assert_debug_fmt_eq!(Ok(()), result);
+
+        let session_set = status
+            .loaded_models
+            .lock()
+            .unwrap()
+            .session_set(&model_header.id);
+        assert!(Arc::ptr_eq(
+            &session_set.pools[InferenceOperationImpl::PredictDuration],
+            &session_set.pools[InferenceOperationImpl::PredictIntonation],
+        ));
+        assert!(!Arc::ptr_eq(
+            &session_set.pools[InferenceOperationImpl::PredictDuration],
+            &session_set.pools[InferenceOperationImpl::Decode],
+        ));
+        let pool = session_set.pools[InferenceOperationImpl::PredictDuration]
+            .resident()
+            .unwrap();
+        assert_eq!(2, pool.members.len());
+        assert!(Arc::ptr_eq(
+            &session_set.slots[InferenceOperationImpl::PredictDuration],
+            &session_set.slots[InferenceOperationImpl::PredictIntonation],
+        ));
+        assert!(!Arc::ptr_eq(
+            &session_set.slots[InferenceOperationImpl::PredictDuration],
+            &session_set.slots[InferenceOperationImpl::Decode],
+        ));
+        assert_eq!(
+            1,
+            session_set.options[InferenceOperationImpl::Decode].cpu_num_threads,
+        );
+
+        let result = status.run_session(
+            &model_header.id,
+            PredictIntonationInput {
+                length: ndarray::arr0(5),
+                vowel_phoneme_list: ndarray::arr1(&[0, 14, 6, 30, 0]),
+                consonant_phoneme_list: ndarray::arr1(&[-1, 37, 35, 37, -1]),
+                start_accent_list: ndarray::arr1(&[0, 1, 0, 0, 0]),
+                end_accent_list: ndarray::arr1(&[0, 1, 0, 0, 0]),
+                start_accent_phrase_list: ndarray::arr1(&[0, 1, 0, 0, 0]),
+                end_accent_phrase_list: ndarray::arr1(&[0, 0, 0, 1, 0]),
+                speaker_id: ndarray::arr1(&[0]),
+            },
+        );
+        assert!(result.is_ok());
+        assert_eq!(1, pool.num_sessions.load(Ordering::Relaxed));
+
+        session_set.evict();
+        assert!(!session_set.is_resident());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn status_load_model_lazily_works() {
+        let status = Status::<InferenceRuntimeImpl, InferenceDomainImpl>::new(
+            enum_map!(_ => InferenceSessionOptions::new(0, false, NonZeroUsize::MIN)),
+        );
+        let vvm = open_default_vvm_file().await;
+        let model_header = vvm.header();
+        let result = status.insert_model_lazily(
+            model_header,
+            model_header.model_reader(),
+            &status.default_model_session_options(),
+            &Default::default(),
+        );
+        assert_debug_fmt_eq!(Ok(()), result);
+        assert!(status.is_loaded_model(&model_header.id));
+
+        let is_created = |op| {
+            status.loaded_models.lock().unwrap().models[&model_header.id]
+                .session_set
+                .pools[op]
+                .resident()
+                .is_some()
+        };
+        assert!(!is_created(InferenceOperationImpl::PredictDuration));
+
+        let result = status.run_session(
+            &model_header.id,
+            PredictDurationInput {
+                phoneme_list: ndarray::arr1(&[0, 37, 14, 35, 6, 37, 30, 0]),
+                speaker_id: ndarray::arr1(&[0]),
+            },
+        );
+        assert!(result.is_ok());
+        assert!(is_created(InferenceOperationImpl::PredictDuration));
+        assert!(!is_created(InferenceOperationImpl::Decode));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn status_style_index_works() {
+        let status = Status::<InferenceRuntimeImpl, InferenceDomainImpl>::new(
+            enum_map!(_ => InferenceSessionOptions::new(0, false, NonZeroUsize::MIN)),
+        );
+        let vvm = open_default_vvm_file().await;
+        let model_header = vvm.header();
+        status
+            .insert_model_lazily(
+                model_header,
+                model_header.model_reader(),
+                &status.default_model_session_options(),
+                &Default::default(),
+            )
+            .unwrap();
+
+        let model_inner_ids = model_header.model_inner_ids();
+        assert!(!model_inner_ids.is_empty());
+        for (&style_id, &model_inner_id) in &model_inner_ids {
+            assert!(status.is_loaded_model_by_style_id(style_id));
+            let (model_id, actual) = status.ids_for(style_id).unwrap();
+            assert_eq!(model_header.id, model_id);
+            assert_eq!(model_inner_id, actual);
+        }
+
+        status.unload_model(&model_header.id).unwrap();
+        for &style_id in model_inner_ids.keys() {
+            assert!(!status.is_loaded_model_by_style_id(style_id));
+            assert!(status.ids_for(style_id).is_err());
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn status_style_id_remapping_works() {
+        let status = Status::<InferenceRuntimeImpl, InferenceDomainImpl>::new(
+            enum_map!(_ => InferenceSessionOptions::new(0, false, NonZeroUsize::MIN)),
+        );
+        let vvm = open_default_vvm_file().await;
+        let insert = |model_header: &VoiceModelHeader, style_id_remapping: &StyleIdRemapping| {
+            status.insert_model_lazily(
+                model_header,
+                vvm.header().model_reader(),
+                &status.default_model_session_options(),
+                style_id_remapping,
+            )
+        };
+        let with_id = |id: &str| {
+            let mut model_header = vvm.header().clone();
+            model_header.id = VoiceModelId::new(id.to_owned());
+            model_header
+        };
+        insert(vvm.header(), &StyleIdRemapping::None).unwrap();
+
+        let model_inner_ids = vvm.header().model_inner_ids();
+        let max_style_id = *model_inner_ids.keys().max().unwrap();
+
+        let result = insert(&with_id("none"), &StyleIdRemapping::None);
+        assert_eq!(ErrorKind::StyleAlreadyLoaded, result.unwrap_err().kind());
+
+        let (&first, &first_inner_id) = model_inner_ids.iter().next().unwrap();
+        let explicit = model_inner_ids
+            .keys()
+            .map(|&id| (id, StyleId::new(id.raw_id() + 1000)))
+            .collect();
+        insert(&with_id("explicit"), &StyleIdRemapping::Explicit(explicit)).unwrap();
+        let remapped = StyleId::new(first.raw_id() + 1000);
+        let (model_id, model_inner_id) = status.ids_for(remapped).unwrap();
+        assert_eq!(VoiceModelId::new("explicit".to_owned()), model_id);
+        assert_eq!(first_inner_id, model_inner_id);
+
+        insert(&with_id("auto"), &StyleIdRemapping::AutoAssign).unwrap();
+        let auto_assigned = StyleId::new(max_style_id.raw_id() + 1000 + 1);
+        let (model_id, model_inner_id) = status.ids_for(auto_assigned).unwrap();
+        assert_eq!(VoiceModelId::new("auto".to_owned()), model_id);
+        assert_eq!(first_inner_id, model_inner_id);
+
+        let style_ids = status
+            .metas()
+            .iter()
+            .flat_map(|speaker| speaker.styles())
+            .map(|style| *style.id())
+            .collect::<Vec<_>>();
+        assert_eq!(3 * model_inner_ids.len(), style_ids.len());
+        assert!(style_ids.contains(&remapped));
+        assert!(style_ids.contains(&auto_assigned));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn status_replace_model_works() {
+        let status = Status::<InferenceRuntimeImpl, InferenceDomainImpl>::new(
+            enum_map!(_ => InferenceSessionOptions::new(0, false, NonZeroUsize::MIN)),
+        );
+        let mut session_options = status.default_model_session_options();
+        session_options.sessions[InferenceOperationImpl::Decode].cpu_num_threads = 1;
+
+        let vvm = open_default_vvm_file().await;
+        let model_header = vvm.header();
+        let model_bytes = &vvm.read_inference_models().await.unwrap();
+        status
+            .insert_model(
+                model_header,
+                model_bytes,
+                model_header.model_reader(),
+                &session_options,
+                &Default::default(),
+            )
+            .unwrap();
+        let session_set = |status: &Status<_, _>| {
+            status
+                .loaded_models
+                .lock()
+                .unwrap()
+                .session_set(&model_header.id)
+        };
+        let old = session_set(&status);
+
+        let result = status.replace_model(
+            &model_header.id,
+            model_header,
+            None,
+            model_header.model_reader(),
+        );
+        assert_debug_fmt_eq!(Ok(()), result);
+
+        let new = session_set(&status);
+        assert!(!Arc::ptr_eq(&old, &new));
+        assert!(!new.is_resident());
+        assert_eq!(
+            1,
+            new.options[InferenceOperationImpl::Decode].cpu_num_threads,
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn status_run_session_for_unprovided_operation_fails() {
+        let status = Status::<InferenceRuntimeImpl, InferenceDomainImpl>::new(
+            enum_map!(_ => InferenceSessionOptions::new(0, false, NonZeroUsize::MIN)),
+        );
+        let vvm = open_default_vvm_file().await;
+        let model_header = vvm.header();
+        let mut model_bytes = vvm.read_inference_models().await.unwrap();
+        model_bytes[InferenceOperationImpl::Decode] = None;
+        status
+            .insert_model(
+                model_header,
+                &model_bytes,
+                model_header.model_reader(),
+                &status.default_model_session_options(),
+                &Default::default(),
+            )
+            .unwrap();
+
+        let provided = status.provided_operations(&model_header.id).unwrap();
+        assert!(provided[InferenceOperationImpl::PredictDuration]);
+        assert!(!provided[InferenceOperationImpl::Decode]);
+
+        let result = status.run_session(
+            &model_header.id,
+            DecodeInput {
+                f0: ndarray::Array2::zeros((8, 1)),
+                phoneme: ndarray::Array2::zeros((8, 45)),
+                speaker_id: ndarray::arr1(&[0]),
+            },
+        );
+        assert_eq!(
+            ErrorKind::OperationNotSupported,
+            result.map(|_| ()).unwrap_err().kind(),
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn status_ids_for_operation_checks_style_type() {
+        let status = Status::<InferenceRuntimeImpl, InferenceDomainImpl>::new(
+            enum_map!(_ => InferenceSessionOptions::new(0, false, NonZeroUsize::MIN)),
+        );
+        let vvm = open_default_vvm_file().await;
+        let mut model_header = vvm.header().clone();
+        let mut metas = serde_json::to_value(&model_header.metas).unwrap();
+        metas[0]["styles"][0]["type"] = "frame_decode".into();
+        model_header.metas = serde_json::from_value(metas).unwrap();
+        let frame_decode = *model_header.metas[0].styles()[0].id();
+        let talk = *model_header.metas[1].styles()[0].id();
+        status
+            .insert_model_lazily(
+                &model_header,
+                model_header.model_reader(),
+                &status.default_model_session_options(),
+                &Default::default(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            StyleType::FrameDecode,
+            status.style_type_of(frame_decode).unwrap(),
+        );
+        assert_eq!(
+            ErrorKind::OperationNotSupported,
+            status
+                .ids_for_operation(frame_decode, InferenceOperationImpl::Decode)
+                .unwrap_err()
+                .kind(),
+        );
+        assert!(status
+            .ids_for_operation(talk, InferenceOperationImpl::Decode)
+            .is_ok());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn status_evict_sessions_works() {
+        let status = Status::<InferenceRuntimeImpl, InferenceDomainImpl>::new(
+            enum_map!(_ => InferenceSessionOptions::new(0, false, NonZeroUsize::MIN)),
+        );
+        let vvm = open_default_vvm_file().await;
+        let model_header = vvm.header();
+        let model_bytes = &vvm.read_inference_models().await.unwrap();
+        status
+            .insert_model(
+                model_header,
+                model_bytes,
+                model_header.model_reader(),
+                &status.default_model_session_options(),
+                &Default::default(),
+            )
+            .unwrap();
+
+        let session_set = status
+            .loaded_models
+            .lock()
+            .unwrap()
+            .session_set(&model_header.id);
+        assert!(session_set.is_resident());
+        assert!(session_set.estimated_size() > 0);
+
+        session_set.evict();
+        assert!(!session_set.is_resident());
+        assert_eq!(0, session_set.estimated_size());
+
+        let result = status.run_session(
+            &model_header.id,
+            PredictDurationInput {
+                phoneme_list: ndarray::arr1(&[0, 37, 14, 35, 6, 37, 30, 0]),
+                speaker_id: ndarray::arr1(&[0]),
+            },
+        );
+        assert!(result.is_ok());
+        assert!(session_set.pools[InferenceOperationImpl::PredictDuration]
+            .resident()
+            .is_some());
+        assert!(session_set.pools[InferenceOperationImpl::Decode]
+            .resident()
+            .is_none());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn status_restores_evicted_pool_in_use() {
+        let status = Status::<InferenceRuntimeImpl, InferenceDomainImpl>::new(
+            enum_map!(_ => InferenceSessionOptions::new(0, false, NonZeroUsize::MIN)),
+        );
+        let vvm = open_default_vvm_file().await;
+        let model_header = vvm.header();
+        let model_bytes = &vvm.read_inference_models().await.unwrap();
+        status
+            .insert_model(
+                model_header,
+                model_bytes,
+                model_header.model_reader(),
+                &status.default_model_session_options(),
+                &Default::default(),
+            )
+            .unwrap();
+        let session_set = status
+            .loaded_models
+            .lock()
+            .unwrap()
+            .session_set(&model_header.id);
+
+        let cell = session_set.get::<PredictDurationInput>().unwrap().unwrap();
+        assert!(!cell.created);
+        let sess = cell.acquire().unwrap();
+        assert!(!sess.created);
+        let size = session_set.estimated_size();
+
+        // 推論中に破棄されても、推論が終わるまではメモリを使っているものとして数える
+        session_set.evict();
+        assert!(!session_set.is_resident());
+        assert_eq!(size, session_set.estimated_size());
+
+        // 推論中のものが、作り直されずに戻される
+        let restored = session_set.get::<PredictDurationInput>().unwrap().unwrap();
+        assert!(!restored.created);
+        assert!(Arc::ptr_eq(&cell.inner, &restored.inner));
+        drop(sess);
+        drop((cell, restored));
+
+        session_set.evict();
+        assert_eq!(0, session_set.estimated_size());
+        let rebuilt = session_set.get::<PredictDurationInput>().unwrap().unwrap();
+        assert!(rebuilt.created);
+    }
+
+    #[rstest]
+    fn session_budget_works() {
+        let budget = SessionBudget {
+            max_models: NonZeroUsize::new(2),
+            max_bytes: NonZeroUsize::new(100),
+        };
+        assert!(budget.accepts(2, 100));
+        assert!(!budget.accepts(3, 100));
+        assert!(!budget.accepts(2, 101));
+        assert!(SessionBudget::default().accepts(usize::MAX, usize::MAX));
+        assert!(SessionBudget::default().is_unlimited());
+    }
 }
@@ -9,12 +9,21 @@ mod macros;
 mod manifest;
 mod metas;
 mod result;
+mod speaker_info;
+mod stats;
+mod style_resolver;
 mod synthesizer;
 mod task;
 mod text_analyzer;
 mod user_dict;
 mod version;
 mod voice_model;
+mod voice_model_set;
+mod voice_model_watcher;
+mod vvm_check;
+mod vvm_pack;
+mod vvm_signature;
+mod worker;
 
 pub mod __internal;
 pub mod blocking;
@@ -33,11 +42,23 @@ pub use self::{
     engine::{AccentPhraseModel, AudioQueryModel, FullcontextExtractor},
     error::{Error, ErrorKind},
     metas::{
-        RawStyleId, RawStyleVersion, SpeakerMeta, StyleId, StyleMeta, StyleVersion, VoiceModelMeta,
+        RawStyleId, RawStyleVersion, SpeakerMeta, StyleId, StyleMeta, StyleType, StyleVersion,
+        VoiceModelMeta,
     },
     result::Result,
-    synthesizer::{AccelerationMode, InitializeOptions, SynthesisOptions, TtsOptions},
+    speaker_info::{SpeakerInfo, StyleInfo},
+    stats::{
+        LatencyHistogram, OperationStats, RealTimeFactorStats, StatsOperation, SynthesizerStats,
+    },
+    style_resolver::{StyleCandidate, StyleKey, StyleResolver},
+    synthesizer::{
+        AccelerationMode, InitializeOptions, LoadVoiceModelOptions, SignaturePolicy,
+        StyleIdRemapping, SynthesisOptions, TtsOptions,
+    },
     user_dict::{UserDictWord, UserDictWordType},
     version::VERSION,
     voice_model::{RawVoiceModelId, VoiceModelId},
+    voice_model_set::VoiceModelConflict,
+    vvm_pack::VvmPackOptions,
+    worker::run_worker_if_requested,
 };
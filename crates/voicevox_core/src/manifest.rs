@@ -1,14 +1,45 @@
 use std::{collections::BTreeMap, fmt::Display};
 
+use anyhow::bail;
 use derive_getters::Getters;
 use derive_new::new;
-use serde::{Deserialize, Serialize};
+use semver::{Comparator, Op, Version};
+use serde::{de::IgnoredAny, Deserialize, Serialize};
 
-use crate::StyleId;
+use crate::{voice_model::RawVoiceModelId, StyleId};
 
-pub type RawManifestVersion = String;
-#[derive(Deserialize, Clone, Debug, PartialEq, new)]
-pub struct ManifestVersion(RawManifestVersion);
+/// マニフェストのバージョン。[SemVer]に従う。
+///
+/// [SemVer]: https://semver.org/lang/ja/
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, new)]
+pub struct ManifestVersion(Version);
+
+impl ManifestVersion {
+    /// 読むことができる最新のバージョン。VVMを作るときにはこのバージョンを書く。
+    pub(crate) const CURRENT: Self = Self(Version::new(0, 1, 0));
+
+    /// `domains`が導入されたバージョン。これより前のマニフェストは、`talk`の内容をトップレベルに
+    /// 直接書く。
+    const DOMAINS: Self = Self(Version::new(0, 1, 0));
+
+    /// このバージョンのマニフェストを読めるかどうか。
+    ///
+    /// [`CURRENT`]以前のバージョンと、[`CURRENT`]と互換性のある(キャレット要件`^CURRENT`を満たす)
+    /// バージョンを読むことができる。
+    ///
+    /// [`CURRENT`]: Self::CURRENT
+    pub(crate) fn is_supported(&self) -> bool {
+        let Self(current) = &Self::CURRENT;
+        let compatible = Comparator {
+            op: Op::Caret,
+            major: current.major,
+            minor: Some(current.minor),
+            patch: Some(current.patch),
+            pre: current.pre.clone(),
+        };
+        *self <= Self::CURRENT || compatible.matches(&self.0)
+    }
+}
 
 impl Display for ManifestVersion {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -16,6 +47,75 @@ impl Display for ManifestVersion {
     }
 }
 
+/// `manifest.json`から、`manifest_version`のみを読むための形式。
+///
+/// 対応していないバージョンのマニフェストでは他のフィールドの意味が変わっているかもしれないため、
+/// バージョンを先に確かめる。
+#[derive(Deserialize)]
+pub(crate) struct ManifestVersionOnly {
+    pub(crate) manifest_version: ManifestVersion,
+}
+
+/// `manifest.json`から、推論ドメインの構成のみを読むための形式。
+///
+/// 対応していない推論ドメインを持つマニフェストと、`domains`導入後のマニフェストに`domains`導入前の
+/// 形式のフィールドが書かれたものを、[`Manifest`]として読む前に拒否するために使う。
+#[derive(Deserialize)]
+pub(crate) struct ManifestDomainsOnly {
+    manifest_version: ManifestVersion,
+    #[serde(default)]
+    domains: Option<BTreeMap<String, IgnoredAny>>,
+    #[serde(default)]
+    predict_duration_filename: Option<IgnoredAny>,
+    #[serde(default)]
+    predict_intonation_filename: Option<IgnoredAny>,
+    #[serde(default)]
+    decode_filename: Option<IgnoredAny>,
+    #[serde(default)]
+    style_id_to_model_inner_id: Option<IgnoredAny>,
+}
+
+impl ManifestDomainsOnly {
+    /// 対応している推論ドメイン。
+    const SUPPORTED: &'static [&'static str] = &["talk"];
+
+    pub(crate) fn ensure_supported(&self) -> anyhow::Result<()> {
+        if let Some(domain) = self
+            .domains
+            .iter()
+            .flat_map(BTreeMap::keys)
+            .find(|domain| !Self::SUPPORTED.contains(&&***domain))
+        {
+            bail!("推論ドメイン`{domain}`には対応していません。VOICEVOX COREを更新してください");
+        }
+        let legacy_fields = [
+            ("predict_duration_filename", &self.predict_duration_filename),
+            (
+                "predict_intonation_filename",
+                &self.predict_intonation_filename,
+            ),
+            ("decode_filename", &self.decode_filename),
+            (
+                "style_id_to_model_inner_id",
+                &self.style_id_to_model_inner_id,
+            ),
+        ];
+        if let Some((field, _)) = legacy_fields.iter().find(|(_, value)| value.is_some()) {
+            if self.domains.is_some() {
+                bail!("`domains`とトップレベルの`{field}`が両方書かれています");
+            }
+            if self.manifest_version >= ManifestVersion::DOMAINS {
+                bail!(
+                    "マニフェストのバージョン`{}`では、トップレベルの`{field}`は使えません。\
+                     `domains`の`talk`に書いてください",
+                    self.manifest_version,
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
 /// モデル内IDの実体
 pub type RawModelInnerId = u32;
 /// モデル内ID
@@ -34,16 +134,209 @@ impl Display for ModelInnerId {
     }
 }
 
-#[derive(Deserialize, Getters, Clone)]
+#[derive(Deserialize, Serialize, Getters, Clone, new)]
+#[serde(from = "ManifestRepr")]
 pub struct Manifest {
-    // FIXME: UUIDにする
-    // https://github.com/VOICEVOX/voicevox_core/issues/581
-    #[allow(dead_code)]
+    /// マニフェストのバージョン。古いバージョンのマニフェストは、読むときに現在の形式に移行される。
     manifest_version: ManifestVersion,
+    /// 音声モデルID。指定されていない場合はメタ情報から導出される。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<RawVoiceModelId>,
     metas_filename: String,
-    decode_filename: String,
-    predict_duration_filename: String,
-    predict_intonation_filename: String,
+    domains: ManifestDomains,
+}
+
+/// VVMが提供する推論ドメイン。
+///
+/// 現在は`talk`にのみ対応しており、それ以外のドメインを持つVVMは読む前に拒否される
+/// ([`ManifestDomainsOnly`])。
+#[derive(Deserialize, Serialize, Getters, Default, Clone, new)]
+pub struct ManifestDomains {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    talk: Option<TalkManifest>,
+}
+
+/// トークのための推論ドメインのマニフェスト。
+///
+/// それぞれの推論操作のモデルは省略でき、省略された推論操作は使えない。
+#[derive(Deserialize, Serialize, Getters, Clone, new)]
+pub struct TalkManifest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    predict_duration_filename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    predict_intonation_filename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    decode_filename: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    style_id_to_model_inner_id: BTreeMap<StyleId, ModelInnerId>,
+}
+
+/// `manifest.json`の実際の形式。
+///
+/// `domains`が導入される前のバージョンでは、`talk`の内容がトップレベルに直接書かれた形式も受け付
+/// ける。両方が書かれたマニフェストや、`domains`が導入された後のバージョンでトップレベルに書かれた
+/// マニフェストは、読む前に拒否される([`ManifestDomainsOnly`])。
+#[derive(Deserialize)]
+struct ManifestRepr {
+    manifest_version: ManifestVersion,
+    #[serde(default)]
+    id: Option<RawVoiceModelId>,
+    metas_filename: String,
+    #[serde(default)]
+    domains: Option<ManifestDomains>,
+    #[serde(default)]
+    predict_duration_filename: Option<String>,
+    #[serde(default)]
+    predict_intonation_filename: Option<String>,
+    #[serde(default)]
+    decode_filename: Option<String>,
     #[serde(default)]
     style_id_to_model_inner_id: BTreeMap<StyleId, ModelInnerId>,
 }
+
+impl From<ManifestRepr> for Manifest {
+    fn from(repr: ManifestRepr) -> Self {
+        let ManifestRepr {
+            manifest_version,
+            id,
+            metas_filename,
+            domains,
+            predict_duration_filename,
+            predict_intonation_filename,
+            decode_filename,
+            style_id_to_model_inner_id,
+        } = repr;
+
+        let domains = domains.unwrap_or_else(|| {
+            if manifest_version >= ManifestVersion::DOMAINS {
+                return ManifestDomains::default();
+            }
+            let is_legacy = predict_duration_filename.is_some()
+                || predict_intonation_filename.is_some()
+                || decode_filename.is_some();
+            ManifestDomains {
+                talk: is_legacy.then_some(TalkManifest {
+                    predict_duration_filename,
+                    predict_intonation_filename,
+                    decode_filename,
+                    style_id_to_model_inner_id,
+                }),
+            }
+        });
+
+        Self {
+            manifest_version,
+            id,
+            metas_filename,
+            domains,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::{Manifest, ManifestDomainsOnly, ManifestVersion};
+
+    #[rstest]
+    #[case(
+        json!({
+            "manifest_version": "0.0.0",
+            "metas_filename": "metas.json",
+            "decode_filename": "decode.onnx",
+            "predict_duration_filename": "predict_duration.onnx",
+            "predict_intonation_filename": "predict_intonation.onnx",
+            "style_id_to_model_inner_id": { "302": 2 }
+        }),
+        Some("decode.onnx"),
+        1
+    )]
+    #[case(
+        json!({
+            "manifest_version": "0.0.0",
+            "metas_filename": "metas.json",
+            "domains": {
+                "talk": {
+                    "predict_duration_filename": "predict_duration.onnx",
+                    "predict_intonation_filename": "predict_intonation.onnx"
+                },
+                "sing": {}
+            }
+        }),
+        None,
+        0
+    )]
+    fn manifest_talk_domain_works(
+        #[case] manifest: serde_json::Value,
+        #[case] decode_filename: Option<&str>,
+        #[case] num_inner_ids: usize,
+    ) {
+        let manifest = serde_json::from_value::<Manifest>(manifest).unwrap();
+        let talk = manifest.domains().talk().as_ref().unwrap();
+        assert_eq!(decode_filename, talk.decode_filename().as_deref());
+        assert_eq!(
+            Some("predict_duration.onnx"),
+            talk.predict_duration_filename().as_deref(),
+        );
+        assert_eq!(num_inner_ids, talk.style_id_to_model_inner_id().len());
+    }
+
+    #[rstest]
+    fn manifest_with_domains_version_rejects_legacy_fields() {
+        let manifest = serde_json::from_value::<ManifestDomainsOnly>(json!({
+            "manifest_version": "0.1.0",
+            "metas_filename": "metas.json",
+            "decode_filename": "decode.onnx"
+        }))
+        .unwrap();
+        assert!(manifest.ensure_supported().is_err());
+    }
+
+    #[rstest]
+    #[case(json!({ "domains": { "talk": {} } }), true)]
+    #[case(json!({ "decode_filename": "decode.onnx" }), true)]
+    #[case(json!({}), true)]
+    #[case(json!({ "domains": { "sing": {} } }), false)]
+    #[case(json!({ "domains": { "talk": {}, "frame_decode": {} } }), false)]
+    #[case(
+        json!({ "domains": { "talk": {} }, "decode_filename": "decode.onnx" }),
+        false
+    )]
+    #[case(
+        json!({ "domains": {}, "style_id_to_model_inner_id": { "302": 2 } }),
+        false
+    )]
+    fn manifest_domains_only_ensure_supported_works(
+        #[case] mut manifest: serde_json::Value,
+        #[case] supported: bool,
+    ) {
+        manifest["manifest_version"] = "0.0.0".into();
+        let manifest = serde_json::from_value::<ManifestDomainsOnly>(manifest).unwrap();
+        assert_eq!(supported, manifest.ensure_supported().is_ok());
+    }
+
+    #[rstest]
+    #[case("0.0.0", true)]
+    #[case("0.1.0", true)]
+    #[case("0.1.1", true)]
+    #[case("0.1.0-alpha", true)]
+    #[case("0.2.0", false)]
+    #[case("1.0.0", false)]
+    fn manifest_version_is_supported_works(#[case] version: &str, #[case] expected: bool) {
+        let version = ManifestVersion::new(version.parse().unwrap());
+        assert_eq!(expected, version.is_supported());
+    }
+
+    #[rstest]
+    fn manifest_without_talk_domain_works() {
+        let manifest = serde_json::from_value::<Manifest>(serde_json::json!({
+            "manifest_version": "0.0.0",
+            "metas_filename": "metas.json",
+            "domains": {}
+        }))
+        .unwrap();
+        assert!(manifest.domains().talk().is_none());
+    }
+}
@@ -1,4 +1,7 @@
-use std::fmt::{Debug, Display};
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Display},
+};
 
 use derive_getters::Getters;
 use derive_new::new;
@@ -88,6 +91,37 @@ impl Display for StyleVersion {
 /// 音声モデルのメタ情報。
 pub type VoiceModelMeta = Vec<SpeakerMeta>;
 
+/// `metas`から、`style_type`のスタイルのみを残したものを返す。
+///
+/// `style_type`のスタイルを一つも持たない話者は取り除かれる。
+pub(crate) fn filter_by_style_type(metas: &[SpeakerMeta], style_type: StyleType) -> VoiceModelMeta {
+    filter_styles(metas, |style| style.r#type == style_type)
+}
+
+/// `metas`から、`predicate`を満たすスタイルのみを残したものを返す。
+///
+/// 残るスタイルを一つも持たない話者は取り除かれる。
+pub(crate) fn filter_styles(
+    metas: &[SpeakerMeta],
+    predicate: impl Fn(&StyleMeta) -> bool,
+) -> VoiceModelMeta {
+    metas
+        .iter()
+        .filter_map(|speaker| {
+            let styles = speaker
+                .styles
+                .iter()
+                .filter(|style| predicate(style))
+                .cloned()
+                .collect::<Vec<_>>();
+            (!styles.is_empty()).then(|| SpeakerMeta {
+                styles,
+                ..speaker.clone()
+            })
+        })
+        .collect()
+}
+
 /// **話者**(_speaker_)のメタ情報。
 #[derive(Deserialize, Serialize, Getters, Clone)]
 pub struct SpeakerMeta {
@@ -103,9 +137,45 @@ pub struct SpeakerMeta {
     ///
     /// `SpeakerMeta`の列は、この値に対して昇順に並んでいるべきである。
     order: Option<u32>,
+    /// 言語ごとの話者名。キーはBCP 47の言語タグ。
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    localized_names: BTreeMap<String, String>,
+    /// 話者を利用する際に求められるクレジット表記。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    credit: Option<String>,
+    /// 利用規約が書かれたMarkdownファイルの、VVM内でのファイル名。
+    ///
+    /// 中身は[`SpeakerInfo::policy`]で得られる。
+    ///
+    /// [`SpeakerInfo::policy`]: crate::SpeakerInfo::policy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    policy_filename: Option<String>,
+    /// 立ち絵の画像ファイルの、VVM内でのファイル名。
+    ///
+    /// 中身は[`SpeakerInfo::portrait`]で得られる。
+    ///
+    /// [`SpeakerInfo::portrait`]: crate::SpeakerInfo::portrait
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    portrait_filename: Option<String>,
 }
 
 impl SpeakerMeta {
+    /// それぞれのスタイルIDを`f`で付け替えたものを返す。
+    pub(crate) fn map_style_ids(&self, f: impl Fn(StyleId) -> StyleId) -> Self {
+        let styles = self
+            .styles
+            .iter()
+            .map(|style| StyleMeta {
+                id: f(style.id),
+                ..style.clone()
+            })
+            .collect();
+        Self {
+            styles,
+            ..self.clone()
+        }
+    }
+
     /// # Panics
     ///
     /// `speaker_uuid`が異なるときパニックする。
@@ -116,6 +186,10 @@ impl SpeakerMeta {
             version: version1,
             speaker_uuid: speaker_uuid1,
             order: order1,
+            localized_names: localized_names1,
+            credit: credit1,
+            policy_filename: policy_filename1,
+            portrait_filename: portrait_filename1,
         } = self;
 
         let Self {
@@ -124,6 +198,10 @@ impl SpeakerMeta {
             version: version2,
             speaker_uuid: speaker_uuid2,
             order: order2,
+            localized_names: localized_names2,
+            credit: credit2,
+            policy_filename: policy_filename2,
+            portrait_filename: portrait_filename2,
         } = other;
 
         if speaker_uuid1 != speaker_uuid2 {
@@ -133,6 +211,25 @@ impl SpeakerMeta {
         warn_diff(speaker_uuid1, "name", name1, name2);
         warn_diff(speaker_uuid1, "version", version1, version2);
         warn_diff(speaker_uuid1, "order", order1, order2);
+        warn_diff(
+            speaker_uuid1,
+            "localized_names",
+            localized_names1,
+            localized_names2,
+        );
+        warn_diff(speaker_uuid1, "credit", credit1, credit2);
+        warn_diff(
+            speaker_uuid1,
+            "policy_filename",
+            policy_filename1,
+            policy_filename2,
+        );
+        warn_diff(
+            speaker_uuid1,
+            "portrait_filename",
+            portrait_filename1,
+            portrait_filename2,
+        );
 
         fn warn_diff<T: PartialEq + Debug>(
             speaker_uuid: &str,
@@ -158,6 +255,46 @@ pub struct StyleMeta {
     ///
     /// [`SpeakerMeta::styles`]は、この値に対して昇順に並んでいるべきである。
     order: Option<u32>,
+    /// スタイルの種類。
+    ///
+    /// 省略された場合は[`StyleType::Talk`]となる。
+    #[serde(default)]
+    r#type: StyleType,
+    /// アイコンの画像ファイルの、VVM内でのファイル名。
+    ///
+    /// 中身は[`StyleInfo::icon`]で得られる。
+    ///
+    /// [`StyleInfo::icon`]: crate::StyleInfo::icon
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    icon_filename: Option<String>,
+    /// 音声サンプルのWAVファイルの、VVM内でのファイル名。
+    ///
+    /// 中身は[`StyleInfo::voice_samples`]で得られる。
+    ///
+    /// [`StyleInfo::voice_samples`]: crate::StyleInfo::voice_samples
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    voice_sample_filenames: Vec<String>,
+}
+
+/// **スタイル**(_style_)の種類。スタイルがどの操作に対応しているかを表わす。
+#[derive(
+    Default, Clone, Copy, PartialEq, Eq, Hash, Debug, Deserialize, Serialize, derive_more::Display,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum StyleType {
+    /// 音声合成クエリの作成と音声合成が可能。
+    #[default]
+    #[display(fmt = "talk")]
+    Talk,
+    /// 歌唱音声合成用のクエリの作成が可能。
+    #[display(fmt = "singing_teacher")]
+    SingingTeacher,
+    /// 歌唱音声合成が可能。
+    #[display(fmt = "frame_decode")]
+    FrameDecode,
+    /// 歌唱音声合成用のクエリの作成と歌唱音声合成が可能。
+    #[display(fmt = "sing")]
+    Sing,
 }
 
 #[cfg(test)]
@@ -165,6 +302,8 @@ mod tests {
     use once_cell::sync::Lazy;
     use serde_json::json;
 
+    use super::StyleType;
+
     #[test]
     fn merge_works() -> anyhow::Result<()> {
         static INPUT: Lazy<serde_json::Value> = Lazy::new(|| {
@@ -224,17 +363,20 @@ mod tests {
                         {
                             "id": 1,
                             "name": "A_1",
-                            "order": 0
+                            "order": 0,
+                            "type": "talk"
                         },
                         {
                             "id": 0,
                             "name": "A_2",
-                            "order": 1
+                            "order": 1,
+                            "type": "talk"
                         },
                         {
                             "id": 2,
                             "name": "A_3",
-                            "order": 2
+                            "order": 2,
+                            "type": "talk"
                         }
                     ],
                     "version": "0.0.0",
@@ -247,7 +389,8 @@ mod tests {
                         {
                             "id": 3,
                             "name": "B_1",
-                            "order": 0
+                            "order": 0,
+                            "type": "talk"
                         }
                     ],
                     "version": "0.0.0",
@@ -263,4 +406,51 @@ mod tests {
         pretty_assertions::assert_eq!(*EXPECTED, actual);
         Ok(())
     }
+
+    #[test]
+    fn filter_by_style_type_works() -> anyhow::Result<()> {
+        let input = &serde_json::from_value::<Vec<_>>(json!([
+            {
+                "name": "A",
+                "styles": [
+                    { "id": 0, "name": "A_1" },
+                    { "id": 1, "name": "A_2", "type": "sing" },
+                    { "id": 2, "name": "A_3", "type": "frame_decode" },
+                ],
+                "version": "0.0.0",
+                "speaker_uuid": "d6fd707c-a451-48e9-8f00-fe9ee3bf6264",
+            },
+            {
+                "name": "B",
+                "styles": [{ "id": 3, "name": "B_1", "type": "talk" }],
+                "version": "0.0.0",
+                "speaker_uuid": "f34ab151-c0f5-4e0a-9ad2-51ce30dba24d",
+            },
+        ]))?;
+
+        let style_ids = |style_type| {
+            super::filter_by_style_type(input, style_type)
+                .iter()
+                .map(|speaker| {
+                    let style_ids = speaker
+                        .styles()
+                        .iter()
+                        .map(|style| style.id().raw_id())
+                        .collect::<Vec<_>>();
+                    (speaker.name().clone(), style_ids)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        pretty_assertions::assert_eq!(
+            [("A".to_owned(), vec![0]), ("B".to_owned(), vec![3])],
+            *style_ids(StyleType::Talk),
+        );
+        pretty_assertions::assert_eq!([("A".to_owned(), vec![1])], *style_ids(StyleType::Sing),);
+        pretty_assertions::assert_eq!(
+            Vec::<(String, Vec<u32>)>::new(),
+            style_ids(StyleType::SingingTeacher),
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,337 @@
+//! 話者の追加情報。
+//!
+//! 利用規約や画像、音声サンプルは[`SpeakerMeta`]と[`StyleMeta`]が参照するVVM内のファイルとして
+//! 同梱され、メタ情報とは別に必要なときに読まれる。
+//!
+//! [`StyleMeta`]: crate::StyleMeta
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_with::{base64::Base64, serde_as};
+
+use crate::{
+    error::{ErrorRepr, LoadModelError, LoadModelErrorKind, LoadModelResult},
+    metas,
+    voice_model::VoiceModelHeader,
+    SpeakerMeta, StyleId, VoiceModelMeta,
+};
+
+/// 話者の追加情報。
+///
+/// JSONにする際、画像と音声サンプルはBase64で表される。
+#[serde_as]
+#[derive(Serialize, Clone, PartialEq, Debug)]
+pub struct SpeakerInfo {
+    /// 利用規約。Markdown形式。
+    pub policy: Option<String>,
+    /// 立ち絵の画像。
+    #[serde_as(as = "Option<Base64>")]
+    pub portrait: Option<Vec<u8>>,
+    /// スタイルごとの追加情報。[`SpeakerMeta::styles`]と同じ順で並ぶ。
+    pub style_infos: Vec<StyleInfo>,
+}
+
+/// スタイルの追加情報。
+#[serde_as]
+#[derive(Serialize, Clone, PartialEq, Debug)]
+pub struct StyleInfo {
+    /// スタイルID。
+    pub id: StyleId,
+    /// アイコンの画像。
+    #[serde_as(as = "Option<Base64>")]
+    pub icon: Option<Vec<u8>>,
+    /// 音声サンプル。WAVの列。
+    #[serde_as(as = "Vec<Base64>")]
+    pub voice_samples: Vec<Vec<u8>>,
+}
+
+impl SpeakerInfo {
+    /// 複数の音声モデルから得た同じ話者の`SpeakerInfo`を、[`metas::merge`]でまとめられた
+    /// `speaker`に合わせて一つにまとめる。
+    ///
+    /// 利用規約と立ち絵は最初に見つかったものを使う。
+    pub(crate) fn merge(infos: impl IntoIterator<Item = Self>, speaker: &SpeakerMeta) -> Self {
+        let mut policy = None;
+        let mut portrait = None;
+        let mut style_infos = HashMap::new();
+        for info in infos {
+            policy = policy.or(info.policy);
+            portrait = portrait.or(info.portrait);
+            style_infos.extend(info.style_infos.into_iter().map(|info| (info.id, info)));
+        }
+        let style_infos = speaker
+            .styles()
+            .iter()
+            .flat_map(|style| style_infos.remove(style.id()))
+            .collect();
+        Self {
+            policy,
+            portrait,
+            style_infos,
+        }
+    }
+}
+
+/// メタ情報が参照する、VVM内のファイルのファイル名。
+pub(crate) fn resource_filenames(metas: &VoiceModelMeta) -> Vec<&str> {
+    metas
+        .iter()
+        .flat_map(|speaker| {
+            let styles = speaker.styles().iter().flat_map(|style| {
+                style
+                    .icon_filename()
+                    .iter()
+                    .chain(style.voice_sample_filenames())
+            });
+            speaker
+                .policy_filename()
+                .iter()
+                .chain(speaker.portrait_filename())
+                .chain(styles)
+        })
+        .map(String::as_str)
+        .collect()
+}
+
+impl VoiceModelHeader {
+    /// 話者のメタ情報を返す。
+    pub(crate) fn speaker(&self, speaker_uuid: &str) -> crate::Result<SpeakerMeta> {
+        let speaker = metas::merge(
+            self.metas
+                .iter()
+                .filter(|speaker| speaker.speaker_uuid() == speaker_uuid),
+        )
+        .into_iter()
+        .next()
+        .ok_or_else(|| ErrorRepr::SpeakerNotFound {
+            speaker_uuid: speaker_uuid.to_owned(),
+        })?;
+        Ok(speaker)
+    }
+
+    /// `speaker`が参照するファイルをVVMから読み、追加情報を得る。
+    ///
+    /// `speaker`のスタイルIDはそのまま[`StyleInfo::id`]となる。
+    ///
+    /// # Performance
+    ///
+    /// ブロッキング操作であるため、非同期ランタイム上では直接実行されるべきではない。
+    pub(crate) fn read_speaker_info(&self, speaker: &SpeakerMeta) -> LoadModelResult<SpeakerInfo> {
+        let read_entry = self.entry_reader()?;
+        let read_optional_entry =
+            |filename: &Option<String>| filename.as_deref().map(&read_entry).transpose();
+
+        let policy = read_optional_entry(speaker.policy_filename())?
+            .map(|policy| {
+                String::from_utf8(policy).map_err(|source| LoadModelError {
+                    path: self.source.display_path(),
+                    context: LoadModelErrorKind::ReadZipEntry {
+                        filename: speaker.policy_filename().clone().unwrap_or_default(),
+                    },
+                    source: Some(source.into()),
+                })
+            })
+            .transpose()?;
+        let portrait = read_optional_entry(speaker.portrait_filename())?;
+        let style_infos = speaker
+            .styles()
+            .iter()
+            .map(|style| {
+                Ok(StyleInfo {
+                    id: *style.id(),
+                    icon: read_optional_entry(style.icon_filename())?,
+                    voice_samples: style
+                        .voice_sample_filenames()
+                        .iter()
+                        .map(|filename| read_entry(filename))
+                        .collect::<LoadModelResult<_>>()?,
+                })
+            })
+            .collect::<LoadModelResult<_>>()?;
+
+        Ok(SpeakerInfo {
+            policy,
+            portrait,
+            style_infos,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use serde_json::json;
+
+    use crate::{SpeakerMeta, StyleId, VvmPackOptions};
+
+    use super::{SpeakerInfo, StyleInfo};
+
+    #[rstest]
+    fn merge_works() {
+        let speaker = serde_json::from_value::<SpeakerMeta>(json!({
+            "name": "A",
+            "styles": [
+                { "id": 0, "name": "A_1", "order": 0 },
+                { "id": 1, "name": "A_2", "order": 1 },
+                { "id": 2, "name": "A_3", "order": 2 },
+            ],
+            "version": "0.0.0",
+            "speaker_uuid": "d6fd707c-a451-48e9-8f00-fe9ee3bf6264",
+        }))
+        .unwrap();
+        let style_info = |id| StyleInfo {
+            id: StyleId::new(id),
+            icon: None,
+            voice_samples: vec![],
+        };
+
+        let merged = SpeakerInfo::merge(
+            [
+                SpeakerInfo {
+                    policy: None,
+                    portrait: Some(b"portrait1".to_vec()),
+                    style_infos: vec![style_info(2), style_info(0)],
+                },
+                SpeakerInfo {
+                    policy: Some("policy".to_owned()),
+                    portrait: Some(b"portrait2".to_vec()),
+                    style_infos: vec![style_info(1)],
+                },
+            ],
+            &speaker,
+        );
+
+        assert_eq!(
+            SpeakerInfo {
+                policy: Some("policy".to_owned()),
+                portrait: Some(b"portrait1".to_vec()),
+                style_infos: [0, 1, 2].into_iter().map(style_info).collect(),
+            },
+            merged,
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn speaker_info_works() {
+        let dir = tempfile::tempdir().unwrap();
+        let speaker_uuid = "d6fd707c-a451-48e9-8f00-fe9ee3bf6264";
+        let files = [
+            ("policy.md", &b"# policy"[..]),
+            ("portrait.png", b"portrait"),
+            ("icons/0.png", b"icon"),
+            ("voice_samples/0_001.wav", b"RIFF1"),
+            ("voice_samples/0_002.wav", b"RIFF2"),
+            (
+                "predict_duration.onnx",
+                &fs_err::read(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/src/test_data/model_sources/load_model_works1/predict_duration.onnx",
+                ))
+                .unwrap(),
+            ),
+        ];
+        for (filename, content) in files {
+            let path = dir.path().join(filename);
+            fs_err::create_dir_all(path.parent().unwrap()).unwrap();
+            fs_err::write(path, content).unwrap();
+        }
+        let metas = json!([
+            {
+                "name": "A",
+                "styles": [
+                    {
+                        "id": 0,
+                        "name": "A_1",
+                        "icon_filename": "icons/0.png",
+                        "voice_sample_filenames": [
+                            "voice_samples/0_001.wav",
+                            "voice_samples/0_002.wav",
+                        ],
+                    },
+                    { "id": 1, "name": "A_2" },
+                ],
+                "version": "0.0.0",
+                "speaker_uuid": speaker_uuid,
+                "localized_names": { "en": "A" },
+                "credit": "VOICEVOX:A",
+                "policy_filename": "policy.md",
+                "portrait_filename": "portrait.png",
+            },
+        ]);
+        fs_err::write(dir.path().join("metas.json"), metas.to_string()).unwrap();
+        fs_err::write(
+            dir.path().join("manifest.json"),
+            json!({
+                "manifest_version": "0.1.0",
+                "metas_filename": "metas.json",
+                "domains": { "talk": { "predict_duration_filename": "predict_duration.onnx" } },
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let packed = dir.path().join("packed.vvm");
+        let packed = crate::blocking::VoiceModel::pack(
+            dir.path().join("metas.json"),
+            &packed,
+            VvmPackOptions {
+                predict_duration: Some(dir.path().join("predict_duration.onnx")),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let expected = SpeakerInfo {
+            policy: Some("# policy".to_owned()),
+            portrait: Some(b"portrait".to_vec()),
+            style_infos: vec![
+                StyleInfo {
+                    id: StyleId::new(0),
+                    icon: Some(b"icon".to_vec()),
+                    voice_samples: vec![b"RIFF1".to_vec(), b"RIFF2".to_vec()],
+                },
+                StyleInfo {
+                    id: StyleId::new(1),
+                    icon: None,
+                    voice_samples: vec![],
+                },
+            ],
+        };
+        let unpacked = crate::blocking::VoiceModel::from_path(dir.path()).unwrap();
+        assert_eq!(Some("VOICEVOX:A"), unpacked.metas()[0].credit().as_deref(),);
+        assert_eq!(expected, unpacked.speaker_info(speaker_uuid).unwrap());
+        assert_eq!(expected, packed.speaker_info(speaker_uuid).unwrap());
+        let tokio = crate::tokio::VoiceModel::from_path(dir.path())
+            .await
+            .unwrap();
+        assert_eq!(expected, tokio.speaker_info(speaker_uuid).await.unwrap());
+
+        assert_eq!(
+            crate::ErrorKind::SpeakerNotFound,
+            unpacked.speaker_info("unknown").unwrap_err().kind(),
+        );
+    }
+
+    #[rstest]
+    fn serialize_works() {
+        let info = SpeakerInfo {
+            policy: Some("policy".to_owned()),
+            portrait: None,
+            style_infos: vec![StyleInfo {
+                id: StyleId::new(0),
+                icon: Some(b"icon".to_vec()),
+                voice_samples: vec![b"RIFF".to_vec()],
+            }],
+        };
+        assert_eq!(
+            json!({
+                "policy": "policy",
+                "portrait": null,
+                "style_infos": [{ "id": 0, "icon": "aWNvbg==", "voice_samples": ["UklGRg=="] }],
+            }),
+            serde_json::to_value(info).unwrap(),
+        );
+    }
+}
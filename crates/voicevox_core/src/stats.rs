@@ -0,0 +1,518 @@
+//! [`Synthesizer`]の処理時間の統計。
+//!
+//! [`Synthesizer`]: crate::blocking::Synthesizer
+
+use std::{
+    cell::Cell,
+    collections::VecDeque,
+    fs::File,
+    io::BufWriter,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use derive_getters::Getters;
+use indexmap::IndexMap;
+use serde::Serialize;
+use tracing::{debug_span, warn};
+
+use crate::{Result, StyleId, VoiceModelId};
+
+/// 統計を取る操作の種類。
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, derive_more::Display)]
+#[serde(rename_all = "snake_case")]
+pub enum StatsOperation {
+    /// テキスト解析。日本語のテキストもしくはAquesTalk風記法からAccentPhraseを作る処理。
+    #[display(fmt = "text_analysis")]
+    TextAnalysis,
+    /// `predict_duration`の推論。
+    #[display(fmt = "predict_duration")]
+    PredictDuration,
+    /// `predict_intonation`の推論。
+    #[display(fmt = "predict_intonation")]
+    PredictIntonation,
+    /// `decode`の推論。
+    #[display(fmt = "decode")]
+    Decode,
+}
+
+/// [`Synthesizer`]が集めた統計のスナップショット。
+///
+/// [`Synthesizer`]: crate::blocking::Synthesizer
+#[derive(Clone, Default, Debug, Getters)]
+pub struct SynthesizerStats {
+    /// 操作ごとの統計。
+    operations: Vec<OperationStats>,
+    /// 音声合成ごとのリアルタイムファクタの統計。
+    real_time_factors: Vec<RealTimeFactorStats>,
+}
+
+/// ある操作の、ある音声モデルとスタイルについての統計。
+#[derive(Clone, Debug, Getters)]
+pub struct OperationStats {
+    operation: StatsOperation,
+    /// 音声モデルID。テキスト解析のように音声モデルを使わない操作では`None`。
+    voice_model_id: Option<VoiceModelId>,
+    /// スタイルID。テキスト解析のように音声モデルを使わない操作では`None`。
+    style_id: Option<StyleId>,
+    /// 失敗した回数。
+    errors: u64,
+    /// 成功した操作の所要時間。
+    latency: LatencyHistogram,
+}
+
+/// 所要時間のヒストグラム。
+///
+/// バケットの境界は[`LatencyHistogram::BUCKET_BOUNDS`]で固定されている。
+#[derive(Clone, PartialEq, Debug)]
+pub struct LatencyHistogram {
+    count: u64,
+    sum: Duration,
+    min: Option<Duration>,
+    max: Option<Duration>,
+    bucket_counts: [u64; Self::BUCKET_BOUNDS.len() + 1],
+}
+
+impl LatencyHistogram {
+    /// 各バケットの上限(その値を含む)。これを超えるものは最後のバケットに入る。
+    pub const BUCKET_BOUNDS: [Duration; 13] = [
+        Duration::from_millis(1),
+        Duration::from_millis(2),
+        Duration::from_millis(5),
+        Duration::from_millis(10),
+        Duration::from_millis(20),
+        Duration::from_millis(50),
+        Duration::from_millis(100),
+        Duration::from_millis(200),
+        Duration::from_millis(500),
+        Duration::from_secs(1),
+        Duration::from_secs(2),
+        Duration::from_secs(5),
+        Duration::from_secs(10),
+    ];
+
+    /// 記録された回数。
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// 所要時間の合計。
+    pub fn sum(&self) -> Duration {
+        self.sum
+    }
+
+    /// 所要時間の平均。記録が無い場合は`None`。
+    pub fn mean(&self) -> Option<Duration> {
+        (self.count > 0)
+            .then(|| Duration::from_nanos((self.sum.as_nanos() / u128::from(self.count)) as u64))
+    }
+
+    /// 所要時間の最小値。記録が無い場合は`None`。
+    pub fn min(&self) -> Option<Duration> {
+        self.min
+    }
+
+    /// 所要時間の最大値。記録が無い場合は`None`。
+    pub fn max(&self) -> Option<Duration> {
+        self.max
+    }
+
+    /// バケットの上限と、そのバケットに入った回数の組を列挙する。
+    ///
+    /// 最後のバケットの上限は`None`(上限無し)となる。
+    pub fn buckets(&self) -> impl Iterator<Item = (Option<Duration>, u64)> + '_ {
+        Self::BUCKET_BOUNDS
+            .iter()
+            .copied()
+            .map(Some)
+            .chain([None])
+            .zip(self.bucket_counts.iter().copied())
+    }
+
+    fn record(&mut self, elapsed: Duration) {
+        self.count += 1;
+        self.sum += elapsed;
+        self.min = Some(self.min.map_or(elapsed, |min| min.min(elapsed)));
+        self.max = Some(self.max.map_or(elapsed, |max| max.max(elapsed)));
+        let i = Self::BUCKET_BOUNDS.partition_point(|&bound| bound < elapsed);
+        self.bucket_counts[i] += 1;
+    }
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            sum: Duration::ZERO,
+            min: None,
+            max: None,
+            bucket_counts: [0; Self::BUCKET_BOUNDS.len() + 1],
+        }
+    }
+}
+
+/// ある音声モデルとスタイルについての、音声合成のリアルタイムファクタ(RTF)の統計。
+///
+/// RTFは「音声合成にかかった時間 / 生成された音声の長さ」であり、1未満であれば実時間より速く合成
+/// できていることを表す。
+#[derive(Clone, Debug, Getters)]
+pub struct RealTimeFactorStats {
+    voice_model_id: VoiceModelId,
+    style_id: StyleId,
+    /// 音声合成の回数。
+    count: u64,
+    /// 音声合成にかかった時間の合計。
+    processing_time: Duration,
+    /// 生成された音声の長さの合計。
+    audio_duration: Duration,
+    /// RTFの最小値。
+    min: f64,
+    /// RTFの最大値。
+    max: f64,
+    /// 直近の音声合成のRTF。
+    last: f64,
+}
+
+impl RealTimeFactorStats {
+    /// これまでの音声合成全体でのRTF。
+    pub fn overall(&self) -> f64 {
+        self.processing_time.as_secs_f64() / self.audio_duration.as_secs_f64()
+    }
+}
+
+/// 統計の記録と、オプトインのトレースを行う。
+pub(crate) struct StatsRecorder {
+    stats: std::sync::Mutex<Stats>,
+    tracer: Option<Tracer>,
+}
+
+#[derive(Default)]
+struct Stats {
+    operations: IndexMap<(StatsOperation, Option<(VoiceModelId, StyleId)>), OperationEntry>,
+    real_time_factors: IndexMap<(VoiceModelId, StyleId), RealTimeFactorStats>,
+}
+
+#[derive(Default)]
+struct OperationEntry {
+    errors: u64,
+    latency: LatencyHistogram,
+}
+
+thread_local! {
+    /// [`StatsRecorder::untracked`]の中にいるか。
+    static UNTRACKED: Cell<bool> = const { Cell::new(false) };
+}
+
+impl StatsRecorder {
+    pub(crate) fn new(trace_output: Option<&Path>) -> Self {
+        Self {
+            stats: Default::default(),
+            tracer: trace_output.map(Tracer::new),
+        }
+    }
+
+    /// `f`を、このスレッドでの[`measure`]による記録を止めた状態で実行する。
+    ///
+    /// ウォームアップのように、利用者が要求したものではない推論を統計とトレースから除くた
+    /// めに使う。
+    ///
+    /// [`measure`]: Self::measure
+    pub(crate) fn untracked<T>(&self, f: impl FnOnce() -> T) -> T {
+        struct Restore(bool);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                UNTRACKED.with(|untracked| untracked.set(self.0));
+            }
+        }
+
+        let _restore = Restore(UNTRACKED.with(|untracked| untracked.replace(true)));
+        f()
+    }
+
+    /// `f`を実行し、その所要時間を`operation`の統計として記録する。
+    ///
+    /// `f`の実行は`tracing`のspanの中で行われる。[`untracked`]の中では記録しない。
+    ///
+    /// [`untracked`]: Self::untracked
+    pub(crate) fn measure<T>(
+        &self,
+        operation: StatsOperation,
+        ids: Option<(&VoiceModelId, StyleId)>,
+        f: impl FnOnce() -> Result<T>,
+    ) -> Result<T> {
+        let span = debug_span!(
+            "voicevox_core::stats",
+            %operation,
+            voice_model_id = ids.map(|(model_id, _)| tracing::field::display(model_id)),
+            style_id = ids.map(|(_, style_id)| style_id.raw_id()),
+            elapsed_ms = tracing::field::Empty,
+            ok = tracing::field::Empty,
+        );
+        let _enter = span.enter();
+
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+
+        span.record("elapsed_ms", elapsed.as_secs_f64() * 1000.);
+        span.record("ok", result.is_ok());
+
+        if UNTRACKED.with(Cell::get) {
+            return result;
+        }
+
+        let ids = ids.map(|(model_id, style_id)| (model_id.clone(), style_id));
+        if let Some(tracer) = &self.tracer {
+            tracer.push(operation, ids.as_ref(), start, elapsed);
+        }
+        let mut stats = self.stats.lock().unwrap();
+        let entry = stats.operations.entry((operation, ids)).or_default();
+        if result.is_ok() {
+            entry.latency.record(elapsed);
+        } else {
+            entry.errors += 1;
+        }
+        result
+    }
+
+    /// 音声合成一回分のリアルタイムファクタを記録し、その値を返す。
+    pub(crate) fn record_synthesis(
+        &self,
+        voice_model_id: &VoiceModelId,
+        style_id: StyleId,
+        processing_time: Duration,
+        audio_duration: Duration,
+    ) -> f64 {
+        let rtf = processing_time.as_secs_f64() / audio_duration.as_secs_f64();
+
+        let mut stats = self.stats.lock().unwrap();
+        let entry = stats
+            .real_time_factors
+            .entry((voice_model_id.clone(), style_id))
+            .or_insert_with(|| RealTimeFactorStats {
+                voice_model_id: voice_model_id.clone(),
+                style_id,
+                count: 0,
+                processing_time: Duration::ZERO,
+                audio_duration: Duration::ZERO,
+                min: f64::INFINITY,
+                max: f64::NEG_INFINITY,
+                last: f64::NAN,
+            });
+        entry.count += 1;
+        entry.processing_time += processing_time;
+        entry.audio_duration += audio_duration;
+        entry.min = entry.min.min(rtf);
+        entry.max = entry.max.max(rtf);
+        entry.last = rtf;
+        rtf
+    }
+
+    pub(crate) fn snapshot(&self) -> SynthesizerStats {
+        let stats = self.stats.lock().unwrap();
+        SynthesizerStats {
+            operations: stats
+                .operations
+                .iter()
+                .map(|((operation, ids), entry)| OperationStats {
+                    operation: *operation,
+                    voice_model_id: ids.as_ref().map(|(model_id, _)| model_id.clone()),
+                    style_id: ids.as_ref().map(|&(_, style_id)| style_id),
+                    errors: entry.errors,
+                    latency: entry.latency.clone(),
+                })
+                .collect(),
+            real_time_factors: stats.real_time_factors.values().cloned().collect(),
+        }
+    }
+
+    pub(crate) fn reset(&self) {
+        *self.stats.lock().unwrap() = Default::default();
+    }
+}
+
+/// `Synthesizer`自身が取った操作単位のトレースを、Chromeのtrace event形式のJSONで書き出す。
+///
+/// 記録は直近の[`Tracer::MAX_EVENTS`]件までを保持し、書き出しは`Synthesizer`の破棄時に行われる。
+// FIXME: ONNX Runtimeのプロファイリング(`SessionOptions`の`EnableProfiling`)によるノード単位の記録
+// には対応していない。onnxruntime-rsがこれを公開していないため。
+struct Tracer {
+    output: PathBuf,
+    origin: Instant,
+    events: std::sync::Mutex<VecDeque<serde_json::Value>>,
+}
+
+impl Tracer {
+    const MAX_EVENTS: usize = 100_000;
+
+    fn new(output: &Path) -> Self {
+        Self {
+            output: output.to_owned(),
+            origin: Instant::now(),
+            events: Default::default(),
+        }
+    }
+
+    fn push(
+        &self,
+        operation: StatsOperation,
+        ids: Option<&(VoiceModelId, StyleId)>,
+        start: Instant,
+        elapsed: Duration,
+    ) {
+        let mut args = serde_json::Map::new();
+        if let Some((model_id, style_id)) = ids {
+            args.insert("voice_model_id".into(), model_id.to_string().into());
+            args.insert("style_id".into(), style_id.raw_id().into());
+        }
+        let event = serde_json::json!({
+            "cat": "Session",
+            "pid": std::process::id(),
+            "tid": 0,
+            "dur": elapsed.as_micros() as u64,
+            "ts": start.saturating_duration_since(self.origin).as_micros() as u64,
+            "ph": "X",
+            "name": operation.to_string(),
+            "args": args,
+        });
+        let mut events = self.events.lock().unwrap();
+        if events.len() == Self::MAX_EVENTS {
+            events.pop_front();
+        }
+        events.push_back(event);
+    }
+
+    fn dump(&self) -> std::io::Result<()> {
+        let events = &*self.events.lock().unwrap();
+        let file = BufWriter::new(File::create(&self.output)?);
+        serde_json::to_writer(file, events)?;
+        Ok(())
+    }
+}
+
+impl Drop for Tracer {
+    fn drop(&mut self) {
+        if let Err(err) = self.dump() {
+            warn!(
+                "トレースを`{}`に書き出せませんでした: {err}",
+                self.output.display(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::{StyleId, VoiceModelId};
+
+    use super::{LatencyHistogram, StatsOperation, StatsRecorder, Tracer};
+
+    #[rstest]
+    fn latency_histogram_works() {
+        let mut histogram = LatencyHistogram::default();
+        assert_eq!(None, histogram.mean());
+
+        for ms in [1, 3, 3, 20_000] {
+            histogram.record(Duration::from_millis(ms));
+        }
+
+        assert_eq!(4, histogram.count());
+        assert_eq!(Some(Duration::from_millis(1)), histogram.min());
+        assert_eq!(Some(Duration::from_secs(20)), histogram.max());
+        assert_eq!(Some(Duration::from_micros(5_001_750)), histogram.mean());
+        let buckets = histogram.buckets().collect::<Vec<_>>();
+        assert_eq!((Some(Duration::from_millis(1)), 1), buckets[0]);
+        assert_eq!((Some(Duration::from_millis(5)), 2), buckets[2]);
+        assert_eq!((None, 1), buckets[LatencyHistogram::BUCKET_BOUNDS.len()]);
+    }
+
+    #[rstest]
+    fn latency_histogram_mean_works_beyond_u32_count() {
+        let histogram = LatencyHistogram {
+            count: 1 << 32,
+            sum: Duration::from_micros(3 << 32),
+            ..Default::default()
+        };
+        assert_eq!(Some(Duration::from_micros(3)), histogram.mean());
+    }
+
+    #[rstest]
+    fn stats_recorder_works() {
+        let recorder = StatsRecorder::new(None);
+        let model_id = VoiceModelId::new("model".to_owned());
+        let style_id = StyleId::new(0);
+
+        recorder
+            .measure(StatsOperation::TextAnalysis, None, || Ok(()))
+            .unwrap();
+        recorder
+            .measure(StatsOperation::Decode, Some((&model_id, style_id)), || {
+                Ok(())
+            })
+            .unwrap();
+        recorder
+            .measure(StatsOperation::Decode, Some((&model_id, style_id)), || {
+                Err::<(), _>(crate::error::ErrorRepr::StyleNotFound { style_id }.into())
+            })
+            .unwrap_err();
+        let rtf = recorder.record_synthesis(
+            &model_id,
+            style_id,
+            Duration::from_millis(500),
+            Duration::from_secs(2),
+        );
+        assert_eq!(0.25, rtf);
+
+        let stats = recorder.snapshot();
+        assert_eq!(2, stats.operations().len());
+        let decode = &stats.operations()[1];
+        assert_eq!(StatsOperation::Decode, *decode.operation());
+        assert_eq!(Some(style_id), *decode.style_id());
+        assert_eq!(1, decode.latency().count());
+        assert_eq!(1, *decode.errors());
+        assert_eq!(0.25, stats.real_time_factors()[0].overall());
+
+        recorder.reset();
+        assert!(recorder.snapshot().operations().is_empty());
+    }
+
+    #[rstest]
+    fn stats_recorder_untracked_works() {
+        let recorder = StatsRecorder::new(None);
+
+        recorder
+            .untracked(|| recorder.measure(StatsOperation::TextAnalysis, None, || Ok(())))
+            .unwrap();
+        assert!(recorder.snapshot().operations().is_empty());
+
+        recorder
+            .measure(StatsOperation::TextAnalysis, None, || Ok(()))
+            .unwrap();
+        assert_eq!(1, recorder.snapshot().operations().len());
+    }
+
+    #[rstest]
+    fn tracer_keeps_only_latest_events() {
+        let dir = tempfile::tempdir().unwrap();
+        let tracer = Tracer::new(&dir.path().join("trace.json"));
+        let start = tracer.origin;
+        for i in 0..=Tracer::MAX_EVENTS {
+            tracer.push(
+                StatsOperation::Decode,
+                None,
+                start + Duration::from_micros(i as _),
+                Duration::ZERO,
+            );
+        }
+
+        let events = tracer.events.lock().unwrap();
+        assert_eq!(Tracer::MAX_EVENTS, events.len());
+        assert_eq!(1, events[0]["ts"]);
+    }
+}
@@ -0,0 +1,295 @@
+//! 話者のUUIDとスタイル名による、スタイルの解決。
+//!
+//! [`StyleId`]は音声モデルの配布物ごとに異なりうるため、プロジェクトファイルなどに保存するときは
+//! 代わりに[`StyleKey`]を使い、読み込むときに解決するとよい。
+
+use std::cmp::Ordering;
+
+use itertools::Itertools as _;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::ErrorRepr, Result, SpeakerMeta, StyleId};
+
+/// [`StyleResolver::search_styles`]が結果に含める、検索語との近さの下限。
+const SEARCH_THRESHOLD: f64 = 0.5;
+
+/// 話者のUUIDとスタイル名の組。
+///
+/// [`StyleId`]と異なり、音声モデルの配布物によらずスタイルを指す。
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub struct StyleKey {
+    /// 話者のUUID。
+    pub speaker_uuid: String,
+    /// スタイル名。
+    pub style_name: String,
+}
+
+/// [`StyleResolver::search_styles`]の検索結果。
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct StyleCandidate {
+    /// 話者名。
+    pub speaker_name: String,
+    /// 話者のUUID。
+    pub speaker_uuid: String,
+    /// スタイル名。
+    pub style_name: String,
+    /// スタイルID。
+    pub style_id: StyleId,
+    /// 検索語との近さ。0以上1以下で、完全に一致するときに1となる。
+    pub score: f64,
+}
+
+/// メタ情報から、[`StyleKey`]と[`StyleId`]を相互に解決する。
+///
+/// [`VoiceModelMeta`]に対して実装されている。[`Synthesizer`]も同名のメソッドを持つ。
+///
+/// [`VoiceModelMeta`]: crate::VoiceModelMeta
+/// [`Synthesizer`]: crate::blocking::Synthesizer
+pub trait StyleResolver {
+    /// 話者のUUIDとスタイル名から、スタイルIDを得る。
+    ///
+    /// スタイル名は完全に一致する必要がある。
+    ///
+    /// # Errors
+    ///
+    /// 話者が見つからないとき、[`ErrorKind::SpeakerNotFound`]を表わすエラーを返す。
+    ///
+    /// スタイルが見つからないとき、[`ErrorKind::StyleNotFound`]を表わすエラーを返す。エラーメッセー
+    /// ジには、その話者のスタイル名が`style_name`に近い順に候補として挙げられる。
+    ///
+    /// [`ErrorKind::SpeakerNotFound`]: crate::ErrorKind::SpeakerNotFound
+    /// [`ErrorKind::StyleNotFound`]: crate::ErrorKind::StyleNotFound
+    fn resolve_style_id(&self, speaker_uuid: &str, style_name: &str) -> Result<StyleId>;
+
+    /// スタイルIDから、話者のUUIDとスタイル名を得る。
+    ///
+    /// # Errors
+    ///
+    /// スタイルが見つからないとき、[`ErrorKind::StyleNotFound`]を表わすエラーを返す。
+    ///
+    /// [`ErrorKind::StyleNotFound`]: crate::ErrorKind::StyleNotFound
+    fn resolve_style_key(&self, style_id: StyleId) -> Result<StyleKey>;
+
+    /// 話者名とスタイル名から、スタイルをあいまいに検索する。
+    ///
+    /// `query`は空白で区切られた語の列として扱われ、それぞれの語が話者名(言語ごとの話者名を含む)
+    /// かスタイル名のいずれかに近いスタイルが、近い順に返される。
+    fn search_styles(&self, query: &str) -> Vec<StyleCandidate>;
+}
+
+impl StyleResolver for [SpeakerMeta] {
+    fn resolve_style_id(&self, speaker_uuid: &str, style_name: &str) -> Result<StyleId> {
+        let styles = self
+            .iter()
+            .filter(|speaker| speaker.speaker_uuid() == speaker_uuid)
+            .flat_map(SpeakerMeta::styles)
+            .collect::<Vec<_>>();
+        if styles.is_empty() {
+            return Err(ErrorRepr::SpeakerNotFound {
+                speaker_uuid: speaker_uuid.to_owned(),
+            }
+            .into());
+        }
+
+        if let Some(style) = styles.iter().find(|style| style.name() == style_name) {
+            return Ok(*style.id());
+        }
+        let candidates = styles
+            .iter()
+            .map(|style| style.name())
+            .unique()
+            .sorted_by(|name1, name2| {
+                let score = |name| similarity(style_name, name);
+                cmp_scores(score(name1), score(name2))
+            })
+            .cloned()
+            .collect();
+        Err(ErrorRepr::StyleNameNotFound {
+            speaker_uuid: speaker_uuid.to_owned(),
+            style_name: style_name.to_owned(),
+            candidates,
+        }
+        .into())
+    }
+
+    fn resolve_style_key(&self, style_id: StyleId) -> Result<StyleKey> {
+        self.iter()
+            .find_map(|speaker| {
+                let style = speaker
+                    .styles()
+                    .iter()
+                    .find(|style| *style.id() == style_id)?;
+                Some(StyleKey {
+                    speaker_uuid: speaker.speaker_uuid().clone(),
+                    style_name: style.name().clone(),
+                })
+            })
+            .ok_or_else(|| ErrorRepr::StyleNotFound { style_id }.into())
+    }
+
+    fn search_styles(&self, query: &str) -> Vec<StyleCandidate> {
+        let words = query.split_whitespace().collect::<Vec<_>>();
+        if words.is_empty() {
+            return vec![];
+        }
+
+        self.iter()
+            .flat_map(|speaker| {
+                let speaker_names = [speaker.name()]
+                    .into_iter()
+                    .chain(speaker.localized_names().values())
+                    .collect::<Vec<_>>();
+                let words = &words;
+                speaker.styles().iter().map(move |style| {
+                    let score = words
+                        .iter()
+                        .map(|word| {
+                            speaker_names
+                                .iter()
+                                .copied()
+                                .chain([style.name()])
+                                .map(|name| similarity(word, name))
+                                .fold(0., f64::max)
+                        })
+                        .fold(1., f64::min);
+                    StyleCandidate {
+                        speaker_name: speaker.name().clone(),
+                        speaker_uuid: speaker.speaker_uuid().clone(),
+                        style_name: style.name().clone(),
+                        style_id: *style.id(),
+                        score,
+                    }
+                })
+            })
+            .filter(|candidate| candidate.score >= SEARCH_THRESHOLD)
+            .sorted_by(|candidate1, candidate2| cmp_scores(candidate1.score, candidate2.score))
+            .collect()
+    }
+}
+
+/// `query`と`name`の近さを、0以上1以下で返す。
+///
+/// 大文字と小文字は区別しない。`name`が`query`を含むときは、完全に一致する場合に次いで近いもの
+/// とする。
+fn similarity(query: &str, name: &str) -> f64 {
+    let (query, name) = (query.to_lowercase(), name.to_lowercase());
+    if query == name {
+        1.
+    } else if name.contains(&query) {
+        0.9
+    } else {
+        strsim::normalized_levenshtein(&query, &name).min(0.9)
+    }
+}
+
+/// 近いものが先に来るように比較する。
+fn cmp_scores(score1: f64, score2: f64) -> Ordering {
+    score2.total_cmp(&score1)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use serde_json::json;
+
+    use crate::{ErrorKind, SpeakerMeta, StyleId};
+
+    use super::{StyleKey, StyleResolver as _};
+
+    fn metas() -> Vec<SpeakerMeta> {
+        serde_json::from_value(json!([
+            {
+                "name": "ずんだもん",
+                "styles": [
+                    { "id": 3, "name": "ノーマル" },
+                    { "id": 1, "name": "あまあま" },
+                    { "id": 7, "name": "ツンツン" },
+                ],
+                "version": "0.0.1",
+                "speaker_uuid": "388f246b-8c41-4ac1-8e2d-5d79f3ff56d9",
+                "localized_names": { "en": "Zundamon" },
+            },
+            {
+                "name": "四国めたん",
+                "styles": [
+                    { "id": 2, "name": "ノーマル" },
+                    { "id": 0, "name": "あまあま" },
+                ],
+                "version": "0.0.1",
+                "speaker_uuid": "7ffcb7ce-00ec-4bdc-82cd-45a8889e43ff",
+            },
+        ]))
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case("388f246b-8c41-4ac1-8e2d-5d79f3ff56d9", "ノーマル", 3)]
+    #[case("388f246b-8c41-4ac1-8e2d-5d79f3ff56d9", "ツンツン", 7)]
+    #[case("7ffcb7ce-00ec-4bdc-82cd-45a8889e43ff", "あまあま", 0)]
+    fn resolve_works(#[case] speaker_uuid: &str, #[case] style_name: &str, #[case] expected: u32) {
+        let metas = metas();
+
+        let style_id = metas.resolve_style_id(speaker_uuid, style_name).unwrap();
+        assert_eq!(StyleId::new(expected), style_id);
+
+        let key = metas.resolve_style_key(style_id).unwrap();
+        assert_eq!(
+            StyleKey {
+                speaker_uuid: speaker_uuid.to_owned(),
+                style_name: style_name.to_owned(),
+            },
+            key,
+        );
+    }
+
+    #[rstest]
+    fn resolve_style_id_lists_candidates() {
+        let err = metas()
+            .resolve_style_id("388f246b-8c41-4ac1-8e2d-5d79f3ff56d9", "ツンツソ")
+            .unwrap_err();
+        assert_eq!(ErrorKind::StyleNotFound, err.kind());
+        assert_eq!(
+            "話者`388f246b-8c41-4ac1-8e2d-5d79f3ff56d9`に`ツンツソ`という名前のスタイルが見つかりま\
+             せんでした。候補: `ツンツン`, `ノーマル`, `あまあま`",
+            err.to_string(),
+        );
+    }
+
+    #[rstest]
+    fn resolve_fails_for_unknown_ids() {
+        let metas = metas();
+        assert_eq!(
+            ErrorKind::SpeakerNotFound,
+            metas
+                .resolve_style_id("00000000-0000-0000-0000-000000000000", "ノーマル")
+                .unwrap_err()
+                .kind(),
+        );
+        assert_eq!(
+            ErrorKind::StyleNotFound,
+            metas
+                .resolve_style_key(StyleId::new(100))
+                .unwrap_err()
+                .kind(),
+        );
+    }
+
+    #[rstest]
+    #[case("ずんだもん", &[3, 1, 7])]
+    #[case("zundamon", &[3, 1, 7])]
+    #[case("ずんだもん あまあま", &[1])]
+    #[case("あまあま", &[1, 0])]
+    #[case("あまああま", &[1, 0])]
+    #[case("めたん ノーマル", &[2])]
+    #[case("", &[])]
+    #[case("ささやき", &[])]
+    fn search_styles_works(#[case] query: &str, #[case] expected: &[u32]) {
+        let style_ids = metas()
+            .search_styles(query)
+            .into_iter()
+            .map(|candidate| candidate.style_id.raw_id())
+            .collect::<Vec<_>>();
+        assert_eq!(expected, style_ids);
+    }
+}
@@ -1,4 +1,9 @@
-use crate::infer::runtimes::Onnxruntime;
+use std::{collections::BTreeMap, path::PathBuf};
+
+use crate::{
+    infer::runtimes::{Onnxruntime, OutOfProcess},
+    ErrorKind, Result, StyleId,
+};
 
 /// [`blocking::Synthesizer::synthesis`]および[`tokio::Synthesizer::synthesis`]のオプション。
 ///
@@ -66,21 +71,212 @@ pub enum AccelerationMode {
 pub struct InitializeOptions {
     pub acceleration_mode: AccelerationMode,
     pub cpu_num_threads: u16,
+    /// GPUを使うときの、GPUの番号。
+    ///
+    /// CUDAではCUDAのデバイス番号、DirectMLではDXGIのアダプタの列挙順の番号となる。GPUを使う場合、
+    /// GPUの数が得られる環境では`Synthesizer`の作成時に検証される。
+    pub gpu_device_index: u16,
+    /// GPUを使うときの、GPUメモリの使用量の上限(バイト)。0の場合は上限を設けない。
+    ///
+    /// CUDAでのみ有効。
+    pub gpu_memory_limit: usize,
+    /// 一つの音声モデルの一つの推論操作につき、同時に保持する`Session`の最大数。
+    ///
+    /// 同じ音声モデルに対する並列な音声合成は、この数まで同時に推論を行える。2つ目以降の`Session`は
+    /// 必要になったときに作られる。0の場合は1として扱う。
+    pub max_sessions_per_operation: usize,
+    /// 指定されている場合、`Synthesizer`自身が取った操作単位のトレースを、`Synthesizer`の破棄時にこの
+    /// パスに書き出す。
+    ///
+    /// 形式はChromeのtrace event形式のJSONで、記録は直近の100,000件までが保持される。ONNX Runtime
+    /// のプロファイリングではなく、ノード単位の記録は含まない。
+    pub trace_output: Option<PathBuf>,
+    /// `true`の場合、音声モデルの読み込み時にウォームアップを行う。
+    ///
+    /// ウォームアップに失敗した場合、その音声モデルの読み込みは取り消される。詳しくは
+    /// [`blocking::Synthesizer::warm_up`]を参照。
+    ///
+    /// [`blocking::Synthesizer::warm_up`]: blocking::Synthesizer::warm_up
+    pub warm_up_on_load: bool,
+    /// `true`の場合、音声モデルの読み込み時には`Session`を作らず、メタ情報とVVMファイルの場所のみ
+    /// を登録する。
+    ///
+    /// それぞれの`Session`は、その推論が最初に行われるときに作られる。そのため読み込みは速くなるが、
+    /// 最初の推論は遅くなり、VVMファイルの不備もそのときまで発見されない。
+    pub lazy_session_creation: bool,
+    /// `Session`を保持する音声モデルの数の上限。0の場合は上限を設けない。
+    ///
+    /// 上限を超えた場合、最も長く使われていない音声モデルの`Session`が破棄される。破棄された音声
+    /// モデルも読み込まれたままであり、次に推論が行われるときにVVMファイルから`Session`が作り直
+    /// される。
+    pub max_resident_voice_models: usize,
+    /// `Session`が使うメモリの上限(バイト)。0の場合は上限を設けない。
+    ///
+    /// 上限を超えたときの挙動は[`max_resident_voice_models`]と同じ。使用量は、作られた`Session`の
+    /// 数とモデルの大きさから見積もられるものであり、実際の使用量とは一致しない。
+    ///
+    /// [`max_resident_voice_models`]: Self::max_resident_voice_models
+    pub session_memory_budget: usize,
+    /// `true`の場合、読み込んだ音声モデルのVVMファイルを監視し、変更されたときに
+    /// [`blocking::Synthesizer::reload_voice_model`]と同様に読み込み直す。
+    ///
+    /// 読み込み直しは変更が止んでから(0.5秒後に)専用のスレッドで行われる。
+    ///
+    /// 読み込み直しに失敗した場合はログに記録され、元の音声モデルが使われ続ける。書き込み途中の
+    /// VVMファイルが読まれないよう、VVMファイルは別のファイルからの移動で置き換えることが推奨される。
+    /// 展開されたVVMの場合は、ディレクトリ直下のファイルが変更されるたびに読み込み直す。
+    /// 監視を開始できなかった場合も、ログに記録した上で監視無しで動作する。
+    ///
+    /// [`blocking::Synthesizer::reload_voice_model`]: blocking::Synthesizer::reload_voice_model
+    pub watch_voice_model_files: bool,
+    /// `true`の場合、テキスト解析と推論を子プロセス(ワーカープロセス)の中で行う。
+    ///
+    /// ワーカープロセスがクラッシュしてもこのプロセスは巻き込まれず、その処理は
+    /// [`ErrorKind::WorkerCrashed`]を表わすエラーとなる。ワーカープロセスは次の処理の際に起動し直
+    /// され、`Session`もそのときに作り直される。一つの要求に120秒以内の応答が無い場合もワーカー
+    /// プロセスはハングしたとみなされ、終了させられて同じエラーとなる。
+    ///
+    /// ワーカープロセスは[`max_sessions_per_operation`]個までの子プロセスから成り、並列な音声合成は
+    /// それらで並列に処理される。
+    ///
+    /// ワーカープロセスに`Session`を作り直させるため、このプロセスは`Session`一つにつき復号済みの
+    /// モデル全体の複製を保持し続ける。これは省けないコストであり、ワーカープロセスを使わない場合に
+    /// 比べ、このプロセスのメモリ使用量は`Session`の数×モデルの大きさだけ増える。
+    /// [`session_memory_budget`]ではその分も数えられる。
+    ///
+    /// ワーカープロセスとして起動されるプログラムは、`main`関数の最初で
+    /// [`run_worker_if_requested`]を呼ばなければならない。
+    ///
+    /// テキスト解析がワーカープロセスで行われるのは、テキスト解析器が[`blocking::OpenJtalk`]か
+    /// [`tokio::OpenJtalk`]である場合のみである。その場合、ユーザー辞書の設定(`use_user_dict`)での
+    /// コンパイルもワーカープロセスの中でのみ行われ、このプロセスでは行われない。
+    ///
+    /// [`max_sessions_per_operation`]: Self::max_sessions_per_operation
+    /// [`session_memory_budget`]: Self::session_memory_budget
+    /// [`ErrorKind::WorkerCrashed`]: crate::ErrorKind::WorkerCrashed
+    /// [`run_worker_if_requested`]: crate::run_worker_if_requested
+    /// [`blocking::OpenJtalk`]: crate::blocking::OpenJtalk
+    /// [`tokio::OpenJtalk`]: crate::tokio::OpenJtalk
+    pub out_of_process: bool,
+    /// ワーカープロセスとして起動するプログラム。`None`の場合は現在の実行ファイル自身を起動する。
+    ///
+    /// [`out_of_process`]が`false`の場合は無視される。
+    ///
+    /// [`out_of_process`]: Self::out_of_process
+    pub worker_program: Option<PathBuf>,
+    /// 音声モデルの署名に対する方針。
+    ///
+    /// 方針によらず、署名のある音声モデルは常に検証され、内容が署名と合わない場合は読み込みに失敗
+    /// する。
+    pub signature_policy: SignaturePolicy,
+    /// 信頼する署名者の、Ed25519の公開鍵。
+    ///
+    /// [`SignaturePolicy::Strict`]の場合に使われる。
+    pub trusted_public_keys: Vec<[u8; 32]>,
+}
+
+/// 音声モデルの署名に対する方針。
+///
+/// 署名はVVMに同梱される。詳しくは[VVMのドキュメント](https://github.com/VOICEVOX/voicevox_core/blob/main/docs/vvm.md)を参照。
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignaturePolicy {
+    /// 署名の無い音声モデルや、信頼されていない署名者による音声モデルも読み込む。
+    #[default]
+    Permissive,
+    /// [`InitializeOptions::trusted_public_keys`]のいずれかで署名された音声モデルのみを読み込む。
+    ///
+    /// それ以外の音声モデルの読み込みは[`ErrorKind::UntrustedVoiceModel`]を表わすエラーとなる。
+    ///
+    /// [`ErrorKind::UntrustedVoiceModel`]: crate::ErrorKind::UntrustedVoiceModel
+    Strict,
+}
+
+/// [`blocking::Synthesizer::load_voice_model_with_options`]および
+/// [`tokio::Synthesizer::load_voice_model_with_options`]のオプション。
+///
+/// 指定しなかった項目は[`InitializeOptions`]に従う。
+///
+/// [`blocking::Synthesizer::load_voice_model_with_options`]: blocking::Synthesizer::load_voice_model_with_options
+/// [`tokio::Synthesizer::load_voice_model_with_options`]: tokio::Synthesizer::load_voice_model_with_options
+#[derive(Default, Debug, Clone)]
+pub struct LoadVoiceModelOptions {
+    /// `decode`をGPUで実行するか。
+    ///
+    /// `Synthesizer`がCPUモードであっても、GPUが利用可能であれば`Some(true)`を指定できる。
+    pub use_gpu_for_decode: Option<bool>,
+    /// それぞれの推論に使うCPUのスレッド数。
+    pub cpu_num_threads: Option<u16>,
+    /// `predict_duration`と`predict_intonation`とで、`Session`のプールを共有するか。
+    ///
+    /// 共有する場合、両者の`Session`は組として作られ、組単位で使い回される。同時に推論できる数の上限
+    /// ([`InitializeOptions::max_sessions_per_operation`])は両者の合計に対するものとなり、メモリの
+    /// 予算の上でも一つとして扱われる。
+    pub share_light_sessions: bool,
+    /// 音声モデルのスタイルIDが、既に読み込まれている音声モデルのものと重複したときの扱い。
+    pub style_id_remapping: StyleIdRemapping,
+}
+
+/// 音声モデルのスタイルIDの付け替えの方針。
+///
+/// 付け替えられたスタイルIDは、音声モデル内の元のスタイルに対応する。`Synthesizer`の
+/// `metas`は付け替え後のスタイルIDを返す。音声モデルを読み込み直した場合も、同じ方針で付け替えが
+/// 行われる。
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub enum StyleIdRemapping {
+    /// 付け替えを行わない。スタイルIDが重複する場合は読み込みに失敗する。
+    #[default]
+    None,
+    /// 元のスタイルIDから新しいスタイルIDへの対応に従って付け替える。
+    ///
+    /// 含まれないスタイルIDはそのまま使われる。付け替え後のスタイルIDが重複する場合は読み込みに
+    /// 失敗する。
+    Explicit(BTreeMap<StyleId, StyleId>),
+    /// 重複したスタイルIDを、使われていないスタイルIDに付け替える。
+    ///
+    /// 重複しないスタイルIDはそのまま使われる。新しいスタイルIDは、使われているスタイルIDの最大値
+    /// の次から順に割り当てられる。
+    AutoAssign,
 }
 
-pub(crate) type InferenceRuntimeImpl = Onnxruntime;
+pub(crate) type InferenceRuntimeImpl = OutOfProcess<LocalInferenceRuntimeImpl>;
+
+/// ワーカープロセスの中、もしくはワーカープロセスを使わない場合に使われる`InferenceRuntime`。
+pub(crate) type LocalInferenceRuntimeImpl = Onnxruntime;
+
+/// 音声モデルの読み込みが、他の呼び出しによって先に同じスタイルが読み込まれたために失敗したので
+/// あれば、成功とみなす。
+fn ok_if_loaded_concurrently(result: Result<()>, is_loaded: impl FnOnce() -> bool) -> Result<()> {
+    match result {
+        Err(err)
+            if matches!(
+                err.kind(),
+                ErrorKind::ModelAlreadyLoaded | ErrorKind::StyleAlreadyLoaded
+            ) && is_loaded() =>
+        {
+            Ok(())
+        }
+        result => result,
+    }
+}
 
 pub(crate) mod blocking {
     // FIXME: ここのdocのコードブロックはasync版のものなので、`tokio`モジュールの方に移した上で、
     // (ブロッキング版をpublic APIにするならの話ではあるが)ブロッキング版はブロッキング版でコード例
     // を用意する
 
-    use std::io::{Cursor, Write as _};
+    use std::{
+        io::{Cursor, Write as _},
+        num::NonZeroUsize,
+        path::Path,
+        sync::Arc,
+        time::{Duration, Instant},
+    };
 
-    use enum_map::enum_map;
+    use enum_map::{enum_map, EnumMap};
+    use tracing::{error, info, warn};
 
     use crate::{
-        engine::{create_kana, mora_to_text, MoraModel, OjtPhoneme},
+        engine::{attach_open_jtalk_worker, create_kana, mora_to_text, MoraModel, OjtPhoneme},
         error::ErrorRepr,
         infer::{
             domain::{
@@ -88,24 +284,38 @@ pub(crate) mod blocking {
                 PredictDurationInput, PredictDurationOutput, PredictIntonationInput,
                 PredictIntonationOutput,
             },
-            status::Status,
+            status::{ModelSessionOptions, SessionBudget, Status},
             InferenceSessionOptions,
         },
+        metas,
+        stats::{StatsOperation, StatsRecorder, SynthesizerStats},
         text_analyzer::{KanaAnalyzer, OpenJTalkAnalyzer, TextAnalyzer},
-        AccentPhraseModel, AudioQueryModel, FullcontextExtractor, Result, StyleId,
-        SupportedDevices, SynthesisOptions, VoiceModelId, VoiceModelMeta,
+        voice_model::VvmSource,
+        voice_model_watcher::{self, VoiceModelWatcher},
+        vvm_signature::SignatureTrust,
+        worker::Worker,
+        AccentPhraseModel, AudioQueryModel, FullcontextExtractor, Result, SpeakerInfo,
+        StyleCandidate, StyleId, StyleKey, StyleResolver as _, StyleType, SupportedDevices,
+        SynthesisOptions, VoiceModelId, VoiceModelMeta,
     };
 
-    use super::{AccelerationMode, InferenceRuntimeImpl, InitializeOptions, TtsOptions};
+    use super::{
+        AccelerationMode, InferenceRuntimeImpl, InitializeOptions, LoadVoiceModelOptions,
+        TtsOptions,
+    };
 
     const DEFAULT_SAMPLING_RATE: u32 = 24000;
 
     /// 音声シンセサイザ。
     pub struct Synthesizer<O> {
-        pub(super) status: Status<InferenceRuntimeImpl, InferenceDomainImpl>,
+        pub(super) status: Arc<Status<InferenceRuntimeImpl, InferenceDomainImpl>>,
         open_jtalk_analyzer: OpenJTalkAnalyzer<O>,
         kana_analyzer: KanaAnalyzer,
         use_gpu: bool,
+        pub(super) warm_up_on_load: bool,
+        pub(super) lazy_session_creation: bool,
+        watcher: Option<VoiceModelWatcher>,
+        stats: StatsRecorder,
     }
 
     impl<O> self::Synthesizer<O> {
@@ -139,9 +349,14 @@ pub(crate) mod blocking {
         /// # Ok(())
         /// # }
         /// ```
-        pub fn new(open_jtalk: O, options: &InitializeOptions) -> Result<Self> {
+        pub fn new(open_jtalk: O, options: &InitializeOptions) -> Result<Self>
+        where
+            O: 'static,
+        {
             #[cfg(windows)]
-            list_windows_video_cards();
+            let num_video_cards = list_windows_video_cards(options.gpu_device_index);
+            #[cfg(not(windows))]
+            let num_video_cards = None;
 
             let use_gpu = match options.acceleration_mode {
                 AccelerationMode::Auto => {
@@ -161,36 +376,91 @@ pub(crate) mod blocking {
                 return Err(ErrorRepr::GpuSupport.into());
             }
 
+            // GPUを使わない場合やGPUの数が得られなかった場合は、`Session`の作成時まで検証されない
+            let num_devices = if !use_gpu {
+                None
+            } else if cfg!(feature = "directml") {
+                num_video_cards
+            } else {
+                crate::devices::count_cuda_devices()
+            };
+            if let Some(num_devices) = num_devices {
+                if usize::from(options.gpu_device_index) >= num_devices {
+                    return Err(ErrorRepr::GpuDeviceNotFound {
+                        index: options.gpu_device_index,
+                    }
+                    .into());
+                }
+            }
+
+            if cfg!(feature = "directml") && options.gpu_memory_limit != 0 {
+                warn!("DirectMLでは`gpu_memory_limit`は無視されます");
+            }
+
+            let max_sessions =
+                NonZeroUsize::new(options.max_sessions_per_operation).unwrap_or(NonZeroUsize::MIN);
+
+            // 並列な音声合成のため、`Session`の数だけ子プロセスを起動しうる
+            let worker = options
+                .out_of_process
+                .then(|| Arc::new(Worker::new(options.worker_program.clone(), max_sessions)));
+            if let Some(worker) = &worker {
+                attach_open_jtalk_worker(&open_jtalk, worker);
+            }
+
+            // 音声モデルごとにGPUを使うよう指定されうるため、GPUの設定はすべてに与えておく
+            let session_options = |use_gpu| InferenceSessionOptions {
+                gpu_device_index: options.gpu_device_index,
+                gpu_memory_limit: options.gpu_memory_limit,
+                worker: worker.clone(),
+                ..InferenceSessionOptions::new(options.cpu_num_threads, use_gpu, max_sessions)
+            };
+
             // 軽いモデルはこちらを使う
-            let light_session_options =
-                InferenceSessionOptions::new(options.cpu_num_threads, false);
+            let light_session_options = session_options(false);
 
             // 重いモデルはこちらを使う
-            let heavy_session_options =
-                InferenceSessionOptions::new(options.cpu_num_threads, use_gpu);
+            let heavy_session_options = session_options(use_gpu);
 
             let status = Status::new(enum_map! {
                 InferenceOperationImpl::PredictDuration
-                | InferenceOperationImpl::PredictIntonation => light_session_options,
-                InferenceOperationImpl::Decode => heavy_session_options,
+                | InferenceOperationImpl::PredictIntonation => light_session_options.clone(),
+                InferenceOperationImpl::Decode => heavy_session_options.clone(),
+            })
+            .with_budget(SessionBudget {
+                max_models: NonZeroUsize::new(options.max_resident_voice_models),
+                max_bytes: NonZeroUsize::new(options.session_memory_budget),
+            })
+            .with_signature_trust(SignatureTrust {
+                policy: options.signature_policy,
+                trusted_public_keys: options.trusted_public_keys.clone(),
             });
+            let status = Arc::new(status);
+
+            let watcher = if options.watch_voice_model_files {
+                let status = Arc::downgrade(&status);
+                let lazy_session_creation = options.lazy_session_creation;
+                VoiceModelWatcher::new(move |path| {
+                    if let Some(status) = status.upgrade() {
+                        reload_changed_voice_models(&status, lazy_session_creation, path);
+                    }
+                })
+                .inspect_err(|err| warn!("VVMファイルの監視を開始できませんでした: {err}"))
+                .ok()
+            } else {
+                None
+            };
 
-            return Ok(Self {
+            Ok(Self {
                 status,
-                open_jtalk_analyzer: OpenJTalkAnalyzer::new(open_jtalk),
+                open_jtalk_analyzer: OpenJTalkAnalyzer::new(open_jtalk, worker),
                 kana_analyzer: KanaAnalyzer,
                 use_gpu,
-            });
-
-            fn can_support_gpu_feature() -> Result<bool> {
-                let supported_devices = SupportedDevices::create()?;
-
-                if cfg!(feature = "directml") {
-                    Ok(*supported_devices.dml())
-                } else {
-                    Ok(*supported_devices.cuda())
-                }
-            }
+                warm_up_on_load: options.warm_up_on_load,
+                lazy_session_creation: options.lazy_session_creation,
+                watcher,
+                stats: StatsRecorder::new(options.trace_output.as_deref()),
+            })
         }
 
         /// ハードウェアアクセラレーションがGPUモードか判定する。
@@ -200,13 +470,256 @@ pub(crate) mod blocking {
 
         /// 音声モデルを読み込む。
         pub fn load_voice_model(&self, model: &crate::blocking::VoiceModel) -> Result<()> {
-            let model_bytes = &model.read_inference_models()?;
-            self.status.insert_model(model.header(), model_bytes)
+            self.load_voice_model_with_options(model, &Default::default())
+        }
+
+        /// 音声モデルを、この音声モデル用の`Session`の設定とともに読み込む。
+        pub fn load_voice_model_with_options(
+            &self,
+            model: &crate::blocking::VoiceModel,
+            options: &LoadVoiceModelOptions,
+        ) -> Result<()> {
+            let session_options = &self.model_session_options(options)?;
+            if self.lazy_session_creation {
+                self.status.insert_model_lazily(
+                    model.header(),
+                    model.header().model_reader(),
+                    session_options,
+                    &options.style_id_remapping,
+                )?;
+            } else {
+                let model_bytes = &model.read_inference_models()?;
+                self.status.insert_model(
+                    model.header(),
+                    model_bytes,
+                    model.header().model_reader(),
+                    session_options,
+                    &options.style_id_remapping,
+                )?;
+            }
+            if self.warm_up_on_load {
+                self.warm_up_or_unload(model.id())?;
+            }
+            self.watch_voice_model_file(&model.header().source);
+            Ok(())
+        }
+
+        /// `model_set`から、`style_id`のスタイルを含む音声モデルを読み込む。
+        ///
+        /// そのスタイルが既に読み込まれている場合は何もしない。他のスレッドが同時に同じ音声モデルを
+        /// 読み込んだ場合も同様に扱う。
+        ///
+        /// # Errors
+        ///
+        /// `model_set`がそのスタイルを含まない場合、[`ErrorKind::StyleNotFound`]を表わすエラーを返
+        /// す。
+        ///
+        /// [`ErrorKind::StyleNotFound`]: crate::ErrorKind::StyleNotFound
+        pub fn load_voice_model_by_style_id(
+            &self,
+            model_set: &crate::blocking::VoiceModelSet,
+            style_id: StyleId,
+        ) -> Result<()> {
+            if self.is_loaded_model_by_style_id(style_id) {
+                return Ok(());
+            }
+            let result = self.load_voice_model(model_set.voice_model_by_style_id(style_id)?);
+            super::ok_if_loaded_concurrently(result, || self.is_loaded_model_by_style_id(style_id))
+        }
+
+        /// 読み込まれている音声モデルを、VVMファイルから読み込み直す。
+        ///
+        /// 音声モデルIDは変わらず、読み込み時に指定した`Session`の設定も引き継がれる。新しい
+        /// `Session`への切り替えは一度に行われ、既に始まっている推論は元の`Session`で最後まで行われ
+        /// る。読み込み直しに失敗した場合、元の音声モデルが読み込まれたままとなる。
+        ///
+        /// メモリ上のVVMから読み込まれた音声モデルは、同じ内容から読み込み直される。
+        ///
+        /// ウォームアップは[`InitializeOptions::warm_up_on_load`]によらず行われない。
+        pub fn reload_voice_model(&self, voice_model_id: &VoiceModelId) -> Result<()> {
+            reload_voice_model(&self.status, self.lazy_session_creation, voice_model_id)
+        }
+
+        /// VVMファイルを監視する。メモリ上のVVMに対しては何もしない。
+        pub(super) fn watch_voice_model_file(&self, source: &VvmSource) {
+            if let (Some(watcher), Some(path)) = (&self.watcher, source.path()) {
+                if let Err(err) = watcher.watch(path) {
+                    warn!("`{}`の監視を開始できませんでした: {err}", path.display());
+                }
+            }
+        }
+
+        pub(super) fn model_session_options(
+            &self,
+            options: &LoadVoiceModelOptions,
+        ) -> Result<ModelSessionOptions<InferenceDomainImpl>> {
+            let mut session_options = self.status.default_model_session_options();
+
+            if let Some(use_gpu) = options.use_gpu_for_decode {
+                if use_gpu && !self.use_gpu && !can_support_gpu_feature()? {
+                    return Err(ErrorRepr::GpuSupport.into());
+                }
+                session_options.sessions[InferenceOperationImpl::Decode].use_gpu = use_gpu;
+            }
+            if let Some(cpu_num_threads) = options.cpu_num_threads {
+                for (_, session_options) in &mut session_options.sessions {
+                    session_options.cpu_num_threads = cpu_num_threads;
+                }
+            }
+            if options.share_light_sessions {
+                session_options.pool_owners[InferenceOperationImpl::PredictIntonation] =
+                    InferenceOperationImpl::PredictDuration;
+            }
+
+            Ok(session_options)
+        }
+
+        /// 音声モデルのウォームアップを行う。
+        ///
+        /// ONNX Runtimeはメモリの確保などを最初の推論時まで遅らせるため、読み込み直後の音声合成は
+        /// 遅くなる。この関数は音声モデルのそれぞれの推論を代表的なダミーの入力で一度ずつ実行し、
+        /// その遅延を前もって済ませる。
+        ///
+        /// 併せて、推論結果が期待される長さを持ち、かつ有限の値のみから成るかを検査する。音声モデル
+        /// が提供していない推論操作は飛ばされる。
+        ///
+        /// ここでの推論は[`stats`]には記録されない。
+        ///
+        /// # Errors
+        ///
+        /// 推論に失敗したとき、または推論結果が不正だったとき、
+        /// [`ErrorKind::WarmUpFailed`]を表わすエラーを返す。
+        ///
+        /// [`ErrorKind::WarmUpFailed`]: crate::ErrorKind::WarmUpFailed
+        /// [`stats`]: Self::stats
+        pub fn warm_up(&self, voice_model_id: &VoiceModelId) -> Result<()> {
+            // `Session`は音声モデル内のすべてのスタイルで共有されているため、一つのスタイルで十分。
+            // ただし推論操作はトーク用のものであるため、トークのスタイルを選ぶ
+            let Some(style_id) =
+                self.status
+                    .style_ids_of(voice_model_id)?
+                    .into_iter()
+                    .find(|&style_id| {
+                        self.status.style_type_of(style_id).ok() == Some(StyleType::Talk)
+                    })
+            else {
+                return Ok(());
+            };
+
+            let provided = self.status.provided_operations(voice_model_id)?;
+
+            self.stats
+                .untracked(|| self.self_test(style_id, &provided))
+                .map_err(|source| {
+                    ErrorRepr::WarmUpFailed {
+                        model_id: voice_model_id.clone(),
+                        source,
+                    }
+                    .into()
+                })
+        }
+
+        pub(super) fn warm_up_or_unload(&self, voice_model_id: &VoiceModelId) -> Result<()> {
+            self.warm_up(voice_model_id).inspect_err(|_| {
+                // 読み込みを取り消すだけなので、ここでのエラーは無視してよい
+                // まだ監視は始まっていないため、`unload_voice_model`は使わない
+                let _ = self.status.unload_model(voice_model_id);
+            })
+        }
+
+        fn self_test(
+            &self,
+            style_id: StyleId,
+            provided: &EnumMap<InferenceOperationImpl, bool>,
+        ) -> anyhow::Result<()> {
+            // 「テスト」という文章に対応する入力
+            const PHONEME_VECTOR: [i64; 8] = [0, 37, 14, 35, 6, 37, 30, 0];
+            const VOWEL_PHONEME_VECTOR: [i64; 5] = [0, 14, 6, 30, 0];
+            const CONSONANT_PHONEME_VECTOR: [i64; 5] = [-1, 37, 35, 37, -1];
+            const START_ACCENT_VECTOR: [i64; 5] = [0, 1, 0, 0, 0];
+            const END_ACCENT_VECTOR: [i64; 5] = [0, 1, 0, 0, 0];
+            const START_ACCENT_PHRASE_VECTOR: [i64; 5] = [0, 1, 0, 0, 0];
+            const END_ACCENT_PHRASE_VECTOR: [i64; 5] = [0, 0, 0, 1, 0];
+
+            if provided[InferenceOperationImpl::PredictDuration] {
+                let phoneme_length = self.predict_duration(&PHONEME_VECTOR, style_id)?;
+                ensure_valid_output("predict_duration", &phoneme_length, PHONEME_VECTOR.len())?;
+            }
+
+            if provided[InferenceOperationImpl::PredictIntonation] {
+                let f0 = self.predict_intonation(
+                    VOWEL_PHONEME_VECTOR.len(),
+                    &VOWEL_PHONEME_VECTOR,
+                    &CONSONANT_PHONEME_VECTOR,
+                    &START_ACCENT_VECTOR,
+                    &END_ACCENT_VECTOR,
+                    &START_ACCENT_PHRASE_VECTOR,
+                    &END_ACCENT_PHRASE_VECTOR,
+                    style_id,
+                )?;
+                ensure_valid_output("predict_intonation", &f0, VOWEL_PHONEME_VECTOR.len())?;
+            }
+
+            if !provided[InferenceOperationImpl::Decode] {
+                return Ok(());
+            }
+
+            // 各音素を同じ長さだけ発声させる
+            const FRAMES_PER_PHONEME: usize = 8;
+            let phoneme_size = OjtPhoneme::num_phoneme();
+            let length = PHONEME_VECTOR.len() * FRAMES_PER_PHONEME;
+            let f0 = PHONEME_VECTOR
+                .iter()
+                .flat_map(|&phoneme| {
+                    let f0 = if phoneme == 0 { 0. } else { 5.5 };
+                    [f0; FRAMES_PER_PHONEME]
+                })
+                .collect::<Vec<_>>();
+            let phoneme = PHONEME_VECTOR
+                .iter()
+                .flat_map(|&phoneme| {
+                    let mut one_hot = vec![0.; phoneme_size];
+                    one_hot[phoneme as usize] = 1.;
+                    one_hot.repeat(FRAMES_PER_PHONEME)
+                })
+                .collect::<Vec<_>>();
+
+            let wave = self.decode(length, phoneme_size, &f0, &phoneme, style_id)?;
+            ensure_valid_output("decode", &wave, length * 256)?;
+
+            return Ok(());
+
+            fn ensure_valid_output(
+                operation: &str,
+                output: &[f32],
+                expected_len: usize,
+            ) -> anyhow::Result<()> {
+                if output.len() != expected_len {
+                    anyhow::bail!(
+                        "`{operation}`の出力の長さが不正です (expected: {expected_len}, got: {})",
+                        output.len(),
+                    );
+                }
+                if let Some(i) = output.iter().position(|x| !x.is_finite()) {
+                    anyhow::bail!(
+                        "`{operation}`の出力に有限でない値が含まれています: [{i}] = {}",
+                        output[i],
+                    );
+                }
+                Ok(())
+            }
         }
 
         /// 音声モデルの読み込みを解除する。
         pub fn unload_voice_model(&self, voice_model_id: &VoiceModelId) -> Result<()> {
-            self.status.unload_model(voice_model_id)
+            let source = self.status.model_source(voice_model_id)?;
+            self.status.unload_model(voice_model_id)?;
+            if let (Some(watcher), Some(path)) = (&self.watcher, source.path()) {
+                if let Err(err) = watcher.unwatch(path) {
+                    warn!("`{}`の監視を終了できませんでした: {err}", path.display());
+                }
+            }
+            Ok(())
         }
 
         /// 指定したIDの音声モデルが読み込まれているか判定する。
@@ -224,6 +737,64 @@ pub(crate) mod blocking {
             self.status.metas()
         }
 
+        /// 今読み込んでいる音声モデルのメタ情報のうち、`style_type`のスタイルのみを返す。
+        ///
+        /// `style_type`のスタイルを一つも持たない話者は含まれない。
+        ///
+        /// Rust APIのみの機能である。C API、Python API、Java APIでは、メタ情報の各スタイルの`type`で
+        /// 絞り込む。
+        pub fn metas_by_style_type(&self, style_type: StyleType) -> VoiceModelMeta {
+            metas::filter_by_style_type(&self.status.metas(), style_type)
+        }
+
+        /// 今読み込んでいる音声モデルのメタ情報から、話者のUUIDとスタイル名に対応するスタイルIDを
+        /// 得る。
+        ///
+        /// 詳細は[`StyleResolver::resolve_style_id`]を参照。
+        ///
+        /// [`StyleResolver::resolve_style_id`]: crate::StyleResolver::resolve_style_id
+        pub fn resolve_style_id(&self, speaker_uuid: &str, style_name: &str) -> Result<StyleId> {
+            self.metas().resolve_style_id(speaker_uuid, style_name)
+        }
+
+        /// 今読み込んでいる音声モデルのメタ情報から、スタイルIDに対応する話者のUUIDとスタイル名を
+        /// 得る。
+        ///
+        /// 詳細は[`StyleResolver::resolve_style_key`]を参照。
+        ///
+        /// [`StyleResolver::resolve_style_key`]: crate::StyleResolver::resolve_style_key
+        pub fn resolve_style_key(&self, style_id: StyleId) -> Result<StyleKey> {
+            self.metas().resolve_style_key(style_id)
+        }
+
+        /// 今読み込んでいる音声モデルのメタ情報から、スタイルをあいまいに検索する。
+        ///
+        /// 詳細は[`StyleResolver::search_styles`]を参照。
+        ///
+        /// [`StyleResolver::search_styles`]: crate::StyleResolver::search_styles
+        pub fn search_styles(&self, query: &str) -> Vec<StyleCandidate> {
+            self.metas().search_styles(query)
+        }
+
+        /// 今読み込んでいる音声モデルから、話者の追加情報を読んで返す。
+        ///
+        /// 話者のスタイルが複数の音声モデルにまたがる場合は、それらから読んだものをまとめて返す。
+        /// スタイルIDは付け替え後のものとなる。
+        pub fn speaker_info(&self, speaker_uuid: &str) -> Result<SpeakerInfo> {
+            self.status.speaker_info(speaker_uuid)
+        }
+
+        /// これまでに集めた、テキスト解析と推論の所要時間、および音声合成のリアルタイムファクタの
+        /// 統計を返す。
+        pub fn stats(&self) -> SynthesizerStats {
+            self.stats.snapshot()
+        }
+
+        /// 集めた統計を破棄する。
+        pub fn reset_stats(&self) {
+            self.stats.reset();
+        }
+
         /// AudioQueryから音声合成を行う。
         pub fn synthesis(
             &self,
@@ -231,6 +802,14 @@ pub(crate) mod blocking {
             style_id: StyleId,
             options: &SynthesisOptions,
         ) -> Result<Vec<u8>> {
+            let span = tracing::info_span!(
+                "voicevox_core::synthesis",
+                style_id = style_id.raw_id(),
+                real_time_factor = tracing::field::Empty,
+            );
+            let _enter = span.enter();
+            let start = Instant::now();
+
             let speed_scale = *audio_query.speed_scale();
             let pitch_scale = *audio_query.pitch_scale();
             let intonation_scale = *audio_query.intonation_scale();
@@ -326,6 +905,9 @@ pub(crate) mod blocking {
             // 2次元のvectorを1次元に変換し、アドレスを連続させる
             let flatten_phoneme = phoneme.into_iter().flatten().collect::<Vec<_>>();
 
+            // 推論の後に音声モデルの読み込みが解除されても合成自体は成功させるため、RTFの記録に使う
+            // 音声モデルIDは推論の前に得ておく
+            let (model_id, _) = self.status.ids_for(style_id)?;
             let wave = &self.decode(
                 f0.len(),
                 OjtPhoneme::num_phoneme(),
@@ -333,7 +915,18 @@ pub(crate) mod blocking {
                 &flatten_phoneme,
                 style_id,
             )?;
-            return Ok(to_wav(wave, audio_query));
+            let wav = to_wav(wave, audio_query);
+
+            if !wave.is_empty() {
+                let rtf = self.stats.record_synthesis(
+                    &model_id,
+                    style_id,
+                    start.elapsed(),
+                    Duration::from_secs_f64(wave.len() as f64 / f64::from(DEFAULT_SAMPLING_RATE)),
+                );
+                span.record("real_time_factor", rtf);
+            }
+            return Ok(wav);
 
             fn adjust_interrogative_accent_phrases(
                 accent_phrases: &[AccentPhraseModel],
@@ -459,7 +1052,9 @@ pub(crate) mod blocking {
             kana: &str,
             style_id: StyleId,
         ) -> Result<Vec<AccentPhraseModel>> {
-            let accent_phrases = self.kana_analyzer.analyze(kana)?;
+            let accent_phrases = self.stats.measure(StatsOperation::TextAnalysis, None, || {
+                self.kana_analyzer.analyze(kana)
+            })?;
             self.replace_mora_data(&accent_phrases, style_id)
         }
 
@@ -717,6 +1312,55 @@ pub(crate) mod blocking {
         }
     }
 
+    fn can_support_gpu_feature() -> Result<bool> {
+        let supported_devices = SupportedDevices::create()?;
+
+        if cfg!(feature = "directml") {
+            Ok(*supported_devices.dml())
+        } else {
+            Ok(*supported_devices.cuda())
+        }
+    }
+
+    fn reload_voice_model(
+        status: &Status<InferenceRuntimeImpl, InferenceDomainImpl>,
+        lazy_session_creation: bool,
+        voice_model_id: &VoiceModelId,
+    ) -> Result<()> {
+        let model = crate::blocking::VoiceModel::from_source(status.model_source(voice_model_id)?)?;
+        let model_bytes = if lazy_session_creation {
+            None
+        } else {
+            Some(model.read_inference_models()?)
+        };
+        status.replace_model(
+            voice_model_id,
+            model.header(),
+            model_bytes.as_ref(),
+            model.header().model_reader(),
+        )
+    }
+
+    /// `changed`のVVMファイルから読み込まれた音声モデルを、すべて読み込み直す。
+    fn reload_changed_voice_models(
+        status: &Status<InferenceRuntimeImpl, InferenceDomainImpl>,
+        lazy_session_creation: bool,
+        changed: &Path,
+    ) {
+        for (voice_model_id, path) in status.model_paths() {
+            let Ok(path) = voice_model_watcher::normalize(&path) else {
+                continue;
+            };
+            if !voice_model_watcher::is_change_of(&path, changed) {
+                continue;
+            }
+            match reload_voice_model(status, lazy_session_creation, &voice_model_id) {
+                Ok(()) => info!("音声モデル`{voice_model_id}`を読み込み直しました"),
+                Err(err) => error!("音声モデル`{voice_model_id}`を読み込み直せませんでした: {err}"),
+            }
+        }
+    }
+
     impl<O: FullcontextExtractor> self::Synthesizer<O> {
         /// 日本語のテキストからAccentPhrase (アクセント句)の配列を生成する。
         ///
@@ -746,7 +1390,9 @@ pub(crate) mod blocking {
             text: &str,
             style_id: StyleId,
         ) -> Result<Vec<AccentPhraseModel>> {
-            let accent_phrases = self.open_jtalk_analyzer.analyze(text)?;
+            let accent_phrases = self.stats.measure(StatsOperation::TextAnalysis, None, || {
+                self.open_jtalk_analyzer.analyze(text)
+            })?;
             self.replace_mora_data(&accent_phrases, style_id)
         }
 
@@ -815,6 +1461,11 @@ pub(crate) mod blocking {
 
         /// `decode`を実行する。
         ///
+        /// `phoneme_size`が0の場合や、`f0`と`phoneme_vector`の長さが`length`と合わない場合は
+        /// [`ErrorKind::InferenceFailed`]を表わすエラーとなる。
+        ///
+        /// [`ErrorKind::InferenceFailed`]: crate::ErrorKind::InferenceFailed
+        ///
         /// # Performance
         ///
         /// CPU/GPU-boundな操作であるため、非同期ランタイム上では直接実行されるべきではない。
@@ -830,20 +1481,23 @@ pub(crate) mod blocking {
 
     impl<O> PerformInference for self::Synthesizer<O> {
         fn predict_duration(&self, phoneme_vector: &[i64], style_id: StyleId) -> Result<Vec<f32>> {
-            // FIXME: `Status::ids_for`があるため、ここは不要なはず
-            if !self.status.validate_speaker_id(style_id) {
-                return Err(ErrorRepr::StyleNotFound { style_id }.into());
-            }
-
-            let (model_id, model_inner_id) = self.status.ids_for(style_id)?;
+            let (model_id, model_inner_id) = self
+                .status
+                .ids_for_operation(style_id, InferenceOperationImpl::PredictDuration)?;
 
             let PredictDurationOutput {
                 phoneme_length: output,
-            } = self.status.run_session(
-                &model_id,
-                PredictDurationInput {
-                    phoneme_list: ndarray::arr1(phoneme_vector),
-                    speaker_id: ndarray::arr1(&[model_inner_id.raw_id().into()]),
+            } = self.stats.measure(
+                StatsOperation::PredictDuration,
+                Some((&model_id, style_id)),
+                || {
+                    self.status.run_session(
+                        &model_id,
+                        PredictDurationInput {
+                            phoneme_list: ndarray::arr1(phoneme_vector),
+                            speaker_id: ndarray::arr1(&[model_inner_id.raw_id().into()]),
+                        },
+                    )
                 },
             )?;
             let mut output = output.into_raw_vec();
@@ -870,24 +1524,27 @@ pub(crate) mod blocking {
             end_accent_phrase_vector: &[i64],
             style_id: StyleId,
         ) -> Result<Vec<f32>> {
-            // FIXME: `Status::ids_for`があるため、ここは不要なはず
-            if !self.status.validate_speaker_id(style_id) {
-                return Err(ErrorRepr::StyleNotFound { style_id }.into());
-            }
-
-            let (model_id, model_inner_id) = self.status.ids_for(style_id)?;
-
-            let PredictIntonationOutput { f0_list: output } = self.status.run_session(
-                &model_id,
-                PredictIntonationInput {
-                    length: ndarray::arr0(length as i64),
-                    vowel_phoneme_list: ndarray::arr1(vowel_phoneme_vector),
-                    consonant_phoneme_list: ndarray::arr1(consonant_phoneme_vector),
-                    start_accent_list: ndarray::arr1(start_accent_vector),
-                    end_accent_list: ndarray::arr1(end_accent_vector),
-                    start_accent_phrase_list: ndarray::arr1(start_accent_phrase_vector),
-                    end_accent_phrase_list: ndarray::arr1(end_accent_phrase_vector),
-                    speaker_id: ndarray::arr1(&[model_inner_id.raw_id().into()]),
+            let (model_id, model_inner_id) = self
+                .status
+                .ids_for_operation(style_id, InferenceOperationImpl::PredictIntonation)?;
+
+            let PredictIntonationOutput { f0_list: output } = self.stats.measure(
+                StatsOperation::PredictIntonation,
+                Some((&model_id, style_id)),
+                || {
+                    self.status.run_session(
+                        &model_id,
+                        PredictIntonationInput {
+                            length: ndarray::arr0(length as i64),
+                            vowel_phoneme_list: ndarray::arr1(vowel_phoneme_vector),
+                            consonant_phoneme_list: ndarray::arr1(consonant_phoneme_vector),
+                            start_accent_list: ndarray::arr1(start_accent_vector),
+                            end_accent_list: ndarray::arr1(end_accent_vector),
+                            start_accent_phrase_list: ndarray::arr1(start_accent_phrase_vector),
+                            end_accent_phrase_list: ndarray::arr1(end_accent_phrase_vector),
+                            speaker_id: ndarray::arr1(&[model_inner_id.raw_id().into()]),
+                        },
+                    )
                 },
             )?;
 
@@ -902,12 +1559,11 @@ pub(crate) mod blocking {
             phoneme_vector: &[f32],
             style_id: StyleId,
         ) -> Result<Vec<f32>> {
-            // FIXME: `Status::ids_for`があるため、ここは不要なはず
-            if !self.status.validate_speaker_id(style_id) {
-                return Err(ErrorRepr::StyleNotFound { style_id }.into());
-            }
+            ensure_decode_input_lengths(length, phoneme_size, f0, phoneme_vector)?;
 
-            let (model_id, model_inner_id) = self.status.ids_for(style_id)?;
+            let (model_id, model_inner_id) = self
+                .status
+                .ids_for_operation(style_id, InferenceOperationImpl::Decode)?;
 
             // 音が途切れてしまうのを避けるworkaround処理が入っている
             // TODO: 改善したらここのpadding処理を取り除く
@@ -925,24 +1581,53 @@ pub(crate) mod blocking {
                 padding_size,
             );
 
-            let DecodeOutput { wave: output } = self.status.run_session(
-                &model_id,
-                DecodeInput {
-                    f0: ndarray::arr1(&f0_with_padding)
-                        .into_shape([length_with_padding, 1])
-                        .unwrap(),
-                    phoneme: ndarray::arr1(&phoneme_with_padding)
-                        .into_shape([length_with_padding, phoneme_size])
-                        .unwrap(),
-                    speaker_id: ndarray::arr1(&[model_inner_id.raw_id().into()]),
-                },
-            )?;
+            let DecodeOutput { wave: output } =
+                self.stats
+                    .measure(StatsOperation::Decode, Some((&model_id, style_id)), || {
+                        self.status.run_session(
+                            &model_id,
+                            DecodeInput {
+                                f0: ndarray::arr1(&f0_with_padding)
+                                    .into_shape([length_with_padding, 1])
+                                    .unwrap(),
+                                phoneme: ndarray::arr1(&phoneme_with_padding)
+                                    .into_shape([length_with_padding, phoneme_size])
+                                    .unwrap(),
+                                speaker_id: ndarray::arr1(&[model_inner_id.raw_id().into()]),
+                            },
+                        )
+                    })?;
 
             return Ok(trim_padding_from_output(
                 output.into_raw_vec(),
                 padding_size,
             ));
 
+            /// `decode`の入力の長さが`length`と`phoneme_size`に合っているかを確かめる。
+            fn ensure_decode_input_lengths(
+                length: usize,
+                phoneme_size: usize,
+                f0: &[f32],
+                phoneme_vector: &[f32],
+            ) -> Result<()> {
+                (|| {
+                    anyhow::ensure!(phoneme_size > 0, "`phoneme_size`が0です");
+                    anyhow::ensure!(
+                        f0.len() == length,
+                        "`f0`の長さが`length`と異なります (length: {length}, f0: {})",
+                        f0.len(),
+                    );
+                    anyhow::ensure!(
+                        length.checked_mul(phoneme_size) == Some(phoneme_vector.len()),
+                        "`phoneme_vector`の長さが`length * phoneme_size`と異なります (length: \
+                         {length}, phoneme_size: {phoneme_size}, phoneme_vector: {})",
+                        phoneme_vector.len(),
+                    );
+                    Ok(())
+                })()
+                .map_err(|e| ErrorRepr::InferenceFailed(e).into())
+            }
+
             fn make_f0_with_padding(
                 f0_slice: &[f32],
                 length_with_padding: usize,
@@ -995,7 +1680,7 @@ pub(crate) mod blocking {
     }
 
     #[cfg(windows)]
-    fn list_windows_video_cards() {
+    fn list_windows_video_cards(gpu_device_index: u16) -> Option<usize> {
         use std::{ffi::OsString, os::windows::ffi::OsStringExt as _};
 
         use humansize::BINARY;
@@ -1004,17 +1689,24 @@ pub(crate) mod blocking {
             CreateDXGIFactory, IDXGIFactory, DXGI_ADAPTER_DESC, DXGI_ERROR_NOT_FOUND,
         };
 
-        info!("検出されたGPU (DirectMLには1番目のGPUが使われます):");
-        match list_windows_video_cards() {
+        info!(
+            "検出されたGPU (DirectMLには{}番目のGPUが使われます):",
+            u32::from(gpu_device_index) + 1,
+        );
+        return match list_windows_video_cards() {
             Ok(descs) => {
-                for desc in descs {
+                for desc in &descs {
                     let description = OsString::from_wide(trim_nul(&desc.Description));
                     let vram = humansize::format_size(desc.DedicatedVideoMemory, BINARY);
                     info!("  - {description:?} ({vram})");
                 }
+                Some(descs.len())
             }
-            Err(err) => error!("{err}"),
-        }
+            Err(err) => {
+                error!("{err}");
+                None
+            }
+        };
 
         fn list_windows_video_cards() -> windows::core::Result<Vec<DXGI_ADAPTER_DESC>> {
             unsafe {
@@ -1132,11 +1824,14 @@ pub(crate) mod tokio {
     use std::sync::Arc;
 
     use crate::{
-        AccentPhraseModel, AudioQueryModel, FullcontextExtractor, Result, StyleId,
-        SynthesisOptions, VoiceModelId, VoiceModelMeta,
+        AccentPhraseModel, AudioQueryModel, FullcontextExtractor, Result, SpeakerInfo,
+        StyleCandidate, StyleId, StyleKey, StyleType, SynthesisOptions, SynthesizerStats,
+        VoiceModelId, VoiceModelMeta,
     };
 
-    use super::{InitializeOptions, TtsOptions};
+    use super::{
+        blocking::PerformInference as _, InitializeOptions, LoadVoiceModelOptions, TtsOptions,
+    };
 
     /// 音声シンセサイザ。
     #[derive(Clone)]
@@ -1155,8 +1850,69 @@ pub(crate) mod tokio {
         }
 
         pub async fn load_voice_model(&self, model: &crate::tokio::VoiceModel) -> Result<()> {
-            let model_bytes = &model.read_inference_models().await?;
-            self.0.status.insert_model(model.header(), model_bytes)
+            self.load_voice_model_with_options(model, &Default::default())
+                .await
+        }
+
+        pub async fn load_voice_model_with_options(
+            &self,
+            model: &crate::tokio::VoiceModel,
+            options: &LoadVoiceModelOptions,
+        ) -> Result<()> {
+            let session_options = &self.0.model_session_options(options)?;
+            if self.0.lazy_session_creation {
+                self.0.status.insert_model_lazily(
+                    model.header(),
+                    model.header().model_reader(),
+                    session_options,
+                    &options.style_id_remapping,
+                )?;
+            } else {
+                let model_bytes = &model.read_inference_models().await?;
+                self.0.status.insert_model(
+                    model.header(),
+                    model_bytes,
+                    model.header().model_reader(),
+                    session_options,
+                    &options.style_id_remapping,
+                )?;
+            }
+            if self.0.warm_up_on_load {
+                let blocking = self.0.clone();
+                let voice_model_id = model.id().clone();
+
+                crate::task::asyncify(move || blocking.warm_up_or_unload(&voice_model_id)).await?;
+            }
+            self.0.watch_voice_model_file(&model.header().source);
+            Ok(())
+        }
+
+        pub async fn load_voice_model_by_style_id(
+            &self,
+            model_set: &crate::tokio::VoiceModelSet,
+            style_id: StyleId,
+        ) -> Result<()> {
+            if self.is_loaded_model_by_style_id(style_id) {
+                return Ok(());
+            }
+            let result = self
+                .load_voice_model(model_set.voice_model_by_style_id(style_id)?)
+                .await;
+            super::ok_if_loaded_concurrently(result, || self.is_loaded_model_by_style_id(style_id))
+        }
+
+        pub async fn warm_up(&self, voice_model_id: &VoiceModelId) -> Result<()> {
+            let blocking = self.0.clone();
+            let voice_model_id = voice_model_id.clone();
+
+            crate::task::asyncify(move || blocking.warm_up(&voice_model_id)).await
+        }
+
+        pub async fn reload_voice_model(&self, voice_model_id: &VoiceModelId) -> Result<()> {
+            let blocking = self.0.clone();
+            let voice_model_id = voice_model_id.clone();
+
+            crate::task::asyncify(move || blocking.reload_voice_model(&voice_model_id)).await
         }
 
         pub fn unload_voice_model(&self, voice_model_id: &VoiceModelId) -> Result<()> {
@@ -1176,6 +1932,37 @@ pub(crate) mod tokio {
             self.0.metas()
         }
 
+        pub fn metas_by_style_type(&self, style_type: StyleType) -> VoiceModelMeta {
+            self.0.metas_by_style_type(style_type)
+        }
+
+        pub fn resolve_style_id(&self, speaker_uuid: &str, style_name: &str) -> Result<StyleId> {
+            self.0.resolve_style_id(speaker_uuid, style_name)
+        }
+
+        pub fn resolve_style_key(&self, style_id: StyleId) -> Result<StyleKey> {
+            self.0.resolve_style_key(style_id)
+        }
+
+        pub fn search_styles(&self, query: &str) -> Vec<StyleCandidate> {
+            self.0.search_styles(query)
+        }
+
+        pub async fn speaker_info(&self, speaker_uuid: &str) -> Result<SpeakerInfo> {
+            let blocking = self.0.clone();
+            let speaker_uuid = speaker_uuid.to_owned();
+
+            crate::task::asyncify(move || blocking.speaker_info(&speaker_uuid)).await
+        }
+
+        pub fn stats(&self) -> SynthesizerStats {
+            self.0.stats()
+        }
+
+        pub fn reset_stats(&self) {
+            self.0.reset_stats()
+        }
+
         pub async fn synthesis(
             &self,
             audio_query: &AudioQueryModel,
@@ -1297,15 +2084,106 @@ pub(crate) mod tokio {
             crate::task::asyncify(move || blocking.tts(&text, style_id, &options)).await
         }
     }
+
+    /// 推論操作を直接実行する、低レベルなAPI。
+    ///
+    /// いずれもブロッキング用のスレッドプール上で実行される。入出力は
+    /// [`blocking::PerformInference`]と同じ。
+    ///
+    /// [`blocking::PerformInference`]: crate::blocking::PerformInference
+    impl<O: Send + Sync + 'static> self::Synthesizer<O> {
+        /// `predict_duration`を実行する。
+        ///
+        /// 音素IDの列`phoneme_vector`から、それぞれの音素の長さ(秒)を推論する。
+        pub async fn predict_duration(
+            &self,
+            phoneme_vector: &[i64],
+            style_id: StyleId,
+        ) -> Result<Vec<f32>> {
+            let blocking = self.0.clone();
+            let phoneme_vector = phoneme_vector.to_owned();
+
+            crate::task::asyncify(move || blocking.predict_duration(&phoneme_vector, style_id))
+                .await
+        }
+
+        /// `predict_intonation`を実行する。
+        ///
+        /// 長さ`length`のモーラ列から、それぞれのモーラの音高を推論する。無声化したモーラや無音の
+        /// 音高は0となる。
+        #[allow(clippy::too_many_arguments)]
+        pub async fn predict_intonation(
+            &self,
+            length: usize,
+            vowel_phoneme_vector: &[i64],
+            consonant_phoneme_vector: &[i64],
+            start_accent_vector: &[i64],
+            end_accent_vector: &[i64],
+            start_accent_phrase_vector: &[i64],
+            end_accent_phrase_vector: &[i64],
+            style_id: StyleId,
+        ) -> Result<Vec<f32>> {
+            let blocking = self.0.clone();
+            let vowel_phoneme_vector = vowel_phoneme_vector.to_owned();
+            let consonant_phoneme_vector = consonant_phoneme_vector.to_owned();
+            let start_accent_vector = start_accent_vector.to_owned();
+            let end_accent_vector = end_accent_vector.to_owned();
+            let start_accent_phrase_vector = start_accent_phrase_vector.to_owned();
+            let end_accent_phrase_vector = end_accent_phrase_vector.to_owned();
+
+            crate::task::asyncify(move || {
+                blocking.predict_intonation(
+                    length,
+                    &vowel_phoneme_vector,
+                    &consonant_phoneme_vector,
+                    &start_accent_vector,
+                    &end_accent_vector,
+                    &start_accent_phrase_vector,
+                    &end_accent_phrase_vector,
+                    style_id,
+                )
+            })
+            .await
+        }
+
+        /// `decode`を実行する。
+        ///
+        /// `length`フレーム分の音高`f0`と、フレームごとの音素のone-hot表現を並べた
+        /// `phoneme_vector`(長さは`length * phoneme_size`)から、波形を推論する。
+        ///
+        /// `phoneme_size`が0の場合や、`f0`と`phoneme_vector`の長さが`length`と合わない場合は
+        /// [`ErrorKind::InferenceFailed`]を表わすエラーとなる。
+        ///
+        /// [`ErrorKind::InferenceFailed`]: crate::ErrorKind::InferenceFailed
+        pub async fn decode(
+            &self,
+            length: usize,
+            phoneme_size: usize,
+            f0: &[f32],
+            phoneme_vector: &[f32],
+            style_id: StyleId,
+        ) -> Result<Vec<f32>> {
+            let blocking = self.0.clone();
+            let f0 = f0.to_owned();
+            let phoneme_vector = phoneme_vector.to_owned();
+
+            crate::task::asyncify(move || {
+                blocking.decode(length, phoneme_size, &f0, &phoneme_vector, style_id)
+            })
+            .await
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
-    use super::{blocking::PerformInference as _, AccelerationMode, InitializeOptions};
+    use super::{
+        blocking::PerformInference as _, AccelerationMode, InitializeOptions, LoadVoiceModelOptions,
+    };
     use crate::{
         engine::MoraModel, macros::tests::assert_debug_fmt_eq, test_util::open_default_vvm_file,
-        AccentPhraseModel, Result, StyleId,
+        AccentPhraseModel, ErrorKind, Result, StyleId,
     };
     use ::test_util::OPEN_JTALK_DIC_DIR;
     use rstest::rstest;
@@ -1378,6 +2256,93 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[tokio::test]
+    async fn warm_up_works() {
+        let syntesizer = super::tokio::Synthesizer::new(
+            (),
+            &InitializeOptions {
+                acceleration_mode: AccelerationMode::Cpu,
+                warm_up_on_load: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let model = &open_default_vvm_file().await;
+        syntesizer.load_voice_model(model).await.unwrap();
+        assert!(syntesizer.is_loaded_voice_model(model.id()));
+
+        let result = syntesizer.warm_up(model.id()).await;
+        assert!(result.is_ok(), "{result:?}");
+
+        syntesizer.unload_voice_model(model.id()).unwrap();
+        let result = syntesizer.warm_up(model.id()).await;
+        assert_eq!(ErrorKind::ModelNotFound, result.unwrap_err().kind());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn load_voice_model_with_options_works() {
+        let syntesizer = super::tokio::Synthesizer::new(
+            (),
+            &InitializeOptions {
+                acceleration_mode: AccelerationMode::Cpu,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let model = &open_default_vvm_file().await;
+        let result = syntesizer
+            .load_voice_model_with_options(
+                model,
+                &LoadVoiceModelOptions {
+                    use_gpu_for_decode: Some(false),
+                    cpu_num_threads: Some(1),
+                    share_light_sessions: true,
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(result.is_ok(), "{result:?}");
+
+        let result = syntesizer
+            .tts_from_kana("テ'スト", StyleId::new(1), &Default::default())
+            .await;
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn reload_voice_model_works() {
+        let syntesizer = super::tokio::Synthesizer::new(
+            (),
+            &InitializeOptions {
+                acceleration_mode: AccelerationMode::Cpu,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let model = &open_default_vvm_file().await;
+        syntesizer.load_voice_model(model).await.unwrap();
+
+        let result = syntesizer.reload_voice_model(model.id()).await;
+        assert!(result.is_ok(), "{result:?}");
+        assert!(syntesizer.is_loaded_voice_model(model.id()));
+        assert_eq!(
+            model.id(),
+            open_default_vvm_file().await.id(),
+            "the same VVM should have the same ID",
+        );
+
+        let result = syntesizer
+            .tts_from_kana("テ'スト", StyleId::new(1), &Default::default())
+            .await;
+        assert!(result.is_ok(), "{result:?}");
+    }
+
     #[rstest]
     #[tokio::test]
     async fn predict_duration_works() {
@@ -1409,6 +2374,34 @@ mod tests {
         assert_eq!(result.unwrap().len(), phoneme_vector.len());
     }
 
+    #[rstest]
+    #[tokio::test]
+    async fn predict_duration_works_asynchronously() {
+        let syntesizer = super::tokio::Synthesizer::new(
+            (),
+            &InitializeOptions {
+                acceleration_mode: AccelerationMode::Cpu,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        syntesizer
+            .load_voice_model(&open_default_vvm_file().await)
+            .await
+            .unwrap();
+
+        // 「テスト」という文章に対応する phoneme_vector
+        let phoneme_vector = [0, 37, 14, 35, 6, 37, 30, 0];
+
+        let result = syntesizer
+            .predict_duration(&phoneme_vector, StyleId::new(1))
+            .await;
+
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(result.unwrap().len(), phoneme_vector.len());
+    }
+
     #[rstest]
     #[tokio::test]
     async fn predict_intonation_works() {
@@ -1494,6 +2487,44 @@ mod tests {
         assert_eq!(result.unwrap().len(), F0_LENGTH * 256);
     }
 
+    #[rstest]
+    #[case(69, 45, 69, 45 * 69)]
+    #[case(69, 45, 68, 45 * 69)]
+    #[case(69, 45, 69, 45 * 69 - 1)]
+    #[case(69, 0, 69, 0)]
+    fn decode_checks_input_lengths(
+        #[case] length: usize,
+        #[case] phoneme_size: usize,
+        #[case] f0_len: usize,
+        #[case] phoneme_len: usize,
+    ) {
+        let syntesizer = super::blocking::Synthesizer::new(
+            (),
+            &InitializeOptions {
+                acceleration_mode: AccelerationMode::Cpu,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let result = syntesizer.decode(
+            length,
+            phoneme_size,
+            &vec![0.; f0_len],
+            &vec![0.; phoneme_len],
+            StyleId::new(1),
+        );
+
+        // 長さが正しい場合は、音声モデルが読み込まれていないためにスタイルが見つからない
+        let expected =
+            if (f0_len, phoneme_len) == (length, length * phoneme_size) && phoneme_size > 0 {
+                ErrorKind::StyleNotFound
+            } else {
+                ErrorKind::InferenceFailed
+            };
+        assert_eq!(expected, result.unwrap_err().kind());
+    }
+
     type TextConsonantVowelData =
         [(&'static [(&'static str, &'static str, &'static str)], usize)];
 
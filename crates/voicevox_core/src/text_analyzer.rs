@@ -1,5 +1,8 @@
+use std::sync::Arc;
+
 use crate::{
-    engine::{extract_full_context_label, parse_kana},
+    engine::{extract_full_context_label, open_jtalk_spec_of, parse_kana},
+    worker::{OutOfProcessOpenJtalk, Worker},
     AccentPhraseModel, FullcontextExtractor, Result,
 };
 
@@ -22,11 +25,16 @@ impl TextAnalyzer for KanaAnalyzer {
 
 /// OpenJtalkからAccentPhraseの配列を生成するTextAnalyzer
 #[derive(Clone)]
-pub(crate) struct OpenJTalkAnalyzer<O>(O);
+pub(crate) struct OpenJTalkAnalyzer<O> {
+    open_jtalk: O,
+    /// 指定されている場合、`open_jtalk`がこのクレートのOpen JTalkであればワーカープロセスの中で
+    /// テキスト解析を行う。
+    worker: Option<Arc<Worker>>,
+}
 
 impl<O> OpenJTalkAnalyzer<O> {
-    pub(crate) fn new(open_jtalk: O) -> Self {
-        Self(open_jtalk)
+    pub(crate) fn new(open_jtalk: O, worker: Option<Arc<Worker>>) -> Self {
+        Self { open_jtalk, worker }
     }
 }
 
@@ -35,6 +43,15 @@ impl<O: FullcontextExtractor> TextAnalyzer for OpenJTalkAnalyzer<O> {
         if text.is_empty() {
             return Ok(Vec::new());
         }
-        Ok(extract_full_context_label(&self.0, text)?)
+        let out_of_process = self.worker.as_ref().and_then(|worker| {
+            Some(OutOfProcessOpenJtalk {
+                worker: worker.clone(),
+                spec: open_jtalk_spec_of(&self.open_jtalk)?,
+            })
+        });
+        match out_of_process {
+            Some(open_jtalk) => Ok(extract_full_context_label(&open_jtalk, text)?),
+            None => Ok(extract_full_context_label(&self.open_jtalk, text)?),
+        }
     }
 }
@@ -3,4 +3,5 @@
 pub use crate::{
     engine::open_jtalk::tokio::OpenJtalk, synthesizer::tokio::Synthesizer,
     user_dict::dict::tokio::UserDict, voice_model::tokio::VoiceModel,
+    voice_model_set::tokio::VoiceModelSet,
 };
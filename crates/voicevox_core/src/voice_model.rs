@@ -1,16 +1,27 @@
 //! 音声モデル（ VVM ファイル）。
 //!
-//! VVM ファイルの定義と形式は[ドキュメント](../../../docs/vvm.md)を参照。
+//! VVM ファイルの定義と形式は[ドキュメント](https://github.com/VOICEVOX/voicevox_core/blob/main/docs/vvm.md)を参照。
 
 use derive_getters::Getters;
 use derive_new::new;
 use serde::Deserialize;
+use sha2::{Digest as _, Sha256};
 
 use crate::{
-    manifest::{Manifest, ModelInnerId},
+    error::{LoadModelError, LoadModelErrorKind, LoadModelResult},
+    infer::domain::InferenceOperationImpl,
+    manifest::{Manifest, ManifestDomainsOnly, ManifestVersion, ModelInnerId, TalkManifest},
+    vvm_signature::SignatureStatus,
     SpeakerMeta, StyleId, StyleMeta, VoiceModelMeta,
 };
-use std::{collections::BTreeMap, path::PathBuf};
+use std::{
+    collections::BTreeMap,
+    fs::Metadata,
+    io,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+    time::SystemTime,
+};
 
 /// [`VoiceModelId`]の実体。
 ///
@@ -35,7 +46,133 @@ pub struct VoiceModelId {
     raw_voice_model_id: RawVoiceModelId,
 }
 
-// FIXME: "header"といいつつ、VVMのファイルパスを持っている状態になっている。
+impl VoiceModelId {
+    /// VVMファイルの音声モデルIDを決める。
+    ///
+    /// マニフェストにIDがあればそれを、無ければメタ情報のファイルのSHA-256ハッシュ値を用いる。
+    /// そのため同じVVMファイルからは常に同じIDが得られる。
+    ///
+    /// 後者ではメタ情報が同じでモデルが異なるVVMどうしのIDが衝突するが、既存のVVMのIDを変えない
+    /// ためにそのままとしている。`pack`で作るVVMには常にIDが書かれる。
+    fn for_vvm(manifest: &Manifest, metas_json: &[u8]) -> Self {
+        let raw_voice_model_id = manifest.id().clone().unwrap_or_else(|| {
+            Sha256::digest(metas_json)
+                .iter()
+                .map(|b| format!("{b:02x}"))
+                .collect()
+        });
+        Self::new(raw_voice_model_id)
+    }
+}
+
+/// マニフェストのバージョンが読めるものかを確かめる。
+fn ensure_supported_manifest_version(path: &Path, version: ManifestVersion) -> LoadModelResult<()> {
+    if !version.is_supported() {
+        return Err(LoadModelError {
+            path: path.to_owned(),
+            context: LoadModelErrorKind::UnsupportedManifestVersion { version },
+            source: None,
+        });
+    }
+    Ok(())
+}
+
+/// マニフェストの推論ドメインの構成が読めるものかを確かめる。
+fn ensure_supported_domains(path: &Path, domains: &ManifestDomainsOnly) -> LoadModelResult<()> {
+    domains.ensure_supported().map_err(|source| LoadModelError {
+        path: path.to_owned(),
+        context: LoadModelErrorKind::InvalidModelData,
+        source: Some(source),
+    })
+}
+
+/// 署名の検証に失敗したことを表すエラー。
+fn untrusted_voice_model_error(path: PathBuf, source: anyhow::Error) -> LoadModelError {
+    LoadModelError {
+        path,
+        context: LoadModelErrorKind::UntrustedVoiceModel,
+        source: Some(source),
+    }
+}
+
+/// メモリ上のVVMの在り処を、エラーメッセージで表すためのパス。
+const IN_MEMORY_VVM_PATH: &str = "<memory>";
+
+/// VVMの実体の在り処。
+#[derive(Clone)]
+pub(crate) enum VvmSource {
+    /// ファイルシステム上のVVMファイルか、VVMを展開したディレクトリ。
+    Path(PathBuf),
+    /// メモリ上に置かれたVVM。
+    Bytes(Arc<[u8]>),
+}
+
+impl VvmSource {
+    /// VVMファイルのパス。メモリ上のVVMの場合は`None`。
+    pub(crate) fn path(&self) -> Option<&Path> {
+        match self {
+            Self::Path(path) => Some(path),
+            Self::Bytes(_) => None,
+        }
+    }
+
+    /// エラーメッセージに使われる、VVMの在り処を表すパス。メモリ上のVVMの場合は`<memory>`となる。
+    pub(crate) fn display_path(&self) -> PathBuf {
+        self.path()
+            .unwrap_or(Path::new(IN_MEMORY_VVM_PATH))
+            .to_owned()
+    }
+}
+
+/// VVMを展開したディレクトリの中での、エントリのパス。
+///
+/// マニフェストに書かれたファイル名が、ディレクトリの外を指すことは許さない。
+pub(crate) fn unpacked_entry_path(dir: &Path, filename: &str) -> io::Result<PathBuf> {
+    let filename = Path::new(filename);
+    if !filename
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("`{}`はディレクトリの外を指しています", filename.display()),
+        ));
+    }
+    Ok(dir.join(filename))
+}
+
+/// VVMの実体の、読み込んだ時点でのファイルの大きさと更新日時。
+///
+/// `Session`を作り直すときなどにVVMを読み直す前に、VVMが変更されていないかをこれで確かめる。VVMを
+/// 展開したディレクトリの場合は、マニフェストと各モデルのファイルが対象となる(存在しないファイルは
+/// `None`)。メモリ上のVVMの場合は空となる。
+#[derive(Clone, PartialEq, Debug, Default)]
+pub(crate) struct VvmFingerprint(Vec<Option<(u64, Option<SystemTime>)>>);
+
+impl VvmFingerprint {
+    fn from_metadata(metadata: &[Option<Metadata>]) -> Self {
+        Self(
+            metadata
+                .iter()
+                .map(|metadata| {
+                    let metadata = metadata.as_ref()?;
+                    Some((metadata.len(), metadata.modified().ok()))
+                })
+                .collect(),
+        )
+    }
+}
+
+/// ファイルのメタデータを得る。ファイルが存在しない場合は`None`。
+fn metadata_if_exists(result: io::Result<Metadata>) -> io::Result<Option<Metadata>> {
+    match result {
+        Ok(metadata) => Ok(Some(metadata)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+// FIXME: "header"といいつつ、VVMの実体の在り処を持っている状態になっている。
 /// 音声モデルが持つ、各モデルファイルの実体を除く情報。
 ///
 /// モデルの`[u8]`と分けて`Status`に渡す。
@@ -43,10 +180,15 @@ pub struct VoiceModelId {
 pub(crate) struct VoiceModelHeader {
     /// ID。
     pub(crate) id: VoiceModelId,
-    manifest: Manifest,
+    pub(crate) manifest: Manifest,
     /// メタ情報。
     pub(crate) metas: VoiceModelMeta,
-    pub(crate) path: PathBuf,
+    /// VVMの実体の在り処。`Session`を作り直すときなどに、ここからモデルを読み直す。
+    pub(crate) source: VvmSource,
+    /// 署名。VVMから読み直したエントリは、これと照合される。
+    pub(crate) signature: SignatureStatus,
+    /// 読み込んだ時点でのVVMの状態。
+    pub(crate) fingerprint: VvmFingerprint,
 }
 
 impl VoiceModelHeader {
@@ -54,43 +196,210 @@ impl VoiceModelHeader {
     ///
     /// モデル内IDのマッピングが存在しない場合はそのままスタイルIDを返す。
     pub(crate) fn model_inner_ids(&self) -> BTreeMap<StyleId, ModelInnerId> {
+        let style_id_to_model_inner_id = self
+            .manifest
+            .domains()
+            .talk()
+            .as_ref()
+            .map(TalkManifest::style_id_to_model_inner_id);
+
         self.metas
             .iter()
             .flat_map(SpeakerMeta::styles)
             .map(StyleMeta::id)
             .map(|&style_id| {
-                let model_inner_id = self
-                    .manifest
-                    .style_id_to_model_inner_id()
-                    .get(&style_id)
+                let model_inner_id = style_id_to_model_inner_id
+                    .and_then(|style_id_to_model_inner_id| {
+                        style_id_to_model_inner_id.get(&style_id)
+                    })
                     .copied()
                     .unwrap_or_else(|| ModelInnerId::new(style_id.raw_id()));
                 (style_id, model_inner_id)
             })
             .collect()
     }
+
+    /// ある推論操作のモデルの、VVMファイル内でのファイル名。VVMがその推論操作を提供していない場合
+    /// は`None`。
+    pub(crate) fn inference_model_filename(&self, op: InferenceOperationImpl) -> Option<&str> {
+        let talk = self.manifest.domains().talk().as_ref()?;
+        let filename = match op {
+            InferenceOperationImpl::PredictDuration => talk.predict_duration_filename(),
+            InferenceOperationImpl::PredictIntonation => talk.predict_intonation_filename(),
+            InferenceOperationImpl::Decode => talk.decode_filename(),
+        };
+        filename.as_deref()
+    }
+
+    /// 変更を検知する対象のファイル。
+    fn fingerprint_paths(&self) -> io::Result<Vec<PathBuf>> {
+        let Some(path) = self.source.path() else {
+            return Ok(vec![]);
+        };
+        if !path.is_dir() {
+            return Ok(vec![path.to_owned()]);
+        }
+        ["manifest.json"]
+            .into_iter()
+            .chain(
+                [
+                    InferenceOperationImpl::PredictDuration,
+                    InferenceOperationImpl::PredictIntonation,
+                    InferenceOperationImpl::Decode,
+                ]
+                .into_iter()
+                .flat_map(|op| self.inference_model_filename(op)),
+            )
+            .map(|filename| unpacked_entry_path(path, filename))
+            .collect()
+    }
+
+    /// 現在のVVMの状態を得る。
+    ///
+    /// # Performance
+    ///
+    /// ブロッキング操作であるため、非同期ランタイム上では直接実行されるべきではない。
+    pub(crate) fn take_fingerprint(&self) -> LoadModelResult<VvmFingerprint> {
+        (|| {
+            let metadata = self
+                .fingerprint_paths()?
+                .iter()
+                .map(|path| metadata_if_exists(fs_err::metadata(path)))
+                .collect::<io::Result<Vec<_>>>()?;
+            Ok(VvmFingerprint::from_metadata(&metadata))
+        })()
+        .map_err(|source: io::Error| self.open_error(source))
+    }
+
+    /// 現在のVVMの状態を得る。
+    pub(crate) async fn take_fingerprint_async(&self) -> LoadModelResult<VvmFingerprint> {
+        let paths = self
+            .fingerprint_paths()
+            .map_err(|source| self.open_error(source))?;
+        let mut metadata = Vec::with_capacity(paths.len());
+        for path in paths {
+            metadata.push(
+                metadata_if_exists(fs_err::tokio::metadata(path).await)
+                    .map_err(|source| self.open_error(source))?,
+            );
+        }
+        Ok(VvmFingerprint::from_metadata(&metadata))
+    }
+
+    /// VVMが読み込んだ時点から変更されていないことを確かめる。
+    ///
+    /// # Performance
+    ///
+    /// ブロッキング操作であるため、非同期ランタイム上では直接実行されるべきではない。
+    pub(crate) fn ensure_unchanged(&self) -> LoadModelResult<()> {
+        self.compare_fingerprint(&self.take_fingerprint()?)
+    }
+
+    /// VVMが読み込んだ時点から変更されていないことを確かめる。
+    pub(crate) async fn ensure_unchanged_async(&self) -> LoadModelResult<()> {
+        self.compare_fingerprint(&self.take_fingerprint_async().await?)
+    }
+
+    fn compare_fingerprint(&self, current: &VvmFingerprint) -> LoadModelResult<()> {
+        if *current != self.fingerprint {
+            return Err(LoadModelError {
+                path: self.source.display_path(),
+                context: LoadModelErrorKind::InvalidModelData,
+                source: Some(anyhow::anyhow!(
+                    "VVMが読み込まれた後に変更されています。もう一度読み込み直してください",
+                )),
+            });
+        }
+        Ok(())
+    }
+
+    fn open_error(&self, source: io::Error) -> LoadModelError {
+        LoadModelError {
+            path: self.source.display_path(),
+            context: LoadModelErrorKind::OpenZipFile,
+            source: Some(source.into()),
+        }
+    }
+
+    /// ある推論操作のモデルを、VVMファイルから読む。
+    ///
+    /// # Performance
+    ///
+    /// ブロッキング操作であるため、非同期ランタイム上では直接実行されるべきではない。
+    ///
+    /// # Panics
+    ///
+    /// VVMがその推論操作を提供していないとき、パニックする。
+    pub(crate) fn read_inference_model(
+        &self,
+        op: InferenceOperationImpl,
+    ) -> LoadModelResult<Vec<u8>> {
+        let filename = self
+            .inference_model_filename(op)
+            .expect("should be provided by the VVM");
+        self.entry_reader()?(filename)
+    }
+
+    /// VVMのエントリを読む関数を作る。読んだエントリは署名と照合される。
+    ///
+    /// VVMが読み込んだ時点から変更されていた場合はエラーとなる。
+    ///
+    /// # Performance
+    ///
+    /// ブロッキング操作であるため、非同期ランタイム上では直接実行されるべきではない。
+    pub(crate) fn entry_reader(
+        &self,
+    ) -> LoadModelResult<impl Fn(&str) -> LoadModelResult<Vec<u8>> + '_> {
+        self.ensure_unchanged()?;
+        let reader = blocking::BlockingVvmEntryReader::open(&self.source)?;
+        Ok(move |filename: &str| {
+            let content = reader.read_vvm_entry(filename)?;
+            self.verify_entry(filename, &content)?;
+            Ok(content)
+        })
+    }
+
+    /// VVMから読んだエントリの中身を、署名と照合する。
+    pub(crate) fn verify_entry(&self, filename: &str, content: &[u8]) -> LoadModelResult<()> {
+        self.signature
+            .verify_entry(filename, content)
+            .map_err(|source| untrusted_voice_model_error(self.source.display_path(), source))
+    }
+
+    /// 推論操作ごとにモデルを読む関数を作る。`Session`を作り直すときに使われる。
+    pub(crate) fn model_reader(
+        &self,
+    ) -> impl Fn(InferenceOperationImpl) -> LoadModelResult<Vec<u8>> + Send + Sync + 'static {
+        let header = self.clone();
+        move |op| header.read_inference_model(op)
+    }
 }
 
 pub(crate) mod blocking {
     use std::{
-        io::{self, Cursor},
-        path::Path,
+        fs::File,
+        io::{self, Cursor, Read, Seek, SeekFrom},
+        path::{Path, PathBuf},
+        sync::Arc,
     };
 
     use enum_map::EnumMap;
-    use nanoid::nanoid;
-    use ouroboros::self_referencing;
     use rayon::iter::{IntoParallelIterator as _, ParallelIterator as _};
     use serde::de::DeserializeOwned;
 
     use crate::{
-        error::{LoadModelError, LoadModelErrorKind, LoadModelResult},
+        error::{ErrorRepr, LoadModelError, LoadModelErrorKind, LoadModelResult},
         infer::domain::InferenceOperationImpl,
-        manifest::Manifest,
-        VoiceModelMeta,
+        manifest::{Manifest, ManifestVersionOnly},
+        metas,
+        vvm_signature::{SignatureStatus, SIGNATURE_FILENAME},
+        SpeakerInfo, StyleType, VoiceModelMeta, VvmPackOptions,
     };
 
-    use super::{VoiceModelHeader, VoiceModelId};
+    use super::{
+        ensure_supported_domains, ensure_supported_manifest_version, unpacked_entry_path,
+        untrusted_voice_model_error, VoiceModelHeader, VoiceModelId, VvmSource, IN_MEMORY_VVM_PATH,
+    };
 
     /// 音声モデル。
     ///
@@ -101,18 +410,29 @@ pub(crate) mod blocking {
     }
 
     impl self::VoiceModel {
+        /// VVMが提供している推論操作のモデルを読む。
         pub(crate) fn read_inference_models(
             &self,
-        ) -> LoadModelResult<EnumMap<InferenceOperationImpl, Vec<u8>>> {
-            let reader = BlockingVvmEntryReader::open(&self.header.path)?;
+        ) -> LoadModelResult<EnumMap<InferenceOperationImpl, Option<Vec<u8>>>> {
+            self.header.ensure_unchanged()?;
+            let reader = BlockingVvmEntryReader::open(&self.header.source)?;
 
             let model_bytes = [
-                self.header.manifest.predict_duration_filename(),
-                self.header.manifest.predict_intonation_filename(),
-                self.header.manifest.decode_filename(),
+                InferenceOperationImpl::PredictDuration,
+                InferenceOperationImpl::PredictIntonation,
+                InferenceOperationImpl::Decode,
             ]
             .into_par_iter()
-            .map(|filename| reader.read_vvm_entry(filename))
+            .map(|op| {
+                self.header
+                    .inference_model_filename(op)
+                    .map(|filename| {
+                        let model_bytes = reader.read_vvm_entry(filename)?;
+                        self.header.verify_entry(filename, &model_bytes)?;
+                        Ok(model_bytes)
+                    })
+                    .transpose()
+            })
             .collect::<std::result::Result<Vec<_>, _>>()?
             .try_into()
             .unwrap_or_else(|_| panic!("should be same length"));
@@ -121,21 +441,83 @@ pub(crate) mod blocking {
         }
 
         /// VVMファイルから`VoiceModel`をコンストラクトする。
+        ///
+        /// `path`には、VVMファイルを展開したディレクトリを指定することもできる。
         pub fn from_path(path: impl AsRef<Path>) -> crate::Result<Self> {
-            let path = path.as_ref().to_owned();
-            let reader = BlockingVvmEntryReader::open(&path)?;
-            let manifest = reader.read_vvm_json::<Manifest>("manifest.json")?;
-            let metas = reader.read_vvm_json(manifest.metas_filename())?;
-            let id = VoiceModelId::new(nanoid!());
+            Self::from_source(VvmSource::Path(path.as_ref().to_owned()))
+        }
 
-            Ok(Self {
-                header: VoiceModelHeader {
-                    id,
-                    metas,
-                    manifest,
-                    path,
-                },
-            })
+        /// メモリ上のVVMから`VoiceModel`をコンストラクトする。
+        ///
+        /// VVMの内容はこの`VoiceModel`が保持し、ファイルシステムは使われない。
+        pub fn from_bytes(bytes: impl Into<Arc<[u8]>>) -> crate::Result<Self> {
+            Self::from_source(VvmSource::Bytes(bytes.into()))
+        }
+
+        /// `reader`から読んだVVMから`VoiceModel`をコンストラクトする。
+        ///
+        /// `reader`は終端まで読まれ、その内容は[`from_bytes`]と同様にメモリ上に保持される。
+        ///
+        /// [`from_bytes`]: Self::from_bytes
+        pub fn from_reader(mut reader: impl Read) -> crate::Result<Self> {
+            let mut bytes = vec![];
+            reader
+                .read_to_end(&mut bytes)
+                .map_err(|source| LoadModelError {
+                    path: IN_MEMORY_VVM_PATH.into(),
+                    context: LoadModelErrorKind::OpenZipFile,
+                    source: Some(source.into()),
+                })?;
+            Self::from_bytes(bytes)
+        }
+
+        /// ONNXファイルとメタ情報`metas`からVVMファイルを`output`に作り、それを開く。
+        ///
+        /// メタ情報が参照する利用規約や画像、音声サンプルのファイルは、メタ情報のファイルがある
+        /// ディレクトリからの相対パスとして読まれ、同じファイル名で格納される。
+        ///
+        /// メタ情報が[`SpeakerMeta`]の列として読めることと、
+        /// [`style_id_to_model_inner_id`]がメタ情報のスタイルと過不足無く対応することを書き込む前に
+        /// 確かめる。VVMは`output`と同じディレクトリの一時ファイルに書き込まれ、読み込み直して問題が
+        /// 無いことを確かめた後に`output`へと移される。問題があった場合は
+        /// [`ErrorKind::PackVvm`]を表わすエラーとなり、`output`は変更されない。
+        ///
+        /// [`SpeakerMeta`]: crate::SpeakerMeta
+        /// [`style_id_to_model_inner_id`]: crate::VvmPackOptions::style_id_to_model_inner_id
+        /// [`ErrorKind::PackVvm`]: crate::ErrorKind::PackVvm
+        pub fn pack(
+            metas: impl AsRef<Path>,
+            output: impl AsRef<Path>,
+            options: VvmPackOptions,
+        ) -> crate::Result<Self> {
+            let output = output.as_ref();
+            crate::vvm_pack::pack(metas.as_ref(), output, options).map_err(|source| {
+                ErrorRepr::PackVvm {
+                    path: output.to_owned(),
+                    source,
+                }
+            })?;
+            Self::from_path(output)
+        }
+
+        pub(crate) fn from_source(source: VvmSource) -> crate::Result<Self> {
+            let reader = BlockingVvmEntryReader::open(&source)?;
+            let signature = reader.read_signature()?;
+            let manifest = reader.read_manifest(&signature)?;
+            let metas_json = reader.read_signed_entry(&signature, manifest.metas_filename())?;
+            let metas = reader.parse_vvm_json(&metas_json)?;
+            let id = VoiceModelId::for_vvm(&manifest, &metas_json);
+
+            let mut header = VoiceModelHeader {
+                id,
+                metas,
+                manifest,
+                source,
+                signature,
+                fingerprint: Default::default(),
+            };
+            header.fingerprint = header.take_fingerprint()?;
+            Ok(Self { header })
         }
 
         /// ID。
@@ -148,78 +530,256 @@ pub(crate) mod blocking {
             &self.header.metas
         }
 
+        /// メタ情報のうち、`style_type`のスタイルのみを返す。
+        ///
+        /// `style_type`のスタイルを一つも持たない話者は含まれない。
+        ///
+        /// Rust APIのみの機能である。C API、Python API、Java APIでは、メタ情報の各スタイルの`type`で
+        /// 絞り込む。
+        pub fn metas_by_style_type(&self, style_type: StyleType) -> VoiceModelMeta {
+            metas::filter_by_style_type(&self.header.metas, style_type)
+        }
+
+        /// 話者の追加情報を、VVMから読んで返す。
+        ///
+        /// # Performance
+        ///
+        /// ブロッキング操作であるため、非同期ランタイム上では直接実行されるべきではない。
+        pub fn speaker_info(&self, speaker_uuid: &str) -> crate::Result<SpeakerInfo> {
+            let speaker = self.header.speaker(speaker_uuid)?;
+            Ok(self.header.read_speaker_info(&speaker)?)
+        }
+
         pub(crate) fn header(&self) -> &VoiceModelHeader {
             &self.header
         }
     }
 
-    #[self_referencing]
-    struct BlockingVvmEntryReader {
-        path: std::path::PathBuf,
-        zip: Vec<u8>,
-        #[covariant]
-        #[borrows(zip)]
-        reader: zip::ZipArchive<Cursor<&'this [u8]>>,
+    /// VVMのエントリを読む。
+    ///
+    /// VVMファイルの場合、セントラルディレクトリと要求されたエントリの部分のみを読む。
+    pub(super) struct BlockingVvmEntryReader {
+        path: PathBuf,
+        archive: BlockingVvmArchive,
+    }
+
+    enum BlockingVvmArchive {
+        Zip(zip::ZipArchive<VvmReader>),
+        /// VVMを展開したディレクトリ。
+        Dir(PathBuf),
     }
 
     impl BlockingVvmEntryReader {
-        fn open(path: &Path) -> LoadModelResult<Self> {
+        pub(super) fn open(vvm: &VvmSource) -> LoadModelResult<Self> {
+            let path = vvm.display_path();
             (|| {
-                let zip = std::fs::read(path)?;
-                Self::try_new(path.to_owned(), zip, |zip| {
-                    zip::ZipArchive::new(Cursor::new(zip))
-                })
+                let reader = match vvm {
+                    VvmSource::Path(path) if path.is_dir() => {
+                        return Ok(BlockingVvmArchive::Dir(path.clone()));
+                    }
+                    VvmSource::Path(path) => VvmReader::File(SharedFile::open(path)?),
+                    VvmSource::Bytes(bytes) => VvmReader::Bytes(Cursor::new(bytes.clone())),
+                };
+                zip::ZipArchive::new(reader).map(BlockingVvmArchive::Zip)
             })()
+            .map(|archive| Self {
+                path: path.clone(),
+                archive,
+            })
             .map_err(|source| LoadModelError {
-                path: path.to_owned(),
+                path,
                 context: LoadModelErrorKind::OpenZipFile,
                 source: Some(source.into()),
             })
         }
 
-        fn read_vvm_json<T: DeserializeOwned>(&self, filename: &str) -> LoadModelResult<T> {
-            let bytes = &self.read_vvm_entry(filename)?;
-            serde_json::from_slice(bytes).map_err(|source| LoadModelError {
-                path: self.borrow_path().clone(),
+        /// 署名があれば読んで検証する。
+        fn read_signature(&self) -> LoadModelResult<SignatureStatus> {
+            let json = self
+                .contains_entry(SIGNATURE_FILENAME)
+                .then(|| self.read_vvm_entry(SIGNATURE_FILENAME))
+                .transpose()?;
+            SignatureStatus::verify(json.as_deref())
+                .map_err(|source| untrusted_voice_model_error(self.path.clone(), source))
+        }
+
+        fn read_manifest(&self, signature: &SignatureStatus) -> LoadModelResult<Manifest> {
+            let json = self.read_signed_entry(signature, "manifest.json")?;
+            let json = self.parse_vvm_json::<serde_json::Value>(&json)?;
+            let ManifestVersionOnly { manifest_version } = self.parse_vvm_json_value(&json)?;
+            ensure_supported_manifest_version(&self.path, manifest_version)?;
+            ensure_supported_domains(&self.path, &self.parse_vvm_json_value(&json)?)?;
+            self.parse_vvm_json_value(&json)
+        }
+
+        fn parse_vvm_json<T: DeserializeOwned>(&self, bytes: &[u8]) -> LoadModelResult<T> {
+            serde_json::from_slice(bytes).map_err(|source| self.invalid_json_error(source))
+        }
+
+        fn parse_vvm_json_value<T: DeserializeOwned>(
+            &self,
+            value: &serde_json::Value,
+        ) -> LoadModelResult<T> {
+            T::deserialize(value).map_err(|source| self.invalid_json_error(source))
+        }
+
+        fn invalid_json_error(&self, source: serde_json::Error) -> LoadModelError {
+            LoadModelError {
+                path: self.path.clone(),
                 context: LoadModelErrorKind::OpenZipFile,
                 source: Some(source.into()),
-            })
+            }
         }
 
-        fn read_vvm_entry(&self, filename: &str) -> LoadModelResult<Vec<u8>> {
+        fn contains_entry(&self, filename: &str) -> bool {
+            match &self.archive {
+                BlockingVvmArchive::Zip(reader) => reader.file_names().any(|name| name == filename),
+                BlockingVvmArchive::Dir(dir) => {
+                    unpacked_entry_path(dir, filename).is_ok_and(|path| path.exists())
+                }
+            }
+        }
+
+        /// エントリを読み、署名と照合する。
+        fn read_signed_entry(
+            &self,
+            signature: &SignatureStatus,
+            filename: &str,
+        ) -> LoadModelResult<Vec<u8>> {
+            let content = self.read_vvm_entry(filename)?;
+            signature
+                .verify_entry(filename, &content)
+                .map_err(|source| untrusted_voice_model_error(self.path.clone(), source))?;
+            Ok(content)
+        }
+
+        pub(super) fn read_vvm_entry(&self, filename: &str) -> LoadModelResult<Vec<u8>> {
             (|| {
-                let mut reader = self.borrow_reader().clone();
+                let reader = match &self.archive {
+                    BlockingVvmArchive::Zip(reader) => reader,
+                    BlockingVvmArchive::Dir(dir) => {
+                        return Ok(fs_err::read(unpacked_entry_path(dir, filename)?)?);
+                    }
+                };
+                // 複製された`ZipArchive`はセントラルディレクトリの情報を共有しつつ、別々の読み取り
+                // 位置を持つ。そのため複数のエントリを並列に読むことができる
+                let mut reader = reader.clone();
                 let mut entry = reader.by_name(filename)?;
                 let mut buf = Vec::with_capacity(entry.size() as _);
                 io::copy(&mut entry, &mut buf)?;
                 Ok(buf)
             })()
             .map_err(|source| LoadModelError {
-                path: self.borrow_path().clone(),
+                path: self.path.clone(),
                 context: LoadModelErrorKind::OpenZipFile,
                 source: Some(source),
             })
         }
     }
+
+    #[derive(Clone)]
+    enum VvmReader {
+        File(SharedFile),
+        Bytes(Cursor<Arc<[u8]>>),
+    }
+
+    impl Read for VvmReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self {
+                Self::File(file) => file.read(buf),
+                Self::Bytes(bytes) => bytes.read(buf),
+            }
+        }
+    }
+
+    impl Seek for VvmReader {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            match self {
+                Self::File(file) => file.seek(pos),
+                Self::Bytes(bytes) => bytes.seek(pos),
+            }
+        }
+    }
+
+    /// 複製されても、読み取り位置を個別に持つ`File`。
+    ///
+    /// OSのファイルの読み取り位置は使わず、位置を指定して読む。
+    #[derive(Clone)]
+    struct SharedFile {
+        file: Arc<File>,
+        len: u64,
+        pos: u64,
+    }
+
+    impl SharedFile {
+        fn open(path: &Path) -> io::Result<Self> {
+            let file = File::open(path)?;
+            let len = file.metadata()?.len();
+            Ok(Self {
+                file: file.into(),
+                len,
+                pos: 0,
+            })
+        }
+    }
+
+    impl Read for SharedFile {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            #[cfg(unix)]
+            let n = std::os::unix::fs::FileExt::read_at(&*self.file, buf, self.pos)?;
+            #[cfg(windows)]
+            let n = std::os::windows::fs::FileExt::seek_read(&*self.file, buf, self.pos)?;
+            self.pos += n as u64;
+            Ok(n)
+        }
+    }
+
+    impl Seek for SharedFile {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            let pos = match pos {
+                SeekFrom::Start(pos) => Some(pos),
+                SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+                SeekFrom::Current(offset) => self.pos.checked_add_signed(offset),
+            }
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "invalid seek to a negative or overflowing position",
+                )
+            })?;
+            self.pos = pos;
+            Ok(pos)
+        }
+    }
 }
 
 pub(crate) mod tokio {
-    use std::{collections::HashMap, io, path::Path};
+    use std::{
+        collections::HashMap,
+        io,
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
 
     use derive_new::new;
     use enum_map::EnumMap;
     use futures::future::join3;
-    use nanoid::nanoid;
     use serde::de::DeserializeOwned;
+    use tokio::io::{AsyncRead, AsyncReadExt as _};
 
     use crate::{
-        error::{LoadModelError, LoadModelErrorKind, LoadModelResult},
+        error::{ErrorRepr, LoadModelError, LoadModelErrorKind, LoadModelResult},
         infer::domain::InferenceOperationImpl,
-        manifest::Manifest,
-        Result, VoiceModelMeta,
+        manifest::{Manifest, ManifestVersionOnly},
+        metas,
+        vvm_signature::{SignatureStatus, SIGNATURE_FILENAME},
+        Result, SpeakerInfo, StyleType, VoiceModelMeta, VvmPackOptions,
     };
 
-    use super::{VoiceModelHeader, VoiceModelId};
+    use super::{
+        ensure_supported_domains, ensure_supported_manifest_version, unpacked_entry_path,
+        untrusted_voice_model_error, VoiceModelHeader, VoiceModelId, VvmSource, IN_MEMORY_VVM_PATH,
+    };
 
     /// 音声モデル。
     ///
@@ -230,18 +790,34 @@ pub(crate) mod tokio {
     }
 
     impl self::VoiceModel {
+        /// VVMが提供している推論操作のモデルを読む。
         pub(crate) async fn read_inference_models(
             &self,
-        ) -> LoadModelResult<EnumMap<InferenceOperationImpl, Vec<u8>>> {
-            let reader = AsyncVvmEntryReader::open(&self.header.path).await?;
+        ) -> LoadModelResult<EnumMap<InferenceOperationImpl, Option<Vec<u8>>>> {
+            self.header.ensure_unchanged_async().await?;
+            let reader = AsyncVvmEntryReader::open(&self.header.source).await?;
+            let reader = &reader;
+            let read_model = |op| {
+                let filename = self.header.inference_model_filename(op);
+                async move {
+                    match filename {
+                        Some(filename) => {
+                            let model_bytes = reader.read_vvm_entry(filename).await?;
+                            self.header.verify_entry(filename, &model_bytes)?;
+                            Ok(Some(model_bytes))
+                        }
+                        None => Ok(None),
+                    }
+                }
+            };
             let (
                 decode_model_result,
                 predict_duration_model_result,
                 predict_intonation_model_result,
             ) = join3(
-                reader.read_vvm_entry(self.header.manifest.decode_filename()),
-                reader.read_vvm_entry(self.header.manifest.predict_duration_filename()),
-                reader.read_vvm_entry(self.header.manifest.predict_intonation_filename()),
+                read_model(InferenceOperationImpl::Decode),
+                read_model(InferenceOperationImpl::PredictDuration),
+                read_model(InferenceOperationImpl::PredictIntonation),
             )
             .await;
 
@@ -252,22 +828,82 @@ pub(crate) mod tokio {
             ]))
         }
         /// VVMファイルから`VoiceModel`をコンストラクトする。
+        ///
+        /// `path`には、VVMファイルを展開したディレクトリを指定することもできる。
         pub async fn from_path(path: impl AsRef<Path>) -> Result<Self> {
-            let reader = AsyncVvmEntryReader::open(path.as_ref()).await?;
-            let manifest = reader.read_vvm_json::<Manifest>("manifest.json").await?;
-            let metas = reader
-                .read_vvm_json::<VoiceModelMeta>(manifest.metas_filename())
-                .await?;
-            let id = VoiceModelId::new(nanoid!());
+            Self::from_source(VvmSource::Path(path.as_ref().to_owned())).await
+        }
 
-            Ok(Self {
-                header: VoiceModelHeader {
-                    id,
-                    metas,
-                    manifest,
-                    path: path.as_ref().into(),
-                },
+        /// メモリ上のVVMから`VoiceModel`をコンストラクトする。
+        ///
+        /// VVMの内容はこの`VoiceModel`が保持し、ファイルシステムは使われない。
+        pub async fn from_bytes(bytes: impl Into<Arc<[u8]>>) -> Result<Self> {
+            Self::from_source(VvmSource::Bytes(bytes.into())).await
+        }
+
+        /// `reader`から読んだVVMから`VoiceModel`をコンストラクトする。
+        ///
+        /// `reader`は終端まで読まれ、その内容は[`from_bytes`]と同様にメモリ上に保持される。
+        ///
+        /// [`from_bytes`]: Self::from_bytes
+        pub async fn from_reader(mut reader: impl AsyncRead + Unpin) -> Result<Self> {
+            let mut bytes = vec![];
+            reader
+                .read_to_end(&mut bytes)
+                .await
+                .map_err(|source| LoadModelError {
+                    path: IN_MEMORY_VVM_PATH.into(),
+                    context: LoadModelErrorKind::OpenZipFile,
+                    source: Some(source.into()),
+                })?;
+            Self::from_bytes(bytes).await
+        }
+
+        /// ONNXファイルとメタ情報`metas`からVVMファイルを`output`に作り、それを開く。
+        ///
+        /// 詳細は[`blocking::VoiceModel::pack`]を参照。
+        ///
+        /// [`blocking::VoiceModel::pack`]: crate::blocking::VoiceModel::pack
+        pub async fn pack(
+            metas: impl AsRef<Path>,
+            output: impl AsRef<Path>,
+            options: VvmPackOptions,
+        ) -> Result<Self> {
+            let metas = metas.as_ref().to_owned();
+            let output = output.as_ref().to_owned();
+            crate::task::asyncify({
+                let output = output.clone();
+                move || crate::vvm_pack::pack(&metas, &output, options)
             })
+            .await
+            .map_err(|source| ErrorRepr::PackVvm {
+                path: output.clone(),
+                source,
+            })?;
+            Self::from_path(output).await
+        }
+
+        async fn from_source(source: VvmSource) -> Result<Self> {
+            let reader = AsyncVvmEntryReader::open(&source).await?;
+            let signature = reader.read_signature().await?;
+            let manifest = reader.read_manifest(&signature).await?;
+            let metas_json = reader
+                .read_signed_entry(&signature, manifest.metas_filename())
+                .await?;
+            let metas =
+                reader.parse_vvm_json::<VoiceModelMeta>(manifest.metas_filename(), &metas_json)?;
+            let id = VoiceModelId::for_vvm(&manifest, &metas_json);
+
+            let mut header = VoiceModelHeader {
+                id,
+                metas,
+                manifest,
+                source,
+                signature,
+                fingerprint: Default::default(),
+            };
+            header.fingerprint = header.take_fingerprint_async().await?;
+            Ok(Self { header })
         }
 
         /// ID。
@@ -280,6 +916,23 @@ pub(crate) mod tokio {
             &self.header.metas
         }
 
+        /// メタ情報のうち、`style_type`のスタイルのみを返す。
+        ///
+        /// `style_type`のスタイルを一つも持たない話者は含まれない。
+        ///
+        /// Rust APIのみの機能である。C API、Python API、Java APIでは、メタ情報の各スタイルの`type`で
+        /// 絞り込む。
+        pub fn metas_by_style_type(&self, style_type: StyleType) -> VoiceModelMeta {
+            metas::filter_by_style_type(&self.header.metas, style_type)
+        }
+
+        /// 話者の追加情報を、VVMから読んで返す。
+        pub async fn speaker_info(&self, speaker_uuid: &str) -> Result<SpeakerInfo> {
+            let speaker = self.header.speaker(speaker_uuid)?;
+            let header = self.header.clone();
+            crate::task::asyncify(move || Ok(header.read_speaker_info(&speaker)?)).await
+        }
+
         pub(crate) fn header(&self) -> &VoiceModelHeader {
             &self.header
         }
@@ -290,65 +943,218 @@ pub(crate) mod tokio {
         entry: async_zip::ZipEntry,
     }
 
+    /// VVMのエントリを読む。
+    ///
+    /// VVMファイルの場合、セントラルディレクトリと要求されたエントリの部分のみを読む。
     #[derive(new)]
-    struct AsyncVvmEntryReader<'a> {
-        path: &'a Path,
-        reader: async_zip::base::read::mem::ZipFileReader,
-        entry_map: HashMap<String, AsyncVvmEntry>,
+    struct AsyncVvmEntryReader {
+        path: PathBuf,
+        archive: AsyncVvmArchive,
+    }
+
+    enum AsyncVvmArchive {
+        Zip {
+            reader: AsyncZipFileReader,
+            entry_map: HashMap<String, AsyncVvmEntry>,
+        },
+        /// VVMを展開したディレクトリ。
+        Dir(PathBuf),
+    }
+
+    enum AsyncZipFileReader {
+        /// エントリを読むたびにファイルを開き直し、そのエントリの位置から読む。
+        File(async_zip::tokio::read::fs::ZipFileReader),
+        /// メモリ上のVVMを複製せずに共有し、エントリを読むたびにその位置から読む。
+        Bytes {
+            bytes: Arc<[u8]>,
+            file: async_zip::ZipFile,
+        },
     }
 
-    impl<'a> AsyncVvmEntryReader<'a> {
-        async fn open(path: &'a Path) -> LoadModelResult<Self> {
-            let reader = async {
-                let file = fs_err::tokio::read(path).await?;
-                async_zip::base::read::mem::ZipFileReader::new(file).await
+    impl AsyncZipFileReader {
+        async fn open(vvm: &VvmSource) -> async_zip::error::Result<Self> {
+            match vvm {
+                VvmSource::Path(path) => async_zip::tokio::read::fs::ZipFileReader::new(path)
+                    .await
+                    .map(Self::File),
+                VvmSource::Bytes(bytes) => {
+                    let reader = async_zip::base::read::seek::ZipFileReader::new(
+                        futures::io::Cursor::new(bytes.clone()),
+                    )
+                    .await?;
+                    Ok(Self::Bytes {
+                        bytes: bytes.clone(),
+                        file: reader.file().clone(),
+                    })
+                }
             }
-            .await
-            .map_err(|source| LoadModelError {
-                path: path.to_owned(),
-                context: LoadModelErrorKind::OpenZipFile,
-                source: Some(source.into()),
-            })?;
+        }
+
+        fn file(&self) -> &async_zip::ZipFile {
+            match self {
+                Self::File(reader) => reader.file(),
+                Self::Bytes { file, .. } => file,
+            }
+        }
+
+        async fn read_entry(
+            &self,
+            index: usize,
+            buf: &mut Vec<u8>,
+        ) -> async_zip::error::Result<()> {
+            match self {
+                Self::File(reader) => {
+                    reader
+                        .reader_with_entry(index)
+                        .await?
+                        .read_to_end_checked(buf)
+                        .await?;
+                }
+                Self::Bytes { bytes, file } => {
+                    async_zip::base::read::seek::ZipFileReader::from_raw_parts(
+                        futures::io::Cursor::new(bytes.clone()),
+                        file.clone(),
+                    )
+                    .reader_with_entry(index)
+                    .await?
+                    .read_to_end_checked(buf)
+                    .await?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl AsyncVvmEntryReader {
+        async fn open(vvm: &VvmSource) -> LoadModelResult<Self> {
+            let path = vvm.display_path();
+            if let VvmSource::Path(dir) = vvm {
+                if fs_err::tokio::metadata(dir)
+                    .await
+                    .is_ok_and(|metadata| metadata.is_dir())
+                {
+                    return Ok(AsyncVvmEntryReader::new(
+                        path,
+                        AsyncVvmArchive::Dir(dir.clone()),
+                    ));
+                }
+            }
+            let reader = AsyncZipFileReader::open(vvm)
+                .await
+                .map_err(|source| LoadModelError {
+                    path: path.clone(),
+                    context: LoadModelErrorKind::OpenZipFile,
+                    source: Some(source.into()),
+                })?;
             let entry_map: HashMap<_, _> = reader
                 .file()
                 .entries()
                 .iter()
-                .flat_map(|e| {
+                .enumerate()
+                .flat_map(|(index, e)| {
                     // 非UTF-8のファイルを利用することはないため、無視する
                     let filename = e.filename().as_str().ok()?;
                     (!e.dir().ok()?).then_some(())?;
-                    Some((filename.to_owned(), (**e).clone()))
+                    let entry = (**e).clone();
+                    Some((filename.to_owned(), AsyncVvmEntry { index, entry }))
                 })
-                .enumerate()
-                .map(|(i, (filename, entry))| (filename, AsyncVvmEntry { index: i, entry }))
                 .collect();
-            Ok(AsyncVvmEntryReader::new(path, reader, entry_map))
+            Ok(AsyncVvmEntryReader::new(
+                path,
+                AsyncVvmArchive::Zip { reader, entry_map },
+            ))
+        }
+        /// 署名があれば読んで検証する。
+        async fn read_signature(&self) -> LoadModelResult<SignatureStatus> {
+            let json = if self.contains_entry(SIGNATURE_FILENAME).await {
+                Some(self.read_vvm_entry(SIGNATURE_FILENAME).await?)
+            } else {
+                None
+            };
+            SignatureStatus::verify(json.as_deref())
+                .map_err(|source| untrusted_voice_model_error(self.path.clone(), source))
+        }
+
+        async fn read_manifest(&self, signature: &SignatureStatus) -> LoadModelResult<Manifest> {
+            const FILENAME: &str = "manifest.json";
+            let json = self.read_signed_entry(signature, FILENAME).await?;
+            let json = self.parse_vvm_json::<serde_json::Value>(FILENAME, &json)?;
+            let ManifestVersionOnly { manifest_version } =
+                self.parse_vvm_json_value(FILENAME, &json)?;
+            ensure_supported_manifest_version(&self.path, manifest_version)?;
+            ensure_supported_domains(&self.path, &self.parse_vvm_json_value(FILENAME, &json)?)?;
+            self.parse_vvm_json_value(FILENAME, &json)
+        }
+
+        fn parse_vvm_json<T: DeserializeOwned>(
+            &self,
+            filename: &str,
+            bytes: &[u8],
+        ) -> LoadModelResult<T> {
+            serde_json::from_slice(bytes)
+                .map_err(|source| self.invalid_json_error(filename, source))
         }
-        async fn read_vvm_json<T: DeserializeOwned>(&self, filename: &str) -> LoadModelResult<T> {
-            let bytes = self.read_vvm_entry(filename).await?;
-            serde_json::from_slice(&bytes).map_err(|source| LoadModelError {
-                path: self.path.to_owned(),
+
+        fn parse_vvm_json_value<T: DeserializeOwned>(
+            &self,
+            filename: &str,
+            value: &serde_json::Value,
+        ) -> LoadModelResult<T> {
+            T::deserialize(value).map_err(|source| self.invalid_json_error(filename, source))
+        }
+
+        fn invalid_json_error(&self, filename: &str, source: serde_json::Error) -> LoadModelError {
+            LoadModelError {
+                path: self.path.clone(),
                 context: LoadModelErrorKind::ReadZipEntry {
                     filename: filename.to_owned(),
                 },
                 source: Some(source.into()),
-            })
+            }
+        }
+
+        async fn contains_entry(&self, filename: &str) -> bool {
+            match &self.archive {
+                AsyncVvmArchive::Zip { entry_map, .. } => entry_map.contains_key(filename),
+                AsyncVvmArchive::Dir(dir) => match unpacked_entry_path(dir, filename) {
+                    Ok(path) => fs_err::tokio::metadata(path).await.is_ok(),
+                    Err(_) => false,
+                },
+            }
+        }
+
+        /// エントリを読み、署名と照合する。
+        async fn read_signed_entry(
+            &self,
+            signature: &SignatureStatus,
+            filename: &str,
+        ) -> LoadModelResult<Vec<u8>> {
+            let content = self.read_vvm_entry(filename).await?;
+            signature
+                .verify_entry(filename, &content)
+                .map_err(|source| untrusted_voice_model_error(self.path.clone(), source))?;
+            Ok(content)
         }
 
         async fn read_vvm_entry(&self, filename: &str) -> LoadModelResult<Vec<u8>> {
             async {
-                let me = self
-                    .entry_map
+                let (reader, entry_map) = match &self.archive {
+                    AsyncVvmArchive::Zip { reader, entry_map } => (reader, entry_map),
+                    AsyncVvmArchive::Dir(dir) => {
+                        let path = unpacked_entry_path(dir, filename)?;
+                        return Ok(fs_err::tokio::read(path).await?);
+                    }
+                };
+                let me = entry_map
                     .get(filename)
                     .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
-                let mut manifest_reader = self.reader.reader_with_entry(me.index).await?;
                 let mut buf = Vec::with_capacity(me.entry.uncompressed_size() as usize);
-                manifest_reader.read_to_end_checked(&mut buf).await?;
+                reader.read_entry(me.index, &mut buf).await?;
                 Ok::<_, anyhow::Error>(buf)
             }
             .await
             .map_err(|source| LoadModelError {
-                path: self.path.to_owned(),
+                path: self.path.clone(),
                 context: LoadModelErrorKind::ReadZipEntry {
                     filename: filename.to_owned(),
                 },
@@ -357,3 +1163,298 @@ pub(crate) mod tokio {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ed25519_dalek::SigningKey;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use serde_json::json;
+
+    use crate::{
+        test_util::open_default_vvm_file,
+        vvm_signature::{VvmSignature, SIGNATURE_FILENAME},
+    };
+
+    use super::{unpacked_entry_path, VvmSource};
+
+    #[rstest]
+    #[tokio::test]
+    async fn from_bytes_works() {
+        let from_path = open_default_vvm_file().await;
+        let VvmSource::Path(path) = &from_path.header().source else {
+            unreachable!();
+        };
+        let bytes = fs_err::read(path).unwrap();
+
+        let from_bytes = crate::tokio::VoiceModel::from_bytes(bytes.clone())
+            .await
+            .unwrap();
+        let from_reader = crate::blocking::VoiceModel::from_reader(&*bytes).unwrap();
+        let blocking_from_path = crate::blocking::VoiceModel::from_path(path).unwrap();
+
+        for (id, metas) in [
+            (from_bytes.id(), from_bytes.metas()),
+            (from_reader.id(), from_reader.metas()),
+            (blocking_from_path.id(), blocking_from_path.metas()),
+        ] {
+            assert_eq!(from_path.id(), id);
+            assert_eq!(
+                serde_json::to_value(from_path.metas()).unwrap(),
+                serde_json::to_value(metas).unwrap(),
+            );
+        }
+        assert!(from_bytes.header().source.path().is_none());
+        assert!(
+            from_path.read_inference_models().await.unwrap()
+                == from_bytes.read_inference_models().await.unwrap()
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn from_path_accepts_unpacked_dir() {
+        let dir = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/test_data/model_sources/load_model_works1",
+        );
+        let from_zip = open_default_vvm_file().await;
+        let blocking = crate::blocking::VoiceModel::from_path(dir).unwrap();
+        let tokio = crate::tokio::VoiceModel::from_path(dir).await.unwrap();
+
+        for (id, metas) in [
+            (blocking.id(), blocking.metas()),
+            (tokio.id(), tokio.metas()),
+        ] {
+            assert_eq!(from_zip.id(), id);
+            assert_eq!(
+                serde_json::to_value(from_zip.metas()).unwrap(),
+                serde_json::to_value(metas).unwrap(),
+            );
+        }
+    }
+
+    #[rstest]
+    #[case("0.0.0", true)]
+    #[case("0.1.0", true)]
+    #[case("0.1.5", true)]
+    #[case("0.2.0", false)]
+    #[case("1.0.0", false)]
+    #[tokio::test]
+    async fn from_path_checks_manifest_version(#[case] version: &str, #[case] supported: bool) {
+        let dir = tempfile::tempdir().unwrap();
+        let source = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/test_data/model_sources/load_model_works1",
+        );
+        fs_err::copy(
+            format!("{source}/metas.json"),
+            dir.path().join("metas.json"),
+        )
+        .unwrap();
+        // 未来のバージョンでは`metas_filename`の意味が変わっているかもしれないため、バージョンが先
+        // に確かめられることを確認する
+        fs_err::write(
+            dir.path().join("manifest.json"),
+            serde_json::to_vec(&json!({
+                "manifest_version": version,
+                "metas_filename": if supported { json!("metas.json") } else { json!(0) },
+            }))
+            .unwrap(),
+        )
+        .unwrap();
+
+        let blocking = crate::blocking::VoiceModel::from_path(dir.path()).map(drop);
+        let tokio = crate::tokio::VoiceModel::from_path(dir.path())
+            .await
+            .map(drop);
+
+        for result in [blocking, tokio] {
+            match result {
+                Ok(()) => assert!(supported),
+                Err(err) => {
+                    assert!(!supported);
+                    assert_eq!(crate::ErrorKind::UnsupportedManifestVersion, err.kind());
+                }
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(Some(json!({ "talk": {} })), None)]
+    #[case(Some(json!({ "sing": {} })), None)]
+    #[case(Some(json!({ "talk": {} })), Some("decode.onnx"))]
+    #[case(None, Some("decode.onnx"))]
+    #[tokio::test]
+    async fn from_path_checks_domains(
+        #[case] domains: Option<serde_json::Value>,
+        #[case] legacy_decode_filename: Option<&str>,
+    ) {
+        let dir = tempfile::tempdir().unwrap();
+        let source = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/test_data/model_sources/load_model_works1",
+        );
+        fs_err::copy(
+            format!("{source}/metas.json"),
+            dir.path().join("metas.json"),
+        )
+        .unwrap();
+        let supported = domains
+            .as_ref()
+            .is_some_and(|domains| domains.get("sing").is_none())
+            && legacy_decode_filename.is_none();
+        let mut manifest = json!({
+            "manifest_version": "0.1.0",
+            "metas_filename": "metas.json",
+        });
+        if let Some(domains) = domains {
+            manifest["domains"] = domains;
+        }
+        if let Some(decode_filename) = legacy_decode_filename {
+            manifest["decode_filename"] = decode_filename.into();
+        }
+        fs_err::write(
+            dir.path().join("manifest.json"),
+            serde_json::to_vec(&manifest).unwrap(),
+        )
+        .unwrap();
+
+        let blocking = crate::blocking::VoiceModel::from_path(dir.path()).map(drop);
+        let tokio = crate::tokio::VoiceModel::from_path(dir.path())
+            .await
+            .map(drop);
+
+        for result in [blocking, tokio] {
+            match result {
+                Ok(()) => assert!(supported),
+                Err(err) => {
+                    assert!(!supported);
+                    assert_eq!(crate::ErrorKind::InvalidModelData, err.kind());
+                }
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(None)]
+    #[case(Some("metas.json"))]
+    #[case(Some("manifest.json"))]
+    #[case(Some("predict_duration.onnx"))]
+    #[tokio::test]
+    async fn from_path_verifies_signature(#[case] tampered: Option<&str>) {
+        let dir = tempfile::tempdir().unwrap();
+        let source = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/test_data/model_sources/load_model_works1",
+        );
+        let mut entries = BTreeMap::from([
+            (
+                "metas.json",
+                fs_err::read(format!("{source}/metas.json")).unwrap(),
+            ),
+            (
+                "predict_duration.onnx",
+                fs_err::read(format!("{source}/predict_duration.onnx")).unwrap(),
+            ),
+            (
+                "manifest.json",
+                serde_json::to_vec(&json!({
+                    "manifest_version": "0.1.0",
+                    "metas_filename": "metas.json",
+                    "domains": {
+                        "talk": { "predict_duration_filename": "predict_duration.onnx" },
+                    },
+                }))
+                .unwrap(),
+            ),
+        ]);
+        let signature = VvmSignature::sign(
+            &SigningKey::from_bytes(&[1; 32]),
+            entries
+                .iter()
+                .map(|(filename, content)| (*filename, &**content)),
+        );
+        entries.insert(SIGNATURE_FILENAME, serde_json::to_vec(&signature).unwrap());
+        if let Some(tampered) = tampered {
+            entries.get_mut(tampered).unwrap().push(b' ');
+        }
+        for (filename, content) in &entries {
+            fs_err::write(dir.path().join(filename), content).unwrap();
+        }
+
+        // モデルは読まれるときに照合される
+        let blocking = crate::blocking::VoiceModel::from_path(dir.path())
+            .and_then(|model| Ok(model.read_inference_models().map(drop)?));
+        let tokio = match crate::tokio::VoiceModel::from_path(dir.path()).await {
+            Ok(model) => model.read_inference_models().await.map_err(Into::into),
+            Err(err) => Err(err),
+        }
+        .map(drop);
+
+        for result in [blocking, tokio] {
+            match (tampered, result) {
+                (None, Ok(())) => {}
+                (Some(_), Err(err)) => {
+                    assert_eq!(crate::ErrorKind::UntrustedVoiceModel, err.kind());
+                }
+                (tampered, result) => panic!("{tampered:?}: {:?}", result.err()),
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(false)]
+    #[case(true)]
+    #[tokio::test]
+    async fn read_inference_models_detects_changes(#[case] unpacked: bool) {
+        let dir = tempfile::tempdir().unwrap();
+        let source = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/test_data/model_sources/load_model_works1",
+        );
+        let (path, changed) = if unpacked {
+            for entry in fs_err::read_dir(source).unwrap() {
+                let entry = entry.unwrap();
+                fs_err::copy(entry.path(), dir.path().join(entry.file_name())).unwrap();
+            }
+            (
+                dir.path().to_owned(),
+                dir.path().join("predict_duration.onnx"),
+            )
+        } else {
+            let model = open_default_vvm_file().await;
+            let VvmSource::Path(vvm) = &model.header().source else {
+                unreachable!();
+            };
+            let path = dir.path().join("model.vvm");
+            fs_err::copy(vvm, &path).unwrap();
+            (path.clone(), path)
+        };
+
+        let blocking = crate::blocking::VoiceModel::from_path(&path).unwrap();
+        let tokio = crate::tokio::VoiceModel::from_path(&path).await.unwrap();
+
+        let mut content = fs_err::read(&changed).unwrap();
+        content.push(0);
+        fs_err::write(&changed, content).unwrap();
+
+        let blocking = blocking.read_inference_models().map(drop);
+        let tokio = tokio.read_inference_models().await.map(drop);
+        for result in [blocking, tokio] {
+            let err = crate::Error::from(result.unwrap_err());
+            assert_eq!(crate::ErrorKind::InvalidModelData, err.kind());
+        }
+    }
+
+    #[rstest]
+    #[case("decode.onnx", true)]
+    #[case("models/decode.onnx", true)]
+    #[case("../decode.onnx", false)]
+    #[case("/decode.onnx", false)]
+    fn unpacked_entry_path_rejects_outside_of_dir(#[case] filename: &str, #[case] ok: bool) {
+        assert_eq!(ok, unpacked_entry_path("vvm".as_ref(), filename).is_ok());
+    }
+}
@@ -0,0 +1,522 @@
+//! 音声モデルの集合。
+//!
+//! ディレクトリ内のVVMを走査し、スタイルIDから音声モデルを引けるようにする。走査の時点では各VVMの
+//! マニフェストとメタ情報のみが読まれ、`Session`は作られない。
+
+use std::{
+    collections::{btree_map, BTreeMap},
+    fs, io,
+    path::PathBuf,
+};
+
+use serde::Serialize;
+use tracing::warn;
+
+use crate::{
+    error::ErrorRepr, metas, voice_model::VoiceModelHeader, Error, Result, SpeakerMeta, StyleId,
+    StyleMeta, VoiceModelMeta,
+};
+
+/// 音声モデルの集合を作るときに見つかった、二つのVVMの衝突。
+///
+/// 先に見つかったVVMが優先される。
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct VoiceModelConflict {
+    /// 優先されたVVMのパス。
+    pub preferred: PathBuf,
+    /// 優先されなかったVVMのパス。
+    pub shadowed: PathBuf,
+    /// 両方のVVMに含まれるスタイルのID。`shadowed`のこれらのスタイルは使われない。
+    pub style_ids: Vec<StyleId>,
+    /// 音声モデルIDが同じであるか。同じ場合、`shadowed`のVVMは丸ごと使われない。
+    pub same_voice_model_id: bool,
+}
+
+/// `dirs`の直下にあるVVMのパスを列挙する。
+///
+/// `dirs`の順に、それぞれのディレクトリの中ではパスの順に並べる。名前が`.vvm`で終わるディレクトリ
+/// は、VVMを展開したものとして含める。
+fn list_vvm_paths(dirs: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut vvm_paths = vec![];
+    for dir in dirs {
+        let mut paths = fs::read_dir(dir)
+            .and_then(|entries| {
+                entries
+                    .map(|entry| Ok(entry?.path()))
+                    .collect::<io::Result<Vec<_>>>()
+            })
+            .map_err(|source| ErrorRepr::ReadModelDir {
+                path: dir.clone(),
+                source,
+            })?;
+        paths.retain(|path| path.extension().map_or(false, |ext| ext == "vvm"));
+        paths.sort();
+        vvm_paths.extend(paths);
+    }
+    Ok(vvm_paths)
+}
+
+fn style_ids(header: &VoiceModelHeader) -> impl Iterator<Item = StyleId> + '_ {
+    header
+        .metas
+        .iter()
+        .flat_map(SpeakerMeta::styles)
+        .map(StyleMeta::id)
+        .copied()
+}
+
+/// ブロッキング版と非同期版の音声モデルの集合の、共通部分。
+struct Inner<M> {
+    /// VVMのパスと音声モデル。音声モデルIDが衝突したものは含まない。
+    models: Vec<(PathBuf, M)>,
+    /// スタイルIDから、`models`のインデックスへの対応。
+    style_to_model: BTreeMap<StyleId, usize>,
+    /// 使われるスタイルのみのメタ情報。
+    metas: VoiceModelMeta,
+    conflicts: Vec<VoiceModelConflict>,
+    /// 読み込めなかったVVMのエラー。
+    errors: Vec<Error>,
+}
+
+impl<M> Inner<M> {
+    fn new(
+        entries: Vec<(PathBuf, M)>,
+        errors: Vec<Error>,
+        header: impl Fn(&M) -> &VoiceModelHeader,
+    ) -> Self {
+        let mut models = Vec::<(PathBuf, M)>::with_capacity(entries.len());
+        let mut style_to_model = BTreeMap::new();
+        let mut conflicts = vec![];
+
+        for (path, model) in entries {
+            if let Some((preferred, preferred_model)) = models
+                .iter()
+                .find(|(_, preferred)| header(preferred).id == header(&model).id)
+            {
+                let preferred_style_ids = style_ids(header(preferred_model)).collect::<Vec<_>>();
+                conflicts.push(VoiceModelConflict {
+                    preferred: preferred.clone(),
+                    shadowed: path,
+                    style_ids: style_ids(header(&model))
+                        .filter(|style_id| preferred_style_ids.contains(style_id))
+                        .collect(),
+                    same_voice_model_id: true,
+                });
+                continue;
+            }
+
+            let index = models.len();
+            let mut shadowed_style_ids = BTreeMap::<usize, Vec<_>>::new();
+            for style_id in style_ids(header(&model)) {
+                match style_to_model.entry(style_id) {
+                    btree_map::Entry::Vacant(entry) => {
+                        entry.insert(index);
+                    }
+                    btree_map::Entry::Occupied(entry) => {
+                        shadowed_style_ids
+                            .entry(*entry.get())
+                            .or_default()
+                            .push(style_id);
+                    }
+                }
+            }
+            conflicts.extend(
+                shadowed_style_ids
+                    .into_iter()
+                    .map(|(preferred, style_ids)| VoiceModelConflict {
+                        preferred: models[preferred].0.clone(),
+                        shadowed: path.clone(),
+                        style_ids,
+                        same_voice_model_id: false,
+                    }),
+            );
+            models.push((path, model));
+        }
+
+        for VoiceModelConflict {
+            preferred,
+            shadowed,
+            style_ids,
+            same_voice_model_id,
+        } in &conflicts
+        {
+            if *same_voice_model_id {
+                warn!(
+                    "`{}`は`{}`と音声モデルIDが同じであるため、使われません",
+                    shadowed.display(),
+                    preferred.display(),
+                );
+            } else {
+                warn!(
+                    "`{}`のスタイル{style_ids:?}は`{}`のものと衝突するため、使われません",
+                    shadowed.display(),
+                    preferred.display(),
+                );
+            }
+        }
+
+        for error in &errors {
+            warn!("{error}。このVVMは使われません");
+        }
+
+        let metas = models
+            .iter()
+            .enumerate()
+            .flat_map(|(index, (_, model))| {
+                metas::filter_styles(&header(model).metas, |style| {
+                    style_to_model[style.id()] == index
+                })
+            })
+            .collect::<Vec<_>>();
+        let metas = metas::merge(&metas);
+
+        Self {
+            models,
+            style_to_model,
+            metas,
+            conflicts,
+            errors,
+        }
+    }
+
+    fn voice_model_by_style_id(&self, style_id: StyleId) -> Result<&M> {
+        let &index = self
+            .style_to_model
+            .get(&style_id)
+            .ok_or(ErrorRepr::StyleNotFoundInModelSet { style_id })?;
+        Ok(&self.models[index].1)
+    }
+}
+
+pub(crate) mod blocking {
+    use std::path::Path;
+
+    use crate::{Error, Result, StyleId, VoiceModelMeta};
+
+    use super::{Inner, VoiceModelConflict};
+
+    /// 音声モデルの集合。
+    ///
+    /// ディレクトリ内のVVMを走査し、スタイルIDに対してそのスタイルを含む音声モデルを引けるように
+    /// する。走査の時点ではVVMのマニフェストとメタ情報のみが読まれ、`Session`は作られない。
+    ///
+    /// 同じスタイルIDを含むVVMや、同じ音声モデルIDを持つVVMが複数見つかった場合、先に見つかった
+    /// ものが優先され、その衝突は[`conflicts`]で得られる。読み込めなかったVVMは飛ばされ、そのエラー
+    /// は[`errors`]で得られる。
+    ///
+    /// [`conflicts`]: Self::conflicts
+    /// [`errors`]: Self::errors
+    pub struct VoiceModelSet(Inner<crate::blocking::VoiceModel>);
+
+    impl self::VoiceModelSet {
+        /// `dirs`の直下にあるVVMを走査し、`VoiceModelSet`をコンストラクトする。
+        ///
+        /// VVMは`dirs`の順に、それぞれのディレクトリの中ではパスの順に見つかったものとする。名前が
+        /// `.vvm`で終わるディレクトリは、VVMを展開したものとして扱う。
+        ///
+        /// # Errors
+        ///
+        /// `dirs`のいずれかを読めなかった場合、[`ErrorKind::ReadModelDir`]を表わすエラーを返す。
+        /// 個々のVVMを読めなかった場合はエラーとせず、そのVVMを飛ばす。
+        ///
+        /// [`ErrorKind::ReadModelDir`]: crate::ErrorKind::ReadModelDir
+        pub fn from_dirs<P: AsRef<Path>>(dirs: impl IntoIterator<Item = P>) -> Result<Self> {
+            let dirs = dirs
+                .into_iter()
+                .map(|dir| dir.as_ref().to_owned())
+                .collect::<Vec<_>>();
+            let mut entries = vec![];
+            let mut errors = vec![];
+            for path in super::list_vvm_paths(&dirs)? {
+                match crate::blocking::VoiceModel::from_path(&path) {
+                    Ok(model) => entries.push((path, model)),
+                    Err(err) => errors.push(err),
+                }
+            }
+            Ok(Self(Inner::new(
+                entries,
+                errors,
+                crate::blocking::VoiceModel::header,
+            )))
+        }
+
+        /// 使われる音声モデルと、そのVVMのパス。
+        pub fn voice_models(&self) -> impl Iterator<Item = (&Path, &crate::blocking::VoiceModel)> {
+            self.0.models.iter().map(|(path, model)| (&**path, model))
+        }
+
+        /// スタイルIDに対し、そのスタイルを含む音声モデルを返す。
+        pub fn voice_model_by_style_id(
+            &self,
+            style_id: StyleId,
+        ) -> Result<&crate::blocking::VoiceModel> {
+            self.0.voice_model_by_style_id(style_id)
+        }
+
+        /// メタ情報。衝突により使われないスタイルは含まない。
+        pub fn metas(&self) -> &VoiceModelMeta {
+            &self.0.metas
+        }
+
+        /// 走査時に見つかった衝突。
+        pub fn conflicts(&self) -> &[VoiceModelConflict] {
+            &self.0.conflicts
+        }
+
+        /// 走査時に読めず、飛ばされたVVMのエラー。
+        pub fn errors(&self) -> &[Error] {
+            &self.0.errors
+        }
+    }
+}
+
+pub(crate) mod tokio {
+    use std::path::Path;
+
+    use crate::{Error, Result, StyleId, VoiceModelMeta};
+
+    use super::{Inner, VoiceModelConflict};
+
+    /// 音声モデルの集合。
+    ///
+    /// ディレクトリ内のVVMを走査し、スタイルIDに対してそのスタイルを含む音声モデルを引けるように
+    /// する。走査の時点ではVVMのマニフェストとメタ情報のみが読まれ、`Session`は作られない。
+    ///
+    /// 同じスタイルIDを含むVVMや、同じ音声モデルIDを持つVVMが複数見つかった場合、先に見つかった
+    /// ものが優先され、その衝突は[`conflicts`]で得られる。読み込めなかったVVMは飛ばされ、そのエラー
+    /// は[`errors`]で得られる。
+    ///
+    /// [`conflicts`]: Self::conflicts
+    /// [`errors`]: Self::errors
+    pub struct VoiceModelSet(Inner<crate::tokio::VoiceModel>);
+
+    impl self::VoiceModelSet {
+        /// `dirs`の直下にあるVVMを走査し、`VoiceModelSet`をコンストラクトする。
+        ///
+        /// VVMは`dirs`の順に、それぞれのディレクトリの中ではパスの順に見つかったものとする。名前が
+        /// `.vvm`で終わるディレクトリは、VVMを展開したものとして扱う。
+        ///
+        /// # Errors
+        ///
+        /// `dirs`のいずれかを読めなかった場合、[`ErrorKind::ReadModelDir`]を表わすエラーを返す。
+        /// 個々のVVMを読めなかった場合はエラーとせず、そのVVMを飛ばす。
+        ///
+        /// [`ErrorKind::ReadModelDir`]: crate::ErrorKind::ReadModelDir
+        pub async fn from_dirs<P: AsRef<Path>>(dirs: impl IntoIterator<Item = P>) -> Result<Self> {
+            let dirs = dirs
+                .into_iter()
+                .map(|dir| dir.as_ref().to_owned())
+                .collect::<Vec<_>>();
+            let paths = crate::task::asyncify(move || super::list_vvm_paths(&dirs)).await?;
+            let mut entries = Vec::with_capacity(paths.len());
+            let mut errors = vec![];
+            for path in paths {
+                match crate::tokio::VoiceModel::from_path(&path).await {
+                    Ok(model) => entries.push((path, model)),
+                    Err(err) => errors.push(err),
+                }
+            }
+            Ok(Self(Inner::new(
+                entries,
+                errors,
+                crate::tokio::VoiceModel::header,
+            )))
+        }
+
+        /// 使われる音声モデルと、そのVVMのパス。
+        pub fn voice_models(&self) -> impl Iterator<Item = (&Path, &crate::tokio::VoiceModel)> {
+            self.0.models.iter().map(|(path, model)| (&**path, model))
+        }
+
+        /// スタイルIDに対し、そのスタイルを含む音声モデルを返す。
+        pub fn voice_model_by_style_id(
+            &self,
+            style_id: StyleId,
+        ) -> Result<&crate::tokio::VoiceModel> {
+            self.0.voice_model_by_style_id(style_id)
+        }
+
+        /// メタ情報。衝突により使われないスタイルは含まない。
+        pub fn metas(&self) -> &VoiceModelMeta {
+            &self.0.metas
+        }
+
+        /// 走査時に見つかった衝突。
+        pub fn conflicts(&self) -> &[VoiceModelConflict] {
+            &self.0.conflicts
+        }
+
+        /// 走査時に読めず、飛ばされたVVMのエラー。
+        pub fn errors(&self) -> &[Error] {
+            &self.0.errors
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use serde_json::json;
+
+    use crate::{ErrorKind, SpeakerMeta, StyleId};
+
+    use super::VoiceModelConflict;
+
+    fn write_vvm(path: &Path, id: &str, speaker_uuid: &str, style_ids: &[u32]) {
+        fs_err::create_dir_all(path).unwrap();
+        fs_err::write(
+            path.join("manifest.json"),
+            serde_json::to_vec(&json!({
+                "manifest_version": "0.0.0",
+                "id": id,
+                "metas_filename": "metas.json",
+            }))
+            .unwrap(),
+        )
+        .unwrap();
+        fs_err::write(
+            path.join("metas.json"),
+            serde_json::to_vec(&json!([
+                {
+                    "name": speaker_uuid,
+                    "styles": style_ids
+                        .iter()
+                        .map(|id| json!({ "id": id, "name": id.to_string() }))
+                        .collect::<Vec<_>>(),
+                    "version": "0.0.1",
+                    "speaker_uuid": speaker_uuid,
+                },
+            ]))
+            .unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn from_dirs_works() {
+        let dir1 = tempfile::tempdir().unwrap();
+        let dir2 = tempfile::tempdir().unwrap();
+        write_vvm(&dir1.path().join("b.vvm"), "b", "B", &[1, 2]);
+        write_vvm(&dir1.path().join("a.vvm"), "a", "A", &[0, 1]);
+        write_vvm(&dir1.path().join("x.txt"), "x", "X", &[3]);
+        write_vvm(&dir2.path().join("c.vvm"), "a", "A", &[0]);
+
+        let dirs = [dir1.path(), dir2.path()];
+        let blocking = crate::blocking::VoiceModelSet::from_dirs(dirs).unwrap();
+        let tokio = crate::tokio::VoiceModelSet::from_dirs(dirs).await.unwrap();
+
+        let expected_conflicts = [
+            VoiceModelConflict {
+                preferred: dir1.path().join("a.vvm"),
+                shadowed: dir1.path().join("b.vvm"),
+                style_ids: vec![StyleId::new(1)],
+                same_voice_model_id: false,
+            },
+            VoiceModelConflict {
+                preferred: dir1.path().join("a.vvm"),
+                shadowed: dir2.path().join("c.vvm"),
+                style_ids: vec![StyleId::new(0)],
+                same_voice_model_id: true,
+            },
+        ];
+        let style_ids = |metas: &[SpeakerMeta]| {
+            metas
+                .iter()
+                .map(|speaker| {
+                    let style_ids = speaker
+                        .styles()
+                        .iter()
+                        .map(|style| style.id().raw_id())
+                        .collect::<Vec<_>>();
+                    (speaker.speaker_uuid().clone(), style_ids)
+                })
+                .collect::<Vec<_>>()
+        };
+        let expected_style_ids = [("A".to_owned(), vec![0, 1]), ("B".to_owned(), vec![2])];
+
+        for (voice_model_ids, metas, conflicts) in [
+            (
+                [0, 1, 2].map(|style_id| {
+                    blocking
+                        .voice_model_by_style_id(StyleId::new(style_id))
+                        .unwrap()
+                        .id()
+                        .raw_voice_model_id()
+                        .clone()
+                }),
+                blocking.metas(),
+                blocking.conflicts(),
+            ),
+            (
+                [0, 1, 2].map(|style_id| {
+                    tokio
+                        .voice_model_by_style_id(StyleId::new(style_id))
+                        .unwrap()
+                        .id()
+                        .raw_voice_model_id()
+                        .clone()
+                }),
+                tokio.metas(),
+                tokio.conflicts(),
+            ),
+        ] {
+            assert_eq!(["a", "a", "b"], voice_model_ids);
+            assert_eq!(&expected_style_ids[..], style_ids(metas));
+            assert_eq!(&expected_conflicts[..], conflicts);
+        }
+
+        assert_eq!(2, blocking.voice_models().count());
+        assert_eq!(
+            ErrorKind::StyleNotFound,
+            blocking
+                .voice_model_by_style_id(StyleId::new(3))
+                .map(drop)
+                .unwrap_err()
+                .kind(),
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn from_dirs_skips_unreadable_vvm() {
+        let dir = tempfile::tempdir().unwrap();
+        write_vvm(&dir.path().join("a.vvm"), "a", "A", &[0]);
+        fs_err::write(dir.path().join("b.vvm"), "not a zip").unwrap();
+
+        let blocking = crate::blocking::VoiceModelSet::from_dirs([dir.path()]).unwrap();
+        let tokio = crate::tokio::VoiceModelSet::from_dirs([dir.path()])
+            .await
+            .unwrap();
+
+        for (num_voice_models, errors) in [
+            (blocking.voice_models().count(), blocking.errors()),
+            (tokio.voice_models().count(), tokio.errors()),
+        ] {
+            assert_eq!(1, num_voice_models);
+            assert_eq!(
+                [ErrorKind::OpenZipFile],
+                *errors.iter().map(|err| err.kind()).collect::<Vec<_>>(),
+            );
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn from_dirs_fails_for_missing_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("missing");
+
+        let blocking = crate::blocking::VoiceModelSet::from_dirs([&missing]).map(drop);
+        let tokio = crate::tokio::VoiceModelSet::from_dirs([&missing])
+            .await
+            .map(drop);
+
+        for result in [blocking, tokio] {
+            assert_eq!(ErrorKind::ReadModelDir, result.unwrap_err().kind());
+        }
+    }
+}
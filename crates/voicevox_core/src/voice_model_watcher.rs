@@ -0,0 +1,188 @@
+//! VVMファイルの変更の監視。
+
+use std::{
+    collections::{HashMap, HashSet},
+    io,
+    path::{Path, PathBuf},
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use anyhow::Context as _;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher as _};
+use tracing::warn;
+
+/// 変更が止んだとみなすまでの時間。
+///
+/// 書き込み途中のファイルを何度も読み込み直さないよう、この時間だけ変更が無かった後に`on_change`
+/// を呼ぶ。
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// VVMファイルの変更を監視する。
+///
+/// VVMファイルが別のファイルで置き換えられた場合にも追従できるよう、VVMファイルそのものではなく
+/// それを含むディレクトリを監視する。展開されたVVMの場合は、そのディレクトリ自体を監視する。
+///
+/// 変更の通知は専用のスレッドで受け取る。通知を受け取るスレッドでは読み込み直しを行わないため、
+/// 読み込み直しの間も通知は取りこぼされない。
+pub(crate) struct VoiceModelWatcher {
+    watcher: Mutex<RecommendedWatcher>,
+    /// 監視しているディレクトリと、その中で監視しているVVMファイルの数。
+    dirs: Mutex<HashMap<PathBuf, usize>>,
+}
+
+impl VoiceModelWatcher {
+    /// 監視しているディレクトリ内のファイルが作られるか変更されると、変更が止んだ後にそのファイル
+    /// の[正規化]されたパスで`on_change`を呼ぶ。
+    ///
+    /// `on_change`は専用のスレッドで呼ばれる。そのスレッドは`VoiceModelWatcher`がdropされると終了
+    /// する。
+    ///
+    /// [正規化]: normalize
+    pub(crate) fn new(on_change: impl Fn(&Path) + Send + 'static) -> anyhow::Result<Self> {
+        Self::with_debounce(DEBOUNCE, on_change)
+    }
+
+    fn with_debounce(
+        debounce: Duration,
+        on_change: impl Fn(&Path) + Send + 'static,
+    ) -> anyhow::Result<Self> {
+        let (tx, rx) = mpsc::channel::<PathBuf>();
+
+        std::thread::Builder::new()
+            .name("voicevox-core-vvm-reloader".to_owned())
+            .spawn(move || {
+                // 送信側(`watcher`)がdropされると`recv`が失敗し、スレッドが終了する
+                while let Ok(path) = rx.recv() {
+                    let mut changed = HashSet::from([path]);
+                    loop {
+                        match rx.recv_timeout(debounce) {
+                            Ok(path) => {
+                                changed.insert(path);
+                            }
+                            Err(RecvTimeoutError::Timeout) => break,
+                            Err(RecvTimeoutError::Disconnected) => return,
+                        }
+                    }
+                    changed.iter().for_each(|path| on_change(path));
+                }
+            })?;
+
+        let watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| match event {
+                Ok(event) => {
+                    if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                        for path in event.paths {
+                            // 受信側が終了しているのは`VoiceModelWatcher`がdropされる途中のみ
+                            let _ = tx.send(path);
+                        }
+                    }
+                }
+                Err(err) => warn!("VVMファイルの監視中にエラーが発生しました: {err}"),
+            })?;
+        Ok(Self {
+            watcher: watcher.into(),
+            dirs: Default::default(),
+        })
+    }
+
+    pub(crate) fn watch(&self, vvm_path: &Path) -> anyhow::Result<()> {
+        let dir = watched_dir(vvm_path)?;
+        let mut dirs = self.dirs.lock().unwrap();
+        if !dirs.contains_key(&dir) {
+            self.watcher
+                .lock()
+                .unwrap()
+                .watch(&dir, RecursiveMode::NonRecursive)?;
+        }
+        *dirs.entry(dir).or_default() += 1;
+        Ok(())
+    }
+
+    pub(crate) fn unwatch(&self, vvm_path: &Path) -> anyhow::Result<()> {
+        let dir = watched_dir(vvm_path)?;
+        let mut dirs = self.dirs.lock().unwrap();
+        let Some(count) = dirs.get_mut(&dir) else {
+            return Ok(());
+        };
+        *count -= 1;
+        if *count == 0 {
+            dirs.remove(&dir);
+            self.watcher.lock().unwrap().unwatch(&dir)?;
+        }
+        Ok(())
+    }
+}
+
+/// VVMファイルのパスを、監視で得られるパスと比較できる形にする。
+///
+/// ファイルが一時的に存在しない場合にも使えるよう、ディレクトリ部分のみを正規化する。
+pub(crate) fn normalize(vvm_path: &Path) -> io::Result<PathBuf> {
+    let dir = match vvm_path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let file_name = vvm_path
+        .file_name()
+        .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidInput))?;
+    Ok(fs_err::canonicalize(dir)?.join(file_name))
+}
+
+/// [正規化]されたVVMのパス`vvm_path`に対し、`changed`の変更がそのVVMの変更であるか。
+///
+/// 展開されたVVMの場合、ディレクトリ直下のファイルの変更をVVMの変更とみなす。
+///
+/// [正規化]: normalize
+pub(crate) fn is_change_of(vvm_path: &Path, changed: &Path) -> bool {
+    changed == vvm_path || vvm_path.is_dir() && changed.parent() == Some(vvm_path)
+}
+
+fn watched_dir(vvm_path: &Path) -> anyhow::Result<PathBuf> {
+    let path = normalize(vvm_path)?;
+    if path.is_dir() {
+        return Ok(path);
+    }
+    let dir = path
+        .parent()
+        .with_context(|| format!("{}", path.display()))?;
+    Ok(dir.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use rstest::rstest;
+
+    use super::{normalize, VoiceModelWatcher};
+
+    #[rstest]
+    fn voice_model_watcher_debounces_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let vvm = dir.path().join("model.vvm");
+        fs_err::write(&vvm, b"").unwrap();
+        let vvm = normalize(&vvm).unwrap();
+
+        let changes = Arc::new(Mutex::new(vec![]));
+        let watcher = VoiceModelWatcher::with_debounce(Duration::from_millis(300), {
+            let changes = changes.clone();
+            move |path| changes.lock().unwrap().push(path.to_owned())
+        })
+        .unwrap();
+        watcher.watch(&vvm).unwrap();
+
+        for i in 0..5 {
+            fs_err::write(&vvm, [i]).unwrap();
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        std::thread::sleep(Duration::from_secs(2));
+
+        assert_eq!([vvm], **changes.lock().unwrap());
+    }
+}
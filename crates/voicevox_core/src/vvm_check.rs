@@ -0,0 +1,214 @@
+//! 配布前のVVMの検証。
+
+use std::{collections::BTreeMap, num::NonZeroUsize};
+
+use thiserror::Error;
+
+use crate::{
+    infer::{
+        domain::{InferenceDomainImpl, InferenceOperationImpl},
+        status::new_checked_session,
+        InferenceDomain as _, InferenceOperation as _, InferenceSessionOptions,
+    },
+    manifest::{ModelInnerId, RawModelInnerId},
+    speaker_info,
+    synthesizer::LocalInferenceRuntimeImpl,
+    SpeakerMeta, StyleId, VoiceModelMeta,
+};
+
+/// [`inspect`]の結果。
+pub struct VvmReport {
+    /// スタイルIDから、モデル内IDへの対応。
+    pub model_inner_ids: BTreeMap<StyleId, RawModelInnerId>,
+    /// 署名者の公開鍵。署名が無い場合は`None`。
+    pub signer: Option<[u8; 32]>,
+    /// 見つかった問題。空であれば問題は無い。
+    pub problems: Vec<VvmProblem>,
+}
+
+/// VVMの問題。
+#[derive(Error, Debug)]
+pub enum VvmProblem {
+    #[error("マニフェストが参照する`{filename}`を読めませんでした")]
+    UnreadableFile {
+        filename: String,
+        #[source]
+        source: crate::Error,
+    },
+
+    #[error("メタ情報が参照する`{filename}`を読めませんでした")]
+    UnreadableResource {
+        filename: String,
+        #[source]
+        source: crate::Error,
+    },
+
+    #[error("スタイル`{style_id}`が`style_id_to_model_inner_id`に含まれていません")]
+    UnmappedStyle { style_id: StyleId },
+
+    #[error("`style_id_to_model_inner_id`のスタイル`{style_id}`はメタ情報にありません")]
+    UnknownStyle { style_id: StyleId },
+
+    #[error("`{filename}`は推論操作`{operation}`のモデルとして使えません")]
+    InvalidModel {
+        operation: &'static str,
+        filename: String,
+        #[source]
+        source: anyhow::Error,
+    },
+}
+
+/// VVMを検証する。
+///
+/// 次のことを確かめる。
+///
+/// - マニフェストが参照するファイルがすべて読めること
+/// - メタ情報が参照する利用規約や画像、音声サンプルのファイルがすべて読めること
+/// - `style_id_to_model_inner_id`が空でない場合、それがメタ情報のスタイルと過不足無く対応すること
+/// - `check_sessions`が`true`の場合、それぞれの推論操作のモデルから`Session`が作れ、その入出力が
+///   推論操作のシグネチャと合っていること
+///
+/// # Performance
+///
+/// ブロッキング操作であるため、非同期ランタイム上では直接実行されるべきではない。
+pub fn inspect(model: &crate::blocking::VoiceModel, check_sessions: bool) -> VvmReport {
+    let header = model.header();
+    let mut problems = vec![];
+
+    if let Some(talk) = header.manifest.domains().talk() {
+        problems.extend(check_style_id_mapping(
+            &header.metas,
+            talk.style_id_to_model_inner_id(),
+        ));
+    }
+
+    for (op, provided) in InferenceDomainImpl::provided_operations(header) {
+        if !provided {
+            continue;
+        }
+        let filename = header
+            .inference_model_filename(op)
+            .expect("should be provided")
+            .to_owned();
+        let model_bytes = match header.read_inference_model(op) {
+            Ok(model_bytes) => model_bytes,
+            Err(err) => {
+                problems.push(VvmProblem::UnreadableFile {
+                    filename,
+                    source: err.into(),
+                });
+                continue;
+            }
+        };
+        if check_sessions {
+            let options = InferenceSessionOptions::new(0, false, NonZeroUsize::MIN);
+            if let Err(source) = new_checked_session::<LocalInferenceRuntimeImpl, InferenceDomainImpl>(
+                op,
+                &model_bytes,
+                options,
+            ) {
+                problems.push(VvmProblem::InvalidModel {
+                    operation: InferenceOperationImpl::NAMES[op],
+                    filename,
+                    source,
+                });
+            }
+        }
+    }
+
+    for filename in speaker_info::resource_filenames(&header.metas) {
+        if let Err(err) = header
+            .entry_reader()
+            .and_then(|read_entry| read_entry(filename))
+        {
+            problems.push(VvmProblem::UnreadableResource {
+                filename: filename.to_owned(),
+                source: err.into(),
+            });
+        }
+    }
+
+    let model_inner_ids = header
+        .model_inner_ids()
+        .into_iter()
+        .map(|(style_id, model_inner_id)| (style_id, model_inner_id.raw_id()))
+        .collect();
+
+    VvmReport {
+        model_inner_ids,
+        signer: header.signature.public_key().copied(),
+        problems,
+    }
+}
+
+/// `style_id_to_model_inner_id`が空でない場合に、それがメタ情報のスタイルと過不足無く対応するかを
+/// 確かめる。
+pub(crate) fn check_style_id_mapping(
+    metas: &VoiceModelMeta,
+    style_id_to_model_inner_id: &BTreeMap<StyleId, ModelInnerId>,
+) -> Vec<VvmProblem> {
+    if style_id_to_model_inner_id.is_empty() {
+        return vec![];
+    }
+    let style_ids = metas
+        .iter()
+        .flat_map(SpeakerMeta::styles)
+        .map(|style| *style.id())
+        .collect::<Vec<_>>();
+
+    let unmapped = style_ids
+        .iter()
+        .filter(|style_id| !style_id_to_model_inner_id.contains_key(style_id))
+        .map(|&style_id| VvmProblem::UnmappedStyle { style_id });
+    let unknown = style_id_to_model_inner_id
+        .keys()
+        .filter(|style_id| !style_ids.contains(style_id))
+        .map(|&style_id| VvmProblem::UnknownStyle { style_id });
+    unmapped.chain(unknown).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::StyleId;
+
+    use super::VvmProblem;
+
+    #[rstest]
+    fn inspect_works() {
+        let model = crate::blocking::VoiceModel::from_path(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/test_data/model_sources/load_model_works1",
+        ))
+        .unwrap();
+
+        let report = super::inspect(&model, false);
+
+        assert_eq!(
+            [(0, 0), (1, 1), (302, 2), (303, 3)],
+            *report
+                .model_inner_ids
+                .iter()
+                .map(|(style_id, &inner_id)| (style_id.raw_id(), inner_id))
+                .collect::<Vec<_>>(),
+        );
+        // テスト用のVVMは`style_id_to_model_inner_id`が一部のスタイルしか含まず、また`decode.onnx`を
+        // 含まない
+        let unmapped = report
+            .problems
+            .iter()
+            .filter_map(|problem| match problem {
+                VvmProblem::UnmappedStyle { style_id } => Some(*style_id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!([StyleId::new(0), StyleId::new(1)], *unmapped);
+        assert_eq!(None, report.signer);
+        assert!(report.problems.iter().any(|problem| matches!(
+            problem,
+            VvmProblem::UnreadableFile { filename, .. } if filename == "decode.onnx",
+        )));
+    }
+}
@@ -0,0 +1,408 @@
+//! VVMの作成。
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    io::Write as _,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, ensure, Context as _};
+use ed25519_dalek::SigningKey;
+use sha2::{Digest as _, Sha256};
+use tempfile::NamedTempFile;
+use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+
+use crate::{
+    manifest::{
+        Manifest, ManifestDomains, ManifestVersion, ModelInnerId, RawModelInnerId, TalkManifest,
+    },
+    speaker_info,
+    voice_model::unpacked_entry_path,
+    vvm_check::{self, check_style_id_mapping},
+    vvm_signature::{VvmSignature, SIGNATURE_FILENAME},
+    RawVoiceModelId, SpeakerMeta, StyleId, VoiceModelMeta,
+};
+
+const MANIFEST_FILENAME: &str = "manifest.json";
+const METAS_FILENAME: &str = "metas.json";
+const MODEL_FILENAMES: [&str; 3] = [
+    "predict_duration.onnx",
+    "predict_intonation.onnx",
+    "decode.onnx",
+];
+
+/// [`blocking::VoiceModel::pack`]と[`tokio::VoiceModel::pack`]のオプション。
+///
+/// [`blocking::VoiceModel::pack`]: crate::blocking::VoiceModel::pack
+/// [`tokio::VoiceModel::pack`]: crate::tokio::VoiceModel::pack
+#[derive(Default)]
+pub struct VvmPackOptions {
+    /// マニフェストに書く音声モデルID。`None`の場合、すべてのエントリの内容から導出したIDを書く。
+    ///
+    /// マニフェストにIDの無いVVMの音声モデルIDはメタ情報のみから導出されるため、メタ情報が同じで
+    /// モデルが異なるVVMどうしが衝突しうる。[`pack`]で作ったVVMは常にIDを持つため、これは起きない。
+    ///
+    /// [`pack`]: crate::blocking::VoiceModel::pack
+    pub id: Option<RawVoiceModelId>,
+    /// `predict_duration`のモデルのONNXファイル。
+    pub predict_duration: Option<PathBuf>,
+    /// `predict_intonation`のモデルのONNXファイル。
+    pub predict_intonation: Option<PathBuf>,
+    /// `decode`のモデルのONNXファイル。
+    pub decode: Option<PathBuf>,
+    /// スタイルIDから、モデル内IDへの対応。空の場合はスタイルIDがそのままモデル内IDとなる。
+    pub style_id_to_model_inner_id: BTreeMap<StyleId, RawModelInnerId>,
+    /// 指定されている場合、この鍵でVVMに署名する。
+    pub signing_key: Option<SigningKey>,
+}
+
+/// ONNXファイルとメタ情報からVVMファイルを作る。
+///
+/// 書き込んだVVMは読み込み直して[`vvm_check::inspect`]で確かめ、問題が無かった場合にのみ`output`へ
+/// と移す。
+pub(crate) fn pack(metas: &Path, output: &Path, options: VvmPackOptions) -> anyhow::Result<()> {
+    let VvmPackOptions {
+        id,
+        predict_duration,
+        predict_intonation,
+        decode,
+        style_id_to_model_inner_id,
+        signing_key,
+    } = options;
+
+    let metas_dir = metas.parent().unwrap_or(Path::new(""));
+    let metas_json = fs_err::read(metas)?;
+    let metas = serde_json::from_slice::<VoiceModelMeta>(&metas_json)
+        .with_context(|| format!("`{}`はメタ情報として不正です", metas.display()))?;
+    validate_metas(&metas)?;
+
+    let style_id_to_model_inner_id = style_id_to_model_inner_id
+        .into_iter()
+        .map(|(style_id, model_inner_id)| (style_id, ModelInnerId::new(model_inner_id)))
+        .collect();
+    if let Some(problem) = check_style_id_mapping(&metas, &style_id_to_model_inner_id)
+        .into_iter()
+        .next()
+    {
+        return Err(problem.into());
+    }
+
+    let mut entries = BTreeMap::from([(METAS_FILENAME.to_owned(), metas_json)]);
+    for filename in speaker_info::resource_filenames(&metas) {
+        ensure!(
+            ![MANIFEST_FILENAME, METAS_FILENAME, SIGNATURE_FILENAME].contains(&filename)
+                && !MODEL_FILENAMES.contains(&filename),
+            "`{filename}`はVVM内のファイル名として予約されています",
+        );
+        let content = fs_err::read(unpacked_entry_path(metas_dir, filename)?)?;
+        entries.insert(filename.to_owned(), content);
+    }
+    let mut add_model = |model: Option<PathBuf>, filename: &str| -> anyhow::Result<_> {
+        let Some(model) = model else {
+            return Ok(None);
+        };
+        let content = fs_err::read(model)?;
+        entries.insert(filename.to_owned(), content);
+        Ok(Some(filename.to_owned()))
+    };
+    let [predict_duration_filename, predict_intonation_filename, decode_filename] = MODEL_FILENAMES;
+    let talk = TalkManifest::new(
+        add_model(predict_duration, predict_duration_filename)?,
+        add_model(predict_intonation, predict_intonation_filename)?,
+        add_model(decode, decode_filename)?,
+        style_id_to_model_inner_id,
+    );
+    ensure!(
+        talk.predict_duration_filename().is_some()
+            || talk.predict_intonation_filename().is_some()
+            || talk.decode_filename().is_some(),
+        "モデルが一つも指定されていません",
+    );
+
+    let new_manifest = |id| {
+        let manifest = Manifest::new(
+            ManifestVersion::CURRENT,
+            id,
+            METAS_FILENAME.to_owned(),
+            ManifestDomains::new(Some(talk.clone())),
+        );
+        serde_json::to_vec_pretty(&manifest)
+    };
+    let id = match id {
+        Some(id) => id,
+        None => {
+            entries.insert(MANIFEST_FILENAME.to_owned(), new_manifest(None)?);
+            derive_id(&entries)
+        }
+    };
+    entries.insert(MANIFEST_FILENAME.to_owned(), new_manifest(Some(id))?);
+
+    if let Some(signing_key) = signing_key {
+        let signature = VvmSignature::sign(
+            &signing_key,
+            entries
+                .iter()
+                .map(|(filename, content)| (&**filename, &**content)),
+        );
+        entries.insert(
+            SIGNATURE_FILENAME.to_owned(),
+            serde_json::to_vec_pretty(&signature)?,
+        );
+    }
+
+    let temp = write_zip(output, &entries)
+        .with_context(|| format!("`{}`に書き込めませんでした", output.display()))?;
+
+    {
+        let model = crate::blocking::VoiceModel::from_path(temp.path())?;
+        if let Some(problem) = vvm_check::inspect(&model, false)
+            .problems
+            .into_iter()
+            .next()
+        {
+            return Err(anyhow::Error::from(problem).context("作成したVVMに問題があります"));
+        }
+    }
+
+    temp.persist(output)
+        .with_context(|| format!("`{}`に書き込めませんでした", output.display()))?;
+    Ok(())
+}
+
+fn validate_metas(metas: &VoiceModelMeta) -> anyhow::Result<()> {
+    ensure!(!metas.is_empty(), "メタ情報に話者がいません");
+
+    let mut style_ids = HashSet::new();
+    for speaker in metas {
+        ensure!(
+            !speaker.styles().is_empty(),
+            "話者`{}`にスタイルがありません",
+            speaker.name(),
+        );
+        for style_id in speaker.styles().iter().map(|style| *style.id()) {
+            if !style_ids.insert(style_id) {
+                bail!("スタイル`{style_id}`が重複しています");
+            }
+        }
+    }
+
+    let mut speaker_uuids = HashSet::new();
+    for speaker_uuid in metas.iter().map(SpeakerMeta::speaker_uuid) {
+        ensure!(
+            speaker_uuids.insert(speaker_uuid),
+            "話者`{speaker_uuid}`が重複しています",
+        );
+    }
+    Ok(())
+}
+
+/// IDの無いマニフェストを含むすべてのエントリから、音声モデルIDを導出する。
+///
+/// エントリの名前とSHA-256ハッシュ値を名前順に`sha256sum`と同じ形式で並べたものの、SHA-256ハッシュ
+/// 値とする。
+fn derive_id(entries: &BTreeMap<String, Vec<u8>>) -> RawVoiceModelId {
+    let mut hasher = Sha256::new();
+    for (filename, content) in entries {
+        hasher.update(format!(
+            "{}  {filename}\n",
+            hex::encode(Sha256::digest(content))
+        ));
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// `output`と同じディレクトリの一時ファイルにZIPを書き込む。
+fn write_zip(output: &Path, entries: &BTreeMap<String, Vec<u8>>) -> anyhow::Result<NamedTempFile> {
+    let dir = match output.parent() {
+        Some(dir) if dir != Path::new("") => dir,
+        _ => Path::new("."),
+    };
+    let mut temp = tempfile::Builder::new()
+        .prefix(".")
+        .suffix(".vvm")
+        .tempfile_in(dir)?;
+
+    {
+        let mut zip = ZipWriter::new(temp.as_file_mut());
+        for (filename, content) in entries {
+            zip.start_file(
+                filename,
+                FileOptions::default().compression_method(CompressionMethod::Stored),
+            )?;
+            zip.write_all(content)?;
+        }
+        zip.finish()?;
+    }
+    temp.as_file().sync_all()?;
+    Ok(temp)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ed25519_dalek::SigningKey;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::{
+        vvm_signature::{VvmSignature, SIGNATURE_FILENAME},
+        StyleId,
+    };
+
+    use super::VvmPackOptions;
+
+    const MODEL_SOURCE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/src/test_data/model_sources/load_model_works1",
+    );
+
+    #[rstest]
+    #[case(false)]
+    #[case(true)]
+    fn pack_works(#[case] sign: bool) {
+        let output = tempfile::tempdir().unwrap();
+        let output = &output.path().join("packed.vvm");
+
+        let model = crate::blocking::VoiceModel::pack(
+            format!("{MODEL_SOURCE}/metas.json"),
+            output,
+            VvmPackOptions {
+                id: Some("packed".to_owned()),
+                predict_duration: Some(format!("{MODEL_SOURCE}/predict_duration.onnx").into()),
+                predict_intonation: Some(format!("{MODEL_SOURCE}/predict_intonation.onnx").into()),
+                style_id_to_model_inner_id: BTreeMap::from([
+                    (StyleId::new(0), 0),
+                    (StyleId::new(1), 1),
+                    (StyleId::new(302), 2),
+                    (StyleId::new(303), 3),
+                ]),
+                signing_key: sign.then(|| SigningKey::from_bytes(&[1; 32])),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!("packed", model.id().raw_voice_model_id());
+        assert_eq!(4, model.header().model_inner_ids().len());
+
+        let mut archive = zip::ZipArchive::new(fs_err::File::open(output).unwrap()).unwrap();
+        let signature = archive
+            .by_name(SIGNATURE_FILENAME)
+            .ok()
+            .map(|entry| serde_json::from_reader::<_, VvmSignature>(entry).unwrap());
+        assert_eq!(sign, signature.is_some());
+        assert_eq!(
+            signature.map(|signature| signature.public_key),
+            crate::__internal::vvm::inspect(&model, false).signer,
+        );
+    }
+
+    #[rstest]
+    fn pack_derives_distinct_ids_from_models() {
+        let dir = tempfile::tempdir().unwrap();
+        let style_id_to_model_inner_id = BTreeMap::from([
+            (StyleId::new(0), 0),
+            (StyleId::new(1), 1),
+            (StyleId::new(302), 2),
+            (StyleId::new(303), 3),
+        ]);
+
+        let ids = [
+            VvmPackOptions {
+                predict_duration: Some(format!("{MODEL_SOURCE}/predict_duration.onnx").into()),
+                style_id_to_model_inner_id: style_id_to_model_inner_id.clone(),
+                ..Default::default()
+            },
+            VvmPackOptions {
+                predict_duration: Some(format!("{MODEL_SOURCE}/predict_duration.onnx").into()),
+                predict_intonation: Some(format!("{MODEL_SOURCE}/predict_intonation.onnx").into()),
+                style_id_to_model_inner_id,
+                ..Default::default()
+            },
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, options)| {
+            let output = dir.path().join(format!("{i}.vvm"));
+            let model = crate::blocking::VoiceModel::pack(
+                format!("{MODEL_SOURCE}/metas.json"),
+                &output,
+                options,
+            )
+            .unwrap();
+            // マニフェストにIDが書かれている
+            let mut archive = zip::ZipArchive::new(fs_err::File::open(output).unwrap()).unwrap();
+            let manifest = serde_json::from_reader::<_, serde_json::Value>(
+                archive.by_name("manifest.json").unwrap(),
+            )
+            .unwrap();
+            assert_eq!(model.id().raw_voice_model_id(), &manifest["id"]);
+            model.id().clone()
+        })
+        .collect::<Vec<_>>();
+
+        assert_ne!(ids[0], ids[1]);
+    }
+
+    #[rstest]
+    fn pack_rejects_unknown_style() {
+        let output = tempfile::tempdir().unwrap();
+
+        let err = crate::blocking::VoiceModel::pack(
+            format!("{MODEL_SOURCE}/metas.json"),
+            output.path().join("packed.vvm"),
+            VvmPackOptions {
+                predict_duration: Some(format!("{MODEL_SOURCE}/predict_duration.onnx").into()),
+                style_id_to_model_inner_id: BTreeMap::from([(StyleId::new(10), 0)]),
+                ..Default::default()
+            },
+        )
+        .map(drop)
+        .unwrap_err();
+
+        assert_eq!(crate::ErrorKind::PackVvm, err.kind());
+        assert!(std::error::Error::source(&err)
+            .unwrap()
+            .is::<crate::__internal::vvm::VvmProblem>());
+        assert!(!output.path().join("packed.vvm").exists());
+    }
+
+    #[rstest]
+    #[case(true)]
+    #[case(false)]
+    fn pack_replaces_output_only_on_success(#[case] valid: bool) {
+        let dir = tempfile::tempdir().unwrap();
+        let output = &dir.path().join("existing.vvm");
+        fs_err::write(output, "existing").unwrap();
+
+        let mut style_id_to_model_inner_id = BTreeMap::from([
+            (StyleId::new(0), 0),
+            (StyleId::new(1), 1),
+            (StyleId::new(302), 2),
+            (StyleId::new(303), 3),
+        ]);
+        if !valid {
+            style_id_to_model_inner_id.insert(StyleId::new(10), 4);
+        }
+
+        let result = crate::blocking::VoiceModel::pack(
+            format!("{MODEL_SOURCE}/metas.json"),
+            output,
+            VvmPackOptions {
+                predict_duration: Some(format!("{MODEL_SOURCE}/predict_duration.onnx").into()),
+                style_id_to_model_inner_id,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(valid, result.is_ok());
+        assert_eq!(!valid, fs_err::read(output).unwrap() == b"existing");
+        // 一時ファイルは残らない
+        let files = fs_err::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(["existing.vvm"], *files);
+    }
+}
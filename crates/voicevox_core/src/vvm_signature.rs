@@ -0,0 +1,236 @@
+//! VVMの署名。
+//!
+//! 署名は[`SIGNATURE_FILENAME`]というエントリとしてVVMに同梱される。署名は、そのエントリを除く
+//! すべてのエントリの名前とSHA-256ハッシュ値を、名前順に`sha256sum`と同じ形式で並べたものに対して
+//! 行われる。ハッシュ値の一覧も署名とともに格納されるため、署名の検証にはエントリを読む必要が無く、
+//! それぞれのエントリはそれが読まれるときにハッシュ値と照合される。
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use anyhow::{anyhow, ensure, Context as _};
+use ed25519_dalek::{
+    Signature, Signer as _, SigningKey, VerifyingKey, PUBLIC_KEY_LENGTH, SIGNATURE_LENGTH,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+
+use crate::SignaturePolicy;
+
+/// 署名のエントリのファイル名。
+pub(crate) const SIGNATURE_FILENAME: &str = "signature.json";
+
+/// Ed25519の公開鍵。
+pub(crate) type PublicKey = [u8; PUBLIC_KEY_LENGTH];
+
+/// `signature.json`の形式。
+#[derive(Deserialize, Serialize)]
+pub(crate) struct VvmSignature {
+    /// 署名者の公開鍵。
+    #[serde(with = "hex::serde")]
+    pub(crate) public_key: PublicKey,
+    /// 署名の対象となる、エントリごとのハッシュ値。
+    pub(crate) entries: BTreeMap<String, EntryHash>,
+    /// Ed25519による署名。
+    #[serde(with = "hex::serde")]
+    pub(crate) signature: [u8; SIGNATURE_LENGTH],
+}
+
+/// エントリのSHA-256ハッシュ値。
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct EntryHash(#[serde(with = "hex::serde")] [u8; 32]);
+
+impl EntryHash {
+    fn of(content: &[u8]) -> Self {
+        Self(Sha256::digest(content).into())
+    }
+}
+
+impl VvmSignature {
+    /// エントリの集まりに署名する。`entries`に[`SIGNATURE_FILENAME`]が含まれていた場合、それは
+    /// 無視される。
+    pub(crate) fn sign<'a>(
+        key: &SigningKey,
+        entries: impl IntoIterator<Item = (&'a str, &'a [u8])>,
+    ) -> Self {
+        let entries = entries
+            .into_iter()
+            .filter(|&(filename, _)| filename != SIGNATURE_FILENAME)
+            .map(|(filename, content)| (filename.to_owned(), EntryHash::of(content)))
+            .collect();
+        Self {
+            public_key: key.verifying_key().to_bytes(),
+            signature: key.sign(&signed_message(&entries)).to_bytes(),
+            entries,
+        }
+    }
+}
+
+/// 署名の対象となるバイト列を作る。
+fn signed_message(entries: &BTreeMap<String, EntryHash>) -> Vec<u8> {
+    entries
+        .iter()
+        .flat_map(|(filename, EntryHash(hash))| {
+            format!("{}  {filename}\n", hex::encode(hash)).into_bytes()
+        })
+        .collect()
+}
+
+/// VVMの署名の状態。
+#[derive(Clone)]
+pub(crate) enum SignatureStatus {
+    /// 署名が無い。
+    Unsigned,
+    /// 検証された署名がある。
+    Signed {
+        public_key: PublicKey,
+        entries: Arc<BTreeMap<String, EntryHash>>,
+    },
+}
+
+impl SignatureStatus {
+    /// `signature.json`の中身から署名を検証する。
+    ///
+    /// ここではハッシュ値の一覧に対する署名のみを検証する。それぞれのエントリは
+    /// [`verify_entry`]で照合する必要がある。
+    ///
+    /// [`verify_entry`]: Self::verify_entry
+    pub(crate) fn verify(signature_json: Option<&[u8]>) -> anyhow::Result<Self> {
+        let Some(signature_json) = signature_json else {
+            return Ok(Self::Unsigned);
+        };
+        let VvmSignature {
+            public_key,
+            entries,
+            signature,
+        } = serde_json::from_slice(signature_json)
+            .with_context(|| format!("`{SIGNATURE_FILENAME}`が不正です"))?;
+        VerifyingKey::from_bytes(&public_key)?
+            .verify_strict(
+                &signed_message(&entries),
+                &Signature::from_bytes(&signature),
+            )
+            .context("署名が不正です")?;
+        Ok(Self::Signed {
+            public_key,
+            entries: entries.into(),
+        })
+    }
+
+    /// エントリの中身が、署名されたものであるかを確かめる。署名が無い場合は何もしない。
+    pub(crate) fn verify_entry(&self, filename: &str, content: &[u8]) -> anyhow::Result<()> {
+        let Self::Signed { entries, .. } = self else {
+            return Ok(());
+        };
+        let hash = entries
+            .get(filename)
+            .ok_or_else(|| anyhow!("`{filename}`は署名されていません"))?;
+        ensure!(
+            *hash == EntryHash::of(content),
+            "`{filename}`の内容が署名されたものと異なります",
+        );
+        Ok(())
+    }
+
+    /// 署名者の公開鍵。
+    pub(crate) fn public_key(&self) -> Option<&PublicKey> {
+        match self {
+            Self::Unsigned => None,
+            Self::Signed { public_key, .. } => Some(public_key),
+        }
+    }
+}
+
+/// 音声モデルの署名者を信頼するかどうかを決める。
+#[derive(Default, Clone)]
+pub(crate) struct SignatureTrust {
+    pub(crate) policy: SignaturePolicy,
+    pub(crate) trusted_public_keys: Vec<PublicKey>,
+}
+
+impl SignatureTrust {
+    /// 署名の状態が、方針の上で受け入れられるものかを確かめる。
+    pub(crate) fn ensure_trusted(&self, signature: &SignatureStatus) -> anyhow::Result<()> {
+        if self.policy == SignaturePolicy::Permissive {
+            return Ok(());
+        }
+        let public_key = signature
+            .public_key()
+            .ok_or_else(|| anyhow!("署名がありません"))?;
+        ensure!(
+            self.trusted_public_keys.contains(public_key),
+            "署名者`{}`は信頼されていません",
+            hex::encode(public_key),
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::SigningKey;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::SignaturePolicy;
+
+    use super::{SignatureStatus, SignatureTrust, VvmSignature, SIGNATURE_FILENAME};
+
+    const ENTRIES: [(&str, &[u8]); 3] = [
+        ("metas.json", b"[]"),
+        ("manifest.json", b"{}"),
+        (SIGNATURE_FILENAME, b"ignored"),
+    ];
+
+    #[test]
+    fn signed_message_works() {
+        let signature = VvmSignature::sign(&SigningKey::from_bytes(&[1; 32]), ENTRIES);
+        assert_eq!(
+            "44136fa355b3678a1146ad16f7e8649e94fb4fc21fe77e8310c060f61caaff8a  manifest.json\n\
+             4f53cda18c2baa0c0354bb5f9a3ecbe5ed12ab4d8e11ba873c2f11161202b945  metas.json\n",
+            String::from_utf8(super::signed_message(&signature.entries)).unwrap(),
+        );
+    }
+
+    #[rstest]
+    #[case("metas.json", b"[]", true)]
+    #[case("metas.json", b"[{}]", false)]
+    #[case("decode.onnx", b"", false)]
+    fn verify_entry_works(#[case] filename: &str, #[case] content: &[u8], #[case] ok: bool) {
+        let signature = VvmSignature::sign(&SigningKey::from_bytes(&[1; 32]), ENTRIES);
+        let status =
+            SignatureStatus::verify(Some(&serde_json::to_vec(&signature).unwrap())).unwrap();
+        assert_eq!(ok, status.verify_entry(filename, content).is_ok());
+    }
+
+    #[rstest]
+    fn verify_rejects_tampered_signature() {
+        let mut signature = VvmSignature::sign(&SigningKey::from_bytes(&[1; 32]), ENTRIES);
+        signature.entries.remove("metas.json");
+        let signature_json = serde_json::to_vec(&signature).unwrap();
+        assert!(SignatureStatus::verify(Some(&signature_json)).is_err());
+    }
+
+    #[rstest]
+    #[case(SignaturePolicy::Permissive, None, true)]
+    #[case(SignaturePolicy::Strict, None, false)]
+    #[case(SignaturePolicy::Strict, Some([1; 32]), true)]
+    #[case(SignaturePolicy::Strict, Some([2; 32]), false)]
+    fn ensure_trusted_works(
+        #[case] policy: SignaturePolicy,
+        #[case] signing_key: Option<[u8; 32]>,
+        #[case] ok: bool,
+    ) {
+        let trust = SignatureTrust {
+            policy,
+            trusted_public_keys: vec![SigningKey::from_bytes(&[1; 32]).verifying_key().to_bytes()],
+        };
+        let status = match signing_key {
+            Some(signing_key) => {
+                let signature = VvmSignature::sign(&SigningKey::from_bytes(&signing_key), ENTRIES);
+                SignatureStatus::verify(Some(&serde_json::to_vec(&signature).unwrap())).unwrap()
+            }
+            None => SignatureStatus::Unsigned,
+        };
+        assert_eq!(ok, trust.ensure_trusted(&status).is_ok());
+    }
+}
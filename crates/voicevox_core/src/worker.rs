@@ -0,0 +1,975 @@
+//! テキスト解析と推論を子プロセス(ワーカープロセス)の中で行う仕組み。
+//!
+//! ワーカープロセスとは標準入出力で通信する。要求と応答はそれぞれbincodeでエンコードされ、一つの
+//! ワーカープロセスとは一つずつやりとりされる。
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    env,
+    io::{self, BufReader, BufWriter, Read, Write},
+    mem,
+    num::NonZeroUsize,
+    path::PathBuf,
+    process::{self, Child, ChildStdin, ChildStdout, Command, Stdio},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex, MutexGuard, PoisonError, TryLockError,
+    },
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Context as _};
+use camino::{Utf8Path, Utf8PathBuf};
+use duplicate::duplicate_item;
+use ndarray::{Array, ArrayD, Dimension};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{error, info, warn};
+
+use crate::{
+    blocking::OpenJtalk,
+    engine::OpenJtalkSpec,
+    infer::{
+        InferenceRuntime, InferenceSessionOptions, InputScalarKind, OutputScalarKind, OutputTensor,
+        ParamInfo, PushInputTensor,
+    },
+    synthesizer::LocalInferenceRuntimeImpl,
+    FullcontextExtractor,
+};
+
+/// ワーカープロセスとして起動されたことを示す環境変数。
+const WORKER_ENV_VAR: &str = "VOICEVOX_CORE_WORKER";
+
+/// 一つの要求に対する応答を待つ時間の上限。これを過ぎた子プロセスはハングしたとみなし、終了させる。
+const CALL_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// このプロセスがワーカープロセスとして起動されたのであれば、ワーカープロセスとして動作し、
+/// 親プロセスとの通信が終わった時点でプロセスを終了する。そうでなければ何もしない。
+///
+/// [`InitializeOptions::out_of_process`]を使う場合、ワーカープロセスとして起動されるプログラムは
+/// `main`関数の最初でこの関数を呼ばなければならない。ワーカープロセスは標準出力を通信に使うため、
+/// それまでに標準出力に何かを書き込んではならない。
+///
+/// [`InitializeOptions::out_of_process`]: crate::InitializeOptions::out_of_process
+pub fn run_worker_if_requested() {
+    if env::var_os(WORKER_ENV_VAR).is_none() {
+        return;
+    }
+    let result = serve::<LocalInferenceRuntimeImpl>(io::stdin().lock(), io::stdout().lock());
+    if let Err(err) = &result {
+        error!("ワーカープロセスが異常終了します: {err:?}");
+    }
+    process::exit(if result.is_ok() { 0 } else { 1 });
+}
+
+/// ワーカープロセスが異常終了したか、通信が途絶えたか、応答が期限までに無かった。
+#[derive(Error, Debug)]
+#[error("ワーカープロセスが異常終了しました ({status})")]
+pub(crate) struct WorkerCrashed {
+    status: String,
+    #[source]
+    source: bincode::Error,
+}
+
+/// `Session`の入出力の情報。
+type ParamInfos = (
+    Vec<ParamInfo<InputScalarKind>>,
+    Vec<ParamInfo<OutputScalarKind>>,
+);
+
+/// ワーカープロセス。
+///
+/// 複数の子プロセスから成る。`RemoteSession`はいずれか一つの子プロセスに順番に作られ、異なる子
+/// プロセスへの要求は並列に処理される。テキスト解析は空いている子プロセスで行われる。
+///
+/// 子プロセスはそれぞれ最初の要求の際に起動される。異常終了した場合や応答が期限までに無かった場合
+/// は終了させられ、次の要求の際に起動し直される。起動し直された子プロセスには`Session`が無いため、
+/// `RemoteSession`はそれぞれ次の推論の際に作り直される。
+///
+/// `RemoteSession`の破棄は子プロセスを待たずに行われ、子プロセスの中の`Session`は、その子プロセス
+/// への次の要求の際に破棄される。
+pub(crate) struct Worker {
+    /// `None`の場合は現在の実行ファイル自身。
+    program: Option<PathBuf>,
+    /// 一つの要求に対する応答を待つ時間の上限。
+    timeout: Duration,
+    processes: Vec<WorkerSlot>,
+    /// 次に`RemoteSession`を作る子プロセスを選ぶためのカウンタ。
+    next_process: AtomicUsize,
+    next_session_id: AtomicU64,
+}
+
+/// 一つの子プロセスについての枠。
+#[derive(Default)]
+struct WorkerSlot {
+    state: Mutex<WorkerState>,
+    /// 破棄された`RemoteSession`のうち、子プロセスの中の`Session`をまだ破棄させていないものの、
+    /// IDと[`WorkerState::num_spawns`]の組。
+    ///
+    /// `state`のロックを待たずに積めるよう、別にロックする。
+    dropped_sessions: Mutex<Vec<(u64, u64)>>,
+}
+
+/// 一つの子プロセスの状態。
+#[derive(Default)]
+struct WorkerState {
+    process: Option<WorkerProcess>,
+    /// これまでに起動した子プロセスの数。`RemoteSession`がどの子プロセスに作られたかを示すのに
+    /// 使う。
+    num_spawns: u64,
+    /// 今の子プロセスに読み込ませた、辞書ディレクトリごとのユーザー辞書の番号。
+    user_dicts: HashMap<Utf8PathBuf, u64>,
+}
+
+impl WorkerSlot {
+    /// 状態をロックする。
+    ///
+    /// 他のスレッドがロックしたままパニックしていた場合、子プロセスとの通信が途中で止まっている
+    /// おそれがあるため、子プロセスを終了させた上でロックを取り戻す。子プロセスは次の要求の際に
+    /// 起動し直される。
+    fn lock(&self) -> MutexGuard<'_, WorkerState> {
+        self.state.lock().unwrap_or_else(|err| self.recover(err))
+    }
+
+    /// 状態のロックを試みる。他のスレッドがロックしていれば`None`を返す。
+    fn try_lock(&self) -> Option<MutexGuard<'_, WorkerState>> {
+        match self.state.try_lock() {
+            Ok(state) => Some(state),
+            Err(TryLockError::WouldBlock) => None,
+            Err(TryLockError::Poisoned(err)) => Some(self.recover(err)),
+        }
+    }
+
+    fn recover<'a>(
+        &'a self,
+        err: PoisonError<MutexGuard<'a, WorkerState>>,
+    ) -> MutexGuard<'a, WorkerState> {
+        let mut state = err.into_inner();
+        self.state.clear_poison();
+        if let Some(process) = state.process.take() {
+            let status = process.terminate();
+            warn!("ワーカープロセスとの通信中にパニックしたため、終了させました: {status}");
+        }
+        state
+    }
+
+    fn dropped_sessions(&self) -> MutexGuard<'_, Vec<(u64, u64)>> {
+        self.dropped_sessions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Worker {
+    /// `num_processes`個までの子プロセスから成るワーカープロセスを作る。
+    pub(crate) fn new(program: Option<PathBuf>, num_processes: NonZeroUsize) -> Self {
+        Self::with_timeout(program, num_processes, CALL_TIMEOUT)
+    }
+
+    fn with_timeout(
+        program: Option<PathBuf>,
+        num_processes: NonZeroUsize,
+        timeout: Duration,
+    ) -> Self {
+        Self {
+            program,
+            timeout,
+            processes: (0..num_processes.get())
+                .map(|_| Default::default())
+                .collect(),
+            next_process: AtomicUsize::new(0),
+            next_session_id: AtomicU64::new(0),
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn new_session(
+        self: &Arc<Self>,
+        model: Vec<u8>,
+        options: &InferenceSessionOptions,
+    ) -> anyhow::Result<(
+        RemoteSession,
+        Vec<ParamInfo<InputScalarKind>>,
+        Vec<ParamInfo<OutputScalarKind>>,
+    )> {
+        let process = self.next_process.fetch_add(1, Ordering::Relaxed) % self.processes.len();
+        let id = self.next_session_id.fetch_add(1, Ordering::Relaxed);
+        let state = &mut *self.processes[process].lock();
+        let options = SessionOptions::from(options);
+
+        let (input_param_infos, output_param_infos) =
+            self.create_session(process, state, id, &model, options)?;
+
+        let sess = RemoteSession {
+            worker: self.clone(),
+            process,
+            id,
+            model,
+            options,
+            generation: state.num_spawns,
+        };
+        Ok((sess, input_param_infos, output_param_infos))
+    }
+
+    fn run(&self, sess: &mut RemoteSession, inputs: Vec<Tensor>) -> anyhow::Result<Vec<Tensor>> {
+        let state = &mut *self.processes[sess.process].lock();
+        self.spawn_if_needed(state)?;
+
+        if sess.generation != state.num_spawns {
+            info!("ワーカープロセスが起動し直されたため、`Session`を作り直します");
+            self.create_session(sess.process, state, sess.id, &sess.model, sess.options)?;
+            sess.generation = state.num_spawns;
+        }
+
+        self.call(
+            sess.process,
+            state,
+            &Request::Run {
+                id: sess.id,
+                inputs,
+            },
+        )
+    }
+
+    fn create_session(
+        &self,
+        process: usize,
+        state: &mut WorkerState,
+        id: u64,
+        model: &[u8],
+        options: SessionOptions,
+    ) -> anyhow::Result<ParamInfos> {
+        self.call(
+            process,
+            state,
+            &Request::NewSession {
+                id,
+                model: model.into(),
+                options,
+            },
+        )
+    }
+
+    /// `RemoteSession`の破棄を記録する。子プロセスの中の`Session`は、その子プロセスへの次の要求の
+    /// 際に破棄させる。
+    fn drop_session(&self, process: usize, id: u64, generation: u64) {
+        self.processes[process]
+            .dropped_sessions()
+            .push((id, generation));
+    }
+
+    pub(crate) fn extract_fullcontext(
+        &self,
+        open_jtalk: &OpenJtalkSpec,
+        text: &str,
+    ) -> anyhow::Result<Vec<String>> {
+        let (process, mut state) = self.lock_any_process();
+        let state = &mut *state;
+        self.use_user_dict_in(process, state, open_jtalk)?;
+        self.call(
+            process,
+            state,
+            &Request::ExtractFullcontext {
+                dict_dir: Cow::Borrowed(open_jtalk.dict_dir()),
+                user_dict: open_jtalk.user_dict_generation(),
+                text: text.into(),
+            },
+        )
+    }
+
+    /// ユーザー辞書をコンパイルし、読み込ませる。
+    ///
+    /// 起動している子プロセスと、コンパイルできるかを確かめるために最初の子プロセスで行う。起動して
+    /// いない子プロセスでは、起動後のテキスト解析の際に行われる。
+    pub(crate) fn use_user_dict(&self, open_jtalk: &OpenJtalkSpec) -> anyhow::Result<()> {
+        for (process, slot) in self.processes.iter().enumerate() {
+            let state = &mut *slot.lock();
+            if process == 0 || state.process.is_some() {
+                self.use_user_dict_in(process, state, open_jtalk)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 子プロセスに`open_jtalk`のユーザー辞書が読み込まれていなければ、読み込ませる。
+    ///
+    /// ユーザー辞書の内容を送るのはこのときのみであり、テキスト解析の要求ではその番号のみを送る。
+    fn use_user_dict_in(
+        &self,
+        process: usize,
+        state: &mut WorkerState,
+        open_jtalk: &OpenJtalkSpec,
+    ) -> anyhow::Result<()> {
+        self.spawn_if_needed(state)?;
+        let generation = open_jtalk.user_dict_generation();
+        if state.user_dicts.get(open_jtalk.dict_dir()) == Some(&generation) {
+            return Ok(());
+        }
+        self.call::<()>(
+            process,
+            state,
+            &Request::UseUserDict {
+                open_jtalk: Cow::Borrowed(open_jtalk),
+            },
+        )?;
+        state
+            .user_dicts
+            .insert(open_jtalk.dict_dir().to_owned(), generation);
+        Ok(())
+    }
+
+    /// 空いている子プロセスをロックし、その位置とともに返す。すべて使われていれば、いずれかが
+    /// 空くのを待つ。
+    fn lock_any_process(&self) -> (usize, MutexGuard<'_, WorkerState>) {
+        for (process, slot) in self.processes.iter().enumerate() {
+            if let Some(state) = slot.try_lock() {
+                return (process, state);
+            }
+        }
+        let process = self.next_process.fetch_add(1, Ordering::Relaxed) % self.processes.len();
+        (process, self.processes[process].lock())
+    }
+
+    /// 要求を送り、応答を受け取る。通信に失敗した場合や応答が期限までに無かった場合は子プロセスを
+    /// 終了させ、[`WorkerCrashed`]を返す。
+    ///
+    /// 破棄された`RemoteSession`があれば、先にその`Session`を破棄させる。
+    fn call<T: DeserializeOwned>(
+        &self,
+        process: usize,
+        state: &mut WorkerState,
+        request: &Request<'_>,
+    ) -> anyhow::Result<T> {
+        self.spawn_if_needed(state)?;
+
+        let dropped_sessions = mem::take(&mut *self.processes[process].dropped_sessions());
+        let ids = dropped_sessions
+            .into_iter()
+            .filter(|&(_, generation)| generation == state.num_spawns)
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>();
+        if !ids.is_empty() {
+            self.exchange::<()>(state, &Request::DropSessions { ids })?;
+        }
+
+        self.exchange(state, request)
+    }
+
+    fn exchange<T: DeserializeOwned>(
+        &self,
+        state: &mut WorkerState,
+        request: &Request<'_>,
+    ) -> anyhow::Result<T> {
+        let process = state.process.as_mut().expect("should be spawned");
+        match process.exchange(request, self.timeout) {
+            Ok(response) => response.map_err(|message| anyhow!(message)),
+            Err(source) => {
+                let process = state.process.take().expect("should be spawned");
+                let timed_out = process.watchdog.expired.load(Ordering::Relaxed);
+                let status = process.terminate();
+                let status = if timed_out {
+                    format!(
+                        "{}秒以内に応答が無かったため終了させました: {status}",
+                        self.timeout.as_secs_f32(),
+                    )
+                } else {
+                    status
+                };
+                let err = WorkerCrashed { status, source };
+                error!("{err}");
+                Err(err.into())
+            }
+        }
+    }
+
+    fn spawn_if_needed<'a>(
+        &self,
+        state: &'a mut WorkerState,
+    ) -> anyhow::Result<&'a mut WorkerProcess> {
+        if state.process.is_none() {
+            let program = match &self.program {
+                Some(program) => program.clone(),
+                None => {
+                    env::current_exe().context("現在の実行ファイルのパスを取得できませんでした")?
+                }
+            };
+            let process = WorkerProcess::spawn(&program)?;
+            info!("ワーカープロセスを起動しました (pid: {})", process.pid());
+            state.process = Some(process);
+            state.num_spawns += 1;
+            state.user_dicts.clear();
+        }
+        Ok(state.process.as_mut().expect("should be spawned"))
+    }
+}
+
+// `InferenceSessionOptions`の比較のため、同一のワーカープロセスであるかどうかで比較する
+impl PartialEq for Worker {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl std::fmt::Debug for Worker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Worker")
+            .field("program", &self.program)
+            .finish_non_exhaustive()
+    }
+}
+
+struct WorkerProcess {
+    child: Arc<Mutex<Child>>,
+    stdin: BufWriter<ChildStdin>,
+    stdout: BufReader<ChildStdout>,
+    watchdog: Watchdog,
+}
+
+impl WorkerProcess {
+    fn spawn(program: &std::path::Path) -> anyhow::Result<Self> {
+        let mut child = Command::new(program)
+            .env(WORKER_ENV_VAR, "1")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .with_context(|| {
+                format!(
+                    "ワーカープロセス`{}`を起動できませんでした",
+                    program.display()
+                )
+            })?;
+        let stdin = child.stdin.take().expect("should be piped");
+        let stdout = child.stdout.take().expect("should be piped");
+        let child = Arc::new(Mutex::new(child));
+        let watchdog = Watchdog::spawn(child.clone())
+            .context("ワーカープロセスを監視するスレッドを起動できませんでした")?;
+        Ok(Self {
+            child,
+            stdin: BufWriter::new(stdin),
+            stdout: BufReader::new(stdout),
+            watchdog,
+        })
+    }
+
+    fn pid(&self) -> u32 {
+        self.child
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .id()
+    }
+
+    /// 要求を送り、応答を受け取る。`timeout`以内に応答が無ければ、子プロセスは終了させられる。
+    fn exchange<T: DeserializeOwned>(
+        &mut self,
+        request: &Request<'_>,
+        timeout: Duration,
+    ) -> bincode::Result<Result<T, String>> {
+        self.watchdog.arm(timeout);
+        let response = (|| {
+            bincode::serialize_into(&mut self.stdin, request)?;
+            self.stdin.flush()?;
+            bincode::deserialize_from(&mut self.stdout)
+        })();
+        self.watchdog.disarm();
+        response
+    }
+
+    /// 子プロセスを終了させ、その終了状態を返す。
+    fn terminate(self) -> String {
+        let child = &mut *self.child.lock().unwrap_or_else(PoisonError::into_inner);
+        // 既に終了している場合は、`kill`は失敗して`wait`は元の終了状態を返す
+        let _ = child.kill();
+        match child.wait() {
+            Ok(status) => status.to_string(),
+            Err(err) => format!("終了状態を取得できませんでした: {err}"),
+        }
+    }
+}
+
+impl Drop for WorkerProcess {
+    fn drop(&mut self) {
+        let Ok(child) = &mut self.child.lock() else {
+            return;
+        };
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
+
+/// 応答が期限までに無かった子プロセスを終了させるスレッド。
+///
+/// スレッドは`Watchdog`がdropされると終了する。
+struct Watchdog {
+    deadlines: mpsc::Sender<Option<Instant>>,
+    /// 期限切れで子プロセスを終了させたか。
+    expired: Arc<AtomicBool>,
+}
+
+impl Watchdog {
+    fn spawn(child: Arc<Mutex<Child>>) -> io::Result<Self> {
+        let (deadlines, rx) = mpsc::channel::<Option<Instant>>();
+        let expired = Arc::new(AtomicBool::new(false));
+
+        std::thread::Builder::new()
+            .name("voicevox-core-worker-watchdog".to_owned())
+            .spawn({
+                let expired = expired.clone();
+                move || {
+                    let mut deadline = None::<Instant>;
+                    loop {
+                        let received = match deadline {
+                            Some(deadline) => {
+                                rx.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                            }
+                            None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+                        };
+                        match received {
+                            Ok(next) => deadline = next,
+                            Err(RecvTimeoutError::Timeout) => {
+                                expired.store(true, Ordering::Relaxed);
+                                if let Ok(child) = &mut child.lock() {
+                                    let _ = child.kill();
+                                }
+                                deadline = None;
+                            }
+                            Err(RecvTimeoutError::Disconnected) => return,
+                        }
+                    }
+                }
+            })?;
+
+        Ok(Self { deadlines, expired })
+    }
+
+    fn arm(&self, timeout: Duration) {
+        let _ = self.deadlines.send(Some(Instant::now() + timeout));
+    }
+
+    fn disarm(&self) {
+        let _ = self.deadlines.send(None);
+    }
+}
+
+/// ワーカープロセスの中の`Session`。
+pub(crate) struct RemoteSession {
+    worker: Arc<Worker>,
+    /// この`Session`を持っている子プロセスの位置。
+    process: usize,
+    id: u64,
+    /// ワーカープロセスが起動し直されたときに`Session`を作り直すための、復号済みのモデル。
+    ///
+    /// `Status`での予算の見積もりでは、この分も数えられる。
+    model: Vec<u8>,
+    options: SessionOptions,
+    /// この`Session`を持っている子プロセスが、何番目に起動されたものか。
+    generation: u64,
+}
+
+impl RemoteSession {
+    pub(crate) fn run(&mut self, inputs: Vec<Tensor>) -> anyhow::Result<Vec<OutputTensor>> {
+        let worker = self.worker.clone();
+        worker
+            .run(self, inputs)?
+            .into_iter()
+            .map(Tensor::into_output)
+            .collect()
+    }
+}
+
+impl Drop for RemoteSession {
+    fn drop(&mut self) {
+        self.worker
+            .drop_session(self.process, self.id, self.generation);
+    }
+}
+
+/// ワーカープロセスの中でテキスト解析を行う[`FullcontextExtractor`]。
+#[derive(Clone)]
+pub(crate) struct OutOfProcessOpenJtalk {
+    pub(crate) worker: Arc<Worker>,
+    pub(crate) spec: OpenJtalkSpec,
+}
+
+impl FullcontextExtractor for OutOfProcessOpenJtalk {
+    fn extract_fullcontext(&self, text: &str) -> anyhow::Result<Vec<String>> {
+        self.worker.extract_fullcontext(&self.spec, text)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+enum Request<'a> {
+    NewSession {
+        id: u64,
+        model: Cow<'a, [u8]>,
+        options: SessionOptions,
+    },
+    DropSessions {
+        ids: Vec<u64>,
+    },
+    Run {
+        id: u64,
+        inputs: Vec<Tensor>,
+    },
+    /// `UseUserDict`で読み込ませた、`user_dict`の番号のユーザー辞書でテキスト解析を行う。
+    ExtractFullcontext {
+        dict_dir: Cow<'a, Utf8Path>,
+        user_dict: u64,
+        text: Cow<'a, str>,
+    },
+    UseUserDict {
+        open_jtalk: Cow<'a, OpenJtalkSpec>,
+    },
+}
+
+/// `InferenceSessionOptions`のうち、ランタイムに渡るもの。
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct SessionOptions {
+    cpu_num_threads: u16,
+    use_gpu: bool,
+    gpu_device_index: u16,
+    gpu_memory_limit: usize,
+}
+
+impl From<&InferenceSessionOptions> for SessionOptions {
+    fn from(options: &InferenceSessionOptions) -> Self {
+        Self {
+            cpu_num_threads: options.cpu_num_threads,
+            use_gpu: options.use_gpu,
+            gpu_device_index: options.gpu_device_index,
+            gpu_memory_limit: options.gpu_memory_limit,
+        }
+    }
+}
+
+impl From<SessionOptions> for InferenceSessionOptions {
+    fn from(options: SessionOptions) -> Self {
+        Self {
+            gpu_device_index: options.gpu_device_index,
+            gpu_memory_limit: options.gpu_memory_limit,
+            // ワーカープロセスの中では使われない
+            ..Self::new(options.cpu_num_threads, options.use_gpu, NonZeroUsize::MIN)
+        }
+    }
+}
+
+/// プロセス間でやりとりされるテンソル。
+#[derive(Serialize, Deserialize)]
+pub(crate) enum Tensor {
+    Int64(TensorData<i64>),
+    Int32(TensorData<i32>),
+    Int8(TensorData<i8>),
+    Float32(TensorData<f32>),
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct TensorData<T> {
+    shape: Vec<usize>,
+    /// 論理的な順序(C順)での要素。
+    elements: Vec<T>,
+}
+
+impl<T: Clone> TensorData<T> {
+    fn new(array: Array<T, impl Dimension>) -> Self {
+        Self {
+            shape: array.shape().to_owned(),
+            elements: array.iter().cloned().collect(),
+        }
+    }
+
+    fn into_array(self) -> anyhow::Result<ArrayD<T>> {
+        Ok(ArrayD::from_shape_vec(self.shape, self.elements)?)
+    }
+}
+
+#[duplicate_item(
+    T       variant;
+    [ i64 ] [ Int64 ];
+    [ i32 ] [ Int32 ];
+    [ i8 ]  [ Int8 ];
+    [ f32 ] [ Float32 ];
+)]
+impl<D: Dimension> From<Array<T, D>> for Tensor {
+    fn from(array: Array<T, D>) -> Self {
+        Self::variant(TensorData::new(array))
+    }
+}
+
+impl From<OutputTensor> for Tensor {
+    fn from(tensor: OutputTensor) -> Self {
+        match tensor {
+            OutputTensor::Float32(tensor) => tensor.into(),
+            OutputTensor::Int32(tensor) => tensor.into(),
+            OutputTensor::Int8(tensor) => tensor.into(),
+        }
+    }
+}
+
+impl Tensor {
+    fn push_to(self, ctx: &mut impl PushInputTensor) -> anyhow::Result<()> {
+        match self {
+            Self::Int64(data) => ctx.push_int64(data.into_array()?),
+            Self::Int32(data) => ctx.push_int32(data.into_array()?),
+            Self::Int8(data) => ctx.push_int8(data.into_array()?),
+            Self::Float32(data) => ctx.push_float32(data.into_array()?),
+        }
+        Ok(())
+    }
+
+    fn into_output(self) -> anyhow::Result<OutputTensor> {
+        Ok(match self {
+            Self::Float32(data) => OutputTensor::Float32(data.into_array()?),
+            Self::Int32(data) => OutputTensor::Int32(data.into_array()?),
+            Self::Int8(data) => OutputTensor::Int8(data.into_array()?),
+            Self::Int64(_) => bail!("`int64_t`の出力には対応していません"),
+        })
+    }
+}
+
+/// ワーカープロセスとして、`input`から要求を読み`output`に応答を書く。`input`が閉じられたら
+/// 終了する。
+fn serve<R: InferenceRuntime>(input: impl Read, output: impl Write) -> anyhow::Result<()> {
+    let mut input = BufReader::new(input);
+    let mut output = BufWriter::new(output);
+    let mut sessions = HashMap::<u64, R::Session>::new();
+    // 辞書ディレクトリごとの、ユーザー辞書の番号と`OpenJtalk`
+    let mut open_jtalks = HashMap::<Utf8PathBuf, (u64, OpenJtalk)>::new();
+
+    loop {
+        let request = match bincode::deserialize_from::<_, Request<'static>>(&mut input) {
+            Ok(request) => request,
+            Err(err) if is_eof(&err) => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+
+        match request {
+            Request::NewSession { id, model, options } => {
+                let result = R::new_session(|| Ok(model.into_owned()), options.into()).map(
+                    |(sess, input_param_infos, output_param_infos)| {
+                        sessions.insert(id, sess);
+                        (input_param_infos, output_param_infos)
+                    },
+                );
+                respond(&mut output, result)?;
+            }
+            Request::DropSessions { ids } => {
+                for id in ids {
+                    sessions.remove(&id);
+                }
+                respond(&mut output, Ok(()))?;
+            }
+            Request::Run { id, inputs } => {
+                let result = run::<R>(&mut sessions, id, inputs);
+                respond(&mut output, result)?;
+            }
+            Request::ExtractFullcontext {
+                dict_dir,
+                user_dict,
+                text,
+            } => {
+                let result = match open_jtalks.get(&*dict_dir) {
+                    Some((generation, open_jtalk)) if *generation == user_dict => {
+                        open_jtalk.extract_fullcontext(&text)
+                    }
+                    _ => Err(anyhow!(
+                        "`{dict_dir}`に対するユーザー辞書`{user_dict}`が読み込まれていません",
+                    )),
+                };
+                respond(&mut output, result)?;
+            }
+            Request::UseUserDict { open_jtalk } => {
+                let result = OpenJtalk::from_spec(&open_jtalk).map(|new| {
+                    let generation = open_jtalk.user_dict_generation();
+                    open_jtalks.insert(open_jtalk.dict_dir().to_owned(), (generation, new));
+                });
+                respond(&mut output, result.map_err(Into::into))?;
+            }
+        }
+    }
+
+    fn run<R: InferenceRuntime>(
+        sessions: &mut HashMap<u64, R::Session>,
+        id: u64,
+        inputs: Vec<Tensor>,
+    ) -> anyhow::Result<Vec<Tensor>> {
+        let sess = sessions
+            .get_mut(&id)
+            .with_context(|| format!("`Session`が見つかりません: {id}"))?;
+        let mut ctx: R::RunContext<'_> = sess.into();
+        for input in inputs {
+            input.push_to(&mut ctx)?;
+        }
+        Ok(R::run(ctx)?.into_iter().map(Into::into).collect())
+    }
+
+    fn respond<T: Serialize>(
+        output: &mut impl Write,
+        result: anyhow::Result<T>,
+    ) -> anyhow::Result<()> {
+        let response = result.map_err(|err| format!("{err:?}"));
+        bincode::serialize_into(&mut *output, &response)?;
+        output.flush()?;
+        Ok(())
+    }
+
+    fn is_eof(err: &bincode::Error) -> bool {
+        matches!(&**err, bincode::ErrorKind::Io(err) if err.kind() == io::ErrorKind::UnexpectedEof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::Cursor,
+        num::NonZeroUsize,
+        sync::Arc,
+        time::{Duration, Instant},
+    };
+
+    use camino::Utf8Path;
+    use ndarray::{array, ArrayD};
+    use rstest::rstest;
+
+    use crate::{engine::OpenJtalkSpec, synthesizer::LocalInferenceRuntimeImpl};
+
+    use super::{RemoteSession, Request, SessionOptions, Tensor, Worker, WorkerCrashed};
+
+    #[rstest]
+    fn tensor_conversion_works() {
+        let array = array![[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        let Tensor::Float32(data) = Tensor::from(array.t().to_owned()) else {
+            panic!("should be `Float32`");
+        };
+        assert_eq!(array.t().into_dyn(), data.into_array().unwrap());
+
+        let array = array![-3i8, 5];
+        let tensor = Tensor::from(array.clone());
+        assert!(matches!(tensor, Tensor::Int8(_)));
+        let output: ArrayD<i8> = tensor.into_output().unwrap().try_into().unwrap();
+        assert_eq!(array.into_dyn(), output);
+    }
+
+    #[rstest]
+    fn serve_works() {
+        let mut input = vec![];
+        for request in [
+            Request::DropSessions { ids: vec![0] },
+            Request::Run {
+                id: 0,
+                inputs: vec![],
+            },
+            Request::ExtractFullcontext {
+                dict_dir: Utf8Path::new(test_util::OPEN_JTALK_DIC_DIR).into(),
+                user_dict: 0,
+                text: "こんにちは".into(),
+            },
+        ] {
+            bincode::serialize_into(&mut input, &request).unwrap();
+        }
+
+        let mut output = vec![];
+        super::serve::<LocalInferenceRuntimeImpl>(&*input, &mut output).unwrap();
+
+        let mut output = Cursor::new(output);
+        let dropped = bincode::deserialize_from::<_, Result<(), String>>(&mut output).unwrap();
+        assert_eq!(Ok(()), dropped);
+        let ran = bincode::deserialize_from::<_, Result<Vec<Tensor>, String>>(&mut output).unwrap();
+        assert!(ran.is_err());
+        // `UseUserDict`で読み込ませていない
+        let extracted =
+            bincode::deserialize_from::<_, Result<Vec<String>, String>>(&mut output).unwrap();
+        assert!(extracted.is_err());
+        assert_eq!(output.get_ref().len() as u64, output.position());
+    }
+
+    #[cfg(unix)]
+    #[rstest]
+    fn worker_crash_is_reported_as_error() {
+        // 何も応答せずに終了するプログラムを、クラッシュするワーカープロセスの代わりとする
+        let worker = Arc::new(Worker::new(Some("false".into()), NonZeroUsize::MIN));
+        let spec = OpenJtalkSpec::new(test_util::OPEN_JTALK_DIC_DIR.into(), None);
+
+        for num_spawns in 1..=2 {
+            let err = worker.extract_fullcontext(&spec, "こんにちは").unwrap_err();
+            assert!(err.is::<WorkerCrashed>());
+            let state = worker.processes[0].lock();
+            assert!(state.process.is_none());
+            assert_eq!(num_spawns, state.num_spawns);
+        }
+    }
+
+    #[cfg(unix)]
+    #[rstest]
+    fn worker_hang_is_reported_as_error() {
+        // 標準入力が閉じられるまで何も書かない`tail`を、ハングするワーカープロセスの代わりとする
+        let worker = Worker::with_timeout(
+            Some("tail".into()),
+            NonZeroUsize::MIN,
+            Duration::from_millis(200),
+        );
+        let spec = OpenJtalkSpec::new(test_util::OPEN_JTALK_DIC_DIR.into(), None);
+
+        let start = Instant::now();
+        let err = worker.extract_fullcontext(&spec, "こんにちは").unwrap_err();
+        assert!(start.elapsed() < Duration::from_secs(10));
+        assert!(err.is::<WorkerCrashed>());
+        assert!(worker.processes[0].lock().process.is_none());
+    }
+
+    #[cfg(unix)]
+    #[rstest]
+    fn worker_recovers_from_poisoned_lock() {
+        let worker = Arc::new(Worker::new(Some("tail".into()), NonZeroUsize::MIN));
+
+        std::thread::spawn({
+            let worker = worker.clone();
+            move || {
+                let state = &mut *worker.processes[0].lock();
+                worker.spawn_if_needed(state).unwrap();
+                panic!("通信中のパニック");
+            }
+        })
+        .join()
+        .unwrap_err();
+
+        let state = worker.processes[0].lock();
+        assert!(state.process.is_none());
+        assert_eq!(1, state.num_spawns);
+        drop(state);
+        assert!(!worker.processes[0].state.is_poisoned());
+    }
+
+    #[rstest]
+    fn dropping_remote_session_does_not_wait_for_process() {
+        let worker = Arc::new(Worker::new(None, NonZeroUsize::MIN));
+        let sess = RemoteSession {
+            worker: worker.clone(),
+            process: 0,
+            id: 0,
+            model: vec![],
+            options: SessionOptions {
+                cpu_num_threads: 0,
+                use_gpu: false,
+                gpu_device_index: 0,
+                gpu_memory_limit: 0,
+            },
+            generation: 0,
+        };
+
+        // 子プロセスが使用中でも待たずに破棄でき、`Session`の破棄は次の要求に回される
+        let _state = worker.processes[0].lock();
+        drop(sess);
+        assert_eq!(vec![(0, 0)], *worker.processes[0].dropped_sessions());
+    }
+
+    #[rstest]
+    fn worker_uses_idle_process() {
+        let worker = Worker::new(None, NonZeroUsize::new(2).unwrap());
+        let (first_process, first) = worker.lock_any_process();
+        let (second_process, second) = worker.lock_any_process();
+        assert_ne!(first_process, second_process);
+        assert!(!std::ptr::eq(&*first, &*second));
+    }
+}
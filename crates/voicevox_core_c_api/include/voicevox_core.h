@@ -178,11 +178,71 @@ enum VoicevoxResultCode
    * UUIDの変換に失敗した
    */
   VOICEVOX_RESULT_INVALID_UUID_ERROR = 25,
+  /**
+   * 音声モデルのウォームアップに失敗した
+   */
+  VOICEVOX_RESULT_WARM_UP_ERROR = 28,
+  /**
+   * 音声モデルが推論操作のためのモデルを含んでいない、またはスタイルの種類が推論操作に対応していない
+   */
+  VOICEVOX_RESULT_OPERATION_NOT_SUPPORTED_ERROR = 29,
+  /**
+   * ワーカープロセスが異常終了した
+   */
+  VOICEVOX_RESULT_WORKER_CRASHED_ERROR = 30,
+  /**
+   * 音声モデルのマニフェストのバージョンに対応していない
+   */
+  VOICEVOX_RESULT_UNSUPPORTED_MANIFEST_VERSION_ERROR = 31,
+  /**
+   * 音声モデルの署名を検証できなかった、または署名者が信頼されていない
+   */
+  VOICEVOX_RESULT_UNTRUSTED_VOICE_MODEL_ERROR = 32,
+  /**
+   * 話者のUUIDに対する話者が見つからなかった
+   */
+  VOICEVOX_RESULT_SPEAKER_NOT_FOUND_ERROR = 33,
+  /**
+   * 音声モデルを探すディレクトリを読めなかった
+   */
+  VOICEVOX_RESULT_READ_MODEL_DIR_ERROR = 34,
+  /**
+   * 指定された番号のGPUが見つからなかった
+   */
+  VOICEVOX_RESULT_GPU_DEVICE_NOT_FOUND_ERROR = 35,
+  /**
+   * VVMを作成できなかった
+   */
+  VOICEVOX_RESULT_PACK_VVM_ERROR = 36,
 };
 #ifndef __cplusplus
 typedef int32_t VoicevoxResultCode;
 #endif // __cplusplus
 
+/**
+ * 音声モデルの署名に対する方針。
+ *
+ * 署名はVVMに同梱される。詳しくは<a href="https://github.com/VOICEVOX/voicevox_core/blob/main/docs/vvm.md">VVMのドキュメント</a>を参照。
+ */
+enum VoicevoxSignaturePolicy
+#ifdef __cplusplus
+  : int32_t
+#endif // __cplusplus
+ {
+  /**
+   * 署名の無い音声モデルや、信頼されていない署名者による音声モデルも読み込む
+   */
+  VOICEVOX_SIGNATURE_POLICY_PERMISSIVE = 0,
+  /**
+   * ::VoicevoxInitializeOptions の`trusted_public_keys`のいずれかで署名された音声モデルのみを読み込む
+   * それ以外の音声モデルの読み込みは ::VOICEVOX_RESULT_UNTRUSTED_VOICE_MODEL_ERROR となる
+   */
+  VOICEVOX_SIGNATURE_POLICY_STRICT = 1,
+};
+#ifndef __cplusplus
+typedef int32_t VoicevoxSignaturePolicy;
+#endif // __cplusplus
+
 /**
  * ユーザー辞書の単語の種類。
  */
@@ -251,12 +311,22 @@ typedef struct VoicevoxUserDict VoicevoxUserDict;
  * 音声モデル。
  *
  * VVMファイルと対応する。
- * <b>構築</b>(_construction_)は ::voicevox_voice_model_new_from_path で行い、<b>破棄</b>(_destruction_)は ::voicevox_voice_model_delete で行う。
+ * <b>構築</b>(_construction_)は ::voicevox_voice_model_new_from_path か ::voicevox_voice_model_new_from_bytes で行い、<b>破棄</b>(_destruction_)は ::voicevox_voice_model_delete で行う。
  */
 typedef struct VoicevoxVoiceModel VoicevoxVoiceModel;
 
+/**
+ * 音声モデルの集合。
+ *
+ * ディレクトリ内のVVMを走査し、スタイルIDに対してそのスタイルを含む音声モデルを引けるようにする。走査の時点ではVVMのマニフェストとメタ情報のみが読まれる。
+ * <b>構築</b>(_construction_)は ::voicevox_voice_model_set_new_from_dirs で行い、<b>破棄</b>(_destruction_)は ::voicevox_voice_model_set_delete で行う。
+ */
+typedef struct VoicevoxVoiceModelSet VoicevoxVoiceModelSet;
+
 /**
  * ::voicevox_synthesizer_new のオプション。
+ *
+ * @warning この構造体には、`gpu_device_index`以降のフィールドが末尾に追加されている。構造体の大きさが変わっているため、以前のヘッダでビルドしたプログラムとはABIの互換性が無い。ヘッダとライブラリのバージョンは必ず一致させ、値は ::voicevox_make_default_initialize_options で得たものを書き換えて使うこと。
  */
 typedef struct VoicevoxInitializeOptions {
   /**
@@ -268,6 +338,41 @@ typedef struct VoicevoxInitializeOptions {
    * 0を指定すると環境に合わせたCPUが利用される
    */
   uint16_t cpu_num_threads;
+  /**
+   * GPUを使うときの、GPUの番号
+   * CUDAではCUDAのデバイス番号、DirectMLではDXGIのアダプタの列挙順の番号となる
+   * GPUを使う場合、GPUの数が得られる環境では ::voicevox_synthesizer_new の時点で検証され、存在しない場合は ::VOICEVOX_RESULT_GPU_DEVICE_NOT_FOUND_ERROR となる
+   */
+  uint16_t gpu_device_index;
+  /**
+   * GPUを使うときの、GPUメモリの使用量の上限(バイト)
+   * 0を指定すると上限を設けない。CUDAでのみ有効
+   */
+  uintptr_t gpu_memory_limit;
+  /**
+   * 一つの音声モデルの一つの推論操作につき、同時に保持するセッションの最大数
+   * 同じ音声モデルに対する並列な音声合成は、この数まで同時に推論を行える。0を指定すると1として扱う
+   */
+  uintptr_t max_sessions_per_operation;
+  /**
+   * 音声モデルの読み込み時に、 ::voicevox_synthesizer_warm_up を行うかどうか
+   * ウォームアップに失敗した場合、読み込みは取り消される
+   */
+  bool warm_up_on_load;
+  /**
+   * 音声モデルの署名に対する方針
+   * 方針によらず、署名のある音声モデルは常に検証され、内容が署名と合わない場合は読み込みに失敗する
+   */
+  VoicevoxSignaturePolicy signature_policy;
+  /**
+   * 信頼する署名者の、Ed25519の公開鍵(32バイト)を`trusted_public_keys_length`個並べたもの
+   * ::VOICEVOX_SIGNATURE_POLICY_STRICT の場合に使われる。内容は ::voicevox_synthesizer_new の中で複製される
+   */
+  const uint8_t *trusted_public_keys;
+  /**
+   * `trusted_public_keys`の公開鍵の数
+   */
+  uintptr_t trusted_public_keys_length;
 } VoicevoxInitializeOptions;
 
 /**
@@ -421,7 +526,7 @@ const char *voicevox_get_version(void);
 /**
  * VVMファイルから ::VoicevoxVoiceModel を<b>構築</b>(_construct_)する。
  *
- * @param [in] path vvmファイルへのUTF-8のファイルパス
+ * @param [in] path vvmファイルか、vvmファイルを展開したディレクトリへのUTF-8のパス
  * @param [out] out_model 構築先
  *
  * @returns 結果コード
@@ -437,6 +542,29 @@ __declspec(dllimport)
 VoicevoxResultCode voicevox_voice_model_new_from_path(const char *path,
                                                       struct VoicevoxVoiceModel **out_model);
 
+/**
+ * メモリ上のVVMから ::VoicevoxVoiceModel を<b>構築</b>(_construct_)する。
+ *
+ * VVMの内容は ::VoicevoxVoiceModel の中に複製されるため、`bytes`はこの関数から戻った後に解放してよい。
+ *
+ * @param [in] bytes VVMの内容
+ * @param [in] length `bytes`のバイト長
+ * @param [out] out_model 構築先
+ *
+ * @returns 結果コード
+ *
+ * \safety{
+ * - `bytes`は<a href="#voicevox-core-safety">読み込みについて有効</a>な`length`バイトの領域を指していなければならない。
+ * - `out_model`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_voice_model_new_from_bytes(const uint8_t *bytes,
+                                                       uintptr_t length,
+                                                       struct VoicevoxVoiceModel **out_model);
+
 /**
  * ::VoicevoxVoiceModel からIDを取得する。
  *
@@ -445,7 +573,7 @@ VoicevoxResultCode voicevox_voice_model_new_from_path(const char *path,
  * @returns 音声モデルID
  *
  * \safety{
- * - `model`は ::voicevox_voice_model_new_from_path で得たものでなければならず、また ::voicevox_voice_model_delete で解放されていてはいけない。
+ * - `model`は ::voicevox_voice_model_new_from_path か ::voicevox_voice_model_new_from_bytes で得たものでなければならず、また ::voicevox_voice_model_delete で解放されていてはいけない。
  * }
  */
 #ifdef _WIN32
@@ -456,12 +584,14 @@ VoicevoxVoiceModelId voicevox_voice_model_id(const struct VoicevoxVoiceModel *mo
 /**
  * ::VoicevoxVoiceModel からメタ情報を取得する。
  *
+ * スタイルの種類で絞り込む関数は無いため、必要であれば各スタイルの`type`で絞り込むこと。
+ *
  * @param [in] model 音声モデル
  *
  * @returns メタ情報のJSON文字列
  *
  * \safety{
- * - `model`は ::voicevox_voice_model_new_from_path で得たものでなければならず、また ::voicevox_voice_model_delete で解放されていてはいけない。
+ * - `model`は ::voicevox_voice_model_new_from_path か ::voicevox_voice_model_new_from_bytes で得たものでなければならず、また ::voicevox_voice_model_delete で解放されていてはいけない。
  * - 戻り値の文字列の<b>生存期間</b>(_lifetime_)は次にこの関数が呼ばれるか、`model`が破棄されるまでである。この生存期間を越えて文字列にアクセスしてはならない。
  * }
  */
@@ -470,13 +600,134 @@ __declspec(dllimport)
 #endif
 const char *voicevox_voice_model_get_metas_json(const struct VoicevoxVoiceModel *model);
 
+/**
+ * ::VoicevoxVoiceModel から、話者の追加情報をJSONとして読む。
+ *
+ * 追加情報には、利用規約と、Base64で表された立ち絵・アイコンの画像と音声サンプルが含まれる。
+ *
+ * 生成したJSON文字列を解放するには ::voicevox_json_free を使う。
+ *
+ * @param [in] model 音声モデル
+ * @param [in] speaker_uuid 話者のUUID
+ * @param [out] output_speaker_info_json 生成先
+ *
+ * @returns 結果コード
+ *
+ * \safety{
+ * - `model`は ::voicevox_voice_model_new_from_path か ::voicevox_voice_model_new_from_bytes で得たものでなければならず、また ::voicevox_voice_model_delete で解放されていてはいけない。
+ * - `speaker_uuid`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+ * - `output_speaker_info_json`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_voice_model_create_speaker_info_json(const struct VoicevoxVoiceModel *model,
+                                                                 const char *speaker_uuid,
+                                                                 char **output_speaker_info_json);
+
+/**
+ * ::VoicevoxVoiceModel のメタ情報から、話者のUUIDとスタイル名に対応するスタイルIDを得る。
+ *
+ * スタイル名は完全に一致する必要がある。スタイルが見つからないときのエラーメッセージには、その話者のスタイル名が近い順に候補として挙げられる。
+ *
+ * @param [in] model 音声モデル
+ * @param [in] speaker_uuid 話者のUUID
+ * @param [in] style_name スタイル名
+ * @param [out] output_style_id 出力先
+ *
+ * @returns 結果コード
+ *
+ * \safety{
+ * - `model`は ::voicevox_voice_model_new_from_path か ::voicevox_voice_model_new_from_bytes で得たものでなければならず、また ::voicevox_voice_model_delete で解放されていてはいけない。
+ * - `speaker_uuid`と`style_name`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+ * - `output_style_id`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_voice_model_resolve_style_id(const struct VoicevoxVoiceModel *model,
+                                                         const char *speaker_uuid,
+                                                         const char *style_name,
+                                                         VoicevoxStyleId *output_style_id);
+
+/**
+ * ::VoicevoxVoiceModel のメタ情報から、スタイルIDに対応する話者のUUIDとスタイル名を、JSONとして得る。
+ *
+ * 生成したJSON文字列を解放するには ::voicevox_json_free を使う。
+ *
+ * @param [in] model 音声モデル
+ * @param [in] style_id スタイルID
+ * @param [out] output_style_key_json 生成先
+ *
+ * @returns 結果コード
+ *
+ * \example{
+ * ```json
+ * { "speaker_uuid": "388f246b-8c41-4ac1-8e2d-5d79f3ff56d9", "style_name": "ノーマル" }
+ * ```
+ * }
+ *
+ * \safety{
+ * - `model`は ::voicevox_voice_model_new_from_path か ::voicevox_voice_model_new_from_bytes で得たものでなければならず、また ::voicevox_voice_model_delete で解放されていてはいけない。
+ * - `output_style_key_json`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_voice_model_create_style_key_json(const struct VoicevoxVoiceModel *model,
+                                                              VoicevoxStyleId style_id,
+                                                              char **output_style_key_json);
+
+/**
+ * ::VoicevoxVoiceModel のメタ情報から、話者名とスタイル名でスタイルをあいまいに検索し、結果をJSONとして得る。
+ *
+ * `query`は空白で区切られた語の列として扱われ、それぞれの語が話者名かスタイル名のいずれかに近いスタイルが、近い順に並ぶ。`score`は検索語との近さで、0以上1以下の値をとる。
+ *
+ * 生成したJSON文字列を解放するには ::voicevox_json_free を使う。
+ *
+ * @param [in] model 音声モデル
+ * @param [in] query 検索語
+ * @param [out] output_candidates_json 生成先
+ *
+ * @returns 結果コード
+ *
+ * \example{
+ * ```json
+ * [
+ *   {
+ *     "speaker_name": "ずんだもん",
+ *     "speaker_uuid": "388f246b-8c41-4ac1-8e2d-5d79f3ff56d9",
+ *     "style_name": "あまあま",
+ *     "style_id": 1,
+ *     "score": 1.0
+ *   }
+ * ]
+ * ```
+ * }
+ *
+ * \safety{
+ * - `model`は ::voicevox_voice_model_new_from_path か ::voicevox_voice_model_new_from_bytes で得たものでなければならず、また ::voicevox_voice_model_delete で解放されていてはいけない。
+ * - `query`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+ * - `output_candidates_json`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_voice_model_search_styles_json(const struct VoicevoxVoiceModel *model,
+                                                           const char *query,
+                                                           char **output_candidates_json);
+
 /**
  * ::VoicevoxVoiceModel を<b>破棄</b>(_destruct_)する。
  *
  * @param [in] model 破棄対象
  *
  * \safety{
- * - `model`は ::voicevox_voice_model_new_from_path で得たものでなければならず、また既にこの関数で解放されていてはいけない。
+ * - `model`は ::voicevox_voice_model_new_from_path か ::voicevox_voice_model_new_from_bytes で得たものでなければならず、また既にこの関数で解放されていてはいけない。
  * - `model`は以後<b>ダングリングポインタ</b>(_dangling pointer_)として扱われなくてはならない。
  * }
  */
@@ -485,6 +736,123 @@ __declspec(dllimport)
 #endif
 void voicevox_voice_model_delete(struct VoicevoxVoiceModel *model);
 
+/**
+ * ディレクトリの直下にあるVVMを走査し、 ::VoicevoxVoiceModelSet を<b>構築</b>(_construct_)する。
+ *
+ * VVMは`dirs`の順に、それぞれのディレクトリの中ではパスの順に見つかったものとし、同じスタイルIDを含むVVMや同じ音声モデルIDを持つVVMが複数見つかった場合は先に見つかったものが優先される。名前が`.vvm`で終わるディレクトリは、VVMを展開したものとして扱う。
+ *
+ * 個々のVVMを読めなかった場合は失敗とせず、そのVVMを飛ばす。飛ばされたVVMのエラーは ::voicevox_voice_model_set_get_errors_json で得られる。
+ *
+ * @param [in] dirs ディレクトリへのUTF-8のパスの配列
+ * @param [in] num_dirs `dirs`の要素数
+ * @param [out] out_model_set 構築先
+ *
+ * @returns 結果コード
+ *
+ * \safety{
+ * - `dirs`は<a href="#voicevox-core-safety">読み込みについて有効</a>な`num_dirs`個の要素を持つ配列を指していなければならない。
+ * - `dirs`の各要素はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+ * - `out_model_set`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_voice_model_set_new_from_dirs(const char *const *dirs,
+                                                          uintptr_t num_dirs,
+                                                          struct VoicevoxVoiceModelSet **out_model_set);
+
+/**
+ * ::VoicevoxVoiceModelSet からメタ情報を取得する。
+ *
+ * 衝突により使われないスタイルは含まれない。
+ *
+ * @param [in] model_set 音声モデルの集合
+ *
+ * @returns メタ情報のJSON文字列
+ *
+ * \safety{
+ * - `model_set`は ::voicevox_voice_model_set_new_from_dirs で得たものでなければならず、また ::voicevox_voice_model_set_delete で解放されていてはいけない。
+ * - 戻り値の文字列の<b>生存期間</b>(_lifetime_)は`model_set`が破棄されるまでである。この生存期間を越えて文字列にアクセスしてはならない。
+ * }
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+const char *voicevox_voice_model_set_get_metas_json(const struct VoicevoxVoiceModelSet *model_set);
+
+/**
+ * ::VoicevoxVoiceModelSet の走査時に見つかった、VVMの衝突を取得する。
+ *
+ * `preferred`は優先されたVVMのパス、`shadowed`は優先されなかったVVMのパス、`style_ids`は両方のVVMに含まれるスタイルのIDである。`same_voice_model_id`が`true`の場合、音声モデルIDが同じであるため`shadowed`のVVMは丸ごと使われない。
+ *
+ * @param [in] model_set 音声モデルの集合
+ *
+ * @returns 衝突の配列のJSON文字列
+ *
+ * \example{
+ * ```json
+ * [
+ *   {
+ *     "preferred": "model/a.vvm",
+ *     "shadowed": "model/b.vvm",
+ *     "style_ids": [1],
+ *     "same_voice_model_id": false
+ *   }
+ * ]
+ * ```
+ * }
+ *
+ * \safety{
+ * - `model_set`は ::voicevox_voice_model_set_new_from_dirs で得たものでなければならず、また ::voicevox_voice_model_set_delete で解放されていてはいけない。
+ * - 戻り値の文字列の<b>生存期間</b>(_lifetime_)は`model_set`が破棄されるまでである。この生存期間を越えて文字列にアクセスしてはならない。
+ * }
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+const char *voicevox_voice_model_set_get_conflicts_json(const struct VoicevoxVoiceModelSet *model_set);
+
+/**
+ * ::VoicevoxVoiceModelSet の走査時に読めず、飛ばされたVVMのエラーメッセージを取得する。
+ *
+ * @param [in] model_set 音声モデルの集合
+ *
+ * @returns エラーメッセージの配列のJSON文字列
+ *
+ * \example{
+ * ```json
+ * [
+ *   "`model/c.vvm`の読み込みに失敗しました: ZIPファイルとして開くことができませんでした"
+ * ]
+ * ```
+ * }
+ *
+ * \safety{
+ * - `model_set`は ::voicevox_voice_model_set_new_from_dirs で得たものでなければならず、また ::voicevox_voice_model_set_delete で解放されていてはいけない。
+ * - 戻り値の文字列の<b>生存期間</b>(_lifetime_)は`model_set`が破棄されるまでである。この生存期間を越えて文字列にアクセスしてはならない。
+ * }
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+const char *voicevox_voice_model_set_get_errors_json(const struct VoicevoxVoiceModelSet *model_set);
+
+/**
+ * ::VoicevoxVoiceModelSet を<b>破棄</b>(_destruct_)する。
+ *
+ * @param [in] model_set 破棄対象
+ *
+ * \safety{
+ * - `model_set`は ::voicevox_voice_model_set_new_from_dirs で得たものでなければならず、また既にこの関数で解放されていてはいけない。
+ * - `model_set`は以後<b>ダングリングポインタ</b>(_dangling pointer_)として扱われなくてはならない。
+ * }
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+void voicevox_voice_model_set_delete(struct VoicevoxVoiceModelSet *model_set);
+
 /**
  * ::VoicevoxSynthesizer を<b>構築</b>(_construct_)する。
  *
@@ -496,6 +864,7 @@ void voicevox_voice_model_delete(struct VoicevoxVoiceModel *model);
  *
  * \safety{
  * - `open_jtalk`は ::voicevox_voice_model_new_from_path で得たものでなければならず、また ::voicevox_open_jtalk_rc_new で解放されていてはいけない。
+ * - `options.trusted_public_keys_length`が0でない場合、`options.trusted_public_keys`は<a href="#voicevox-core-safety">読み込みについて有効</a>な`options.trusted_public_keys_length * 32`バイトの領域を指していなければならない。
  * - `out_synthesizer`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
  * }
  */
@@ -531,7 +900,7 @@ void voicevox_synthesizer_delete(struct VoicevoxSynthesizer *synthesizer);
  *
  * \safety{
  * - `synthesizer`は ::voicevox_synthesizer_new で得たものでなければならず、また ::voicevox_synthesizer_delete で解放されていてはいけない。
- * - `model`は ::voicevox_voice_model_new_from_path で得たものでなければならず、また ::voicevox_voice_model_delete で解放されていてはいけない。
+ * - `model`は ::voicevox_voice_model_new_from_path か ::voicevox_voice_model_new_from_bytes で得たものでなければならず、また ::voicevox_voice_model_delete で解放されていてはいけない。
  * }
  */
 #ifdef _WIN32
@@ -540,6 +909,29 @@ __declspec(dllimport)
 VoicevoxResultCode voicevox_synthesizer_load_voice_model(const struct VoicevoxSynthesizer *synthesizer,
                                                          const struct VoicevoxVoiceModel *model);
 
+/**
+ * 音声モデルの集合から、スタイルIDに対するスタイルを含む音声モデルを読み込む。
+ *
+ * そのスタイルが既に読み込まれている場合は何もしない。他のスレッドが同時に同じ音声モデルを読み込んだ場合も同様に扱う。
+ *
+ * @param [in] synthesizer 音声シンセサイザ
+ * @param [in] model_set 音声モデルの集合
+ * @param [in] style_id スタイルID
+ *
+ * @returns 結果コード
+ *
+ * \safety{
+ * - `synthesizer`は ::voicevox_synthesizer_new で得たものでなければならず、また ::voicevox_synthesizer_delete で解放されていてはいけない。
+ * - `model_set`は ::voicevox_voice_model_set_new_from_dirs で得たものでなければならず、また ::voicevox_voice_model_set_delete で解放されていてはいけない。
+ * }
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_synthesizer_load_voice_model_by_style_id(const struct VoicevoxSynthesizer *synthesizer,
+                                                                     const struct VoicevoxVoiceModelSet *model_set,
+                                                                     VoicevoxStyleId style_id);
+
 /**
  * 音声モデルの読み込みを解除する。
  *
@@ -559,6 +951,38 @@ __declspec(dllimport)
 VoicevoxResultCode voicevox_synthesizer_unload_voice_model(const struct VoicevoxSynthesizer *synthesizer,
                                                            VoicevoxVoiceModelId model_id);
 
+/**
+ * 音声モデルのウォームアップを行う。
+ *
+ * 音声モデルのそれぞれの推論を代表的なダミーの入力で一度ずつ実行し、読み込み直後の音声合成の遅延を前もって済ませる。併せて、推論結果が期待される長さを持ち、かつ有限の値のみから成るかを検査する。
+ *
+ * ここでの推論は統計には記録されない。
+ *
+ * @param [in] synthesizer 音声シンセサイザ
+ * @param [in] model_id 音声モデルID
+ *
+ * @returns 結果コード
+ *
+ * \example{
+ * ```c
+ * VoicevoxResultCode result = voicevox_synthesizer_warm_up(synthesizer, model_id);
+ * if (result == VOICEVOX_RESULT_WARM_UP_ERROR) {
+ *   // 音声モデルが壊れている
+ * }
+ * ```
+ * }
+ *
+ * \safety{
+ * - `synthesizer`は ::voicevox_synthesizer_new で得たものでなければならず、また ::voicevox_synthesizer_delete で解放されていてはいけない。
+ * - `model_id`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+ * }
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_synthesizer_warm_up(const struct VoicevoxSynthesizer *synthesizer,
+                                                VoicevoxVoiceModelId model_id);
+
 /**
  * ハードウェアアクセラレーションがGPUモードか判定する。
  *
@@ -597,6 +1021,8 @@ bool voicevox_synthesizer_is_loaded_voice_model(const struct VoicevoxSynthesizer
 /**
  * 今読み込んでいる音声モデルのメタ情報を、JSONで取得する。
  *
+ * スタイルの種類で絞り込む関数は無いため、必要であれば各スタイルの`type`で絞り込むこと。
+ *
  * JSONの解放は ::voicevox_json_free で行う。
  *
  * @param [in] synthesizer 音声シンセサイザ
@@ -612,6 +1038,127 @@ __declspec(dllimport)
 #endif
 char *voicevox_synthesizer_create_metas_json(const struct VoicevoxSynthesizer *synthesizer);
 
+/**
+ * 今読み込んでいる音声モデルから、話者の追加情報をJSONとして読む。
+ *
+ * 追加情報には、利用規約と、Base64で表された立ち絵・アイコンの画像と音声サンプルが含まれる。話者のスタイルが複数の音声モデルにまたがる場合は、それらから読んだものがまとめられる。
+ *
+ * 生成したJSON文字列を解放するには ::voicevox_json_free を使う。
+ *
+ * @param [in] synthesizer 音声シンセサイザ
+ * @param [in] speaker_uuid 話者のUUID
+ * @param [out] output_speaker_info_json 生成先
+ *
+ * @returns 結果コード
+ *
+ * \safety{
+ * - `synthesizer`は ::voicevox_synthesizer_new で得たものでなければならず、また ::voicevox_synthesizer_delete で解放されていてはいけない。
+ * - `speaker_uuid`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+ * - `output_speaker_info_json`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_synthesizer_create_speaker_info_json(const struct VoicevoxSynthesizer *synthesizer,
+                                                                 const char *speaker_uuid,
+                                                                 char **output_speaker_info_json);
+
+/**
+ * 今読み込んでいる音声モデルのメタ情報から、話者のUUIDとスタイル名に対応するスタイルIDを得る。
+ *
+ * スタイル名は完全に一致する必要がある。スタイルが見つからないときのエラーメッセージには、その話者のスタイル名が近い順に候補として挙げられる。
+ *
+ * @param [in] synthesizer 音声シンセサイザ
+ * @param [in] speaker_uuid 話者のUUID
+ * @param [in] style_name スタイル名
+ * @param [out] output_style_id 出力先
+ *
+ * @returns 結果コード
+ *
+ * \safety{
+ * - `synthesizer`は ::voicevox_synthesizer_new で得たものでなければならず、また ::voicevox_synthesizer_delete で解放されていてはいけない。
+ * - `speaker_uuid`と`style_name`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+ * - `output_style_id`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_synthesizer_resolve_style_id(const struct VoicevoxSynthesizer *synthesizer,
+                                                         const char *speaker_uuid,
+                                                         const char *style_name,
+                                                         VoicevoxStyleId *output_style_id);
+
+/**
+ * 今読み込んでいる音声モデルのメタ情報から、スタイルIDに対応する話者のUUIDとスタイル名を、JSONとして得る。
+ *
+ * 生成したJSON文字列を解放するには ::voicevox_json_free を使う。
+ *
+ * @param [in] synthesizer 音声シンセサイザ
+ * @param [in] style_id スタイルID
+ * @param [out] output_style_key_json 生成先
+ *
+ * @returns 結果コード
+ *
+ * \example{
+ * ```json
+ * { "speaker_uuid": "388f246b-8c41-4ac1-8e2d-5d79f3ff56d9", "style_name": "ノーマル" }
+ * ```
+ * }
+ *
+ * \safety{
+ * - `synthesizer`は ::voicevox_synthesizer_new で得たものでなければならず、また ::voicevox_synthesizer_delete で解放されていてはいけない。
+ * - `output_style_key_json`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_synthesizer_create_style_key_json(const struct VoicevoxSynthesizer *synthesizer,
+                                                              VoicevoxStyleId style_id,
+                                                              char **output_style_key_json);
+
+/**
+ * 今読み込んでいる音声モデルのメタ情報から、話者名とスタイル名でスタイルをあいまいに検索し、結果をJSONとして得る。
+ *
+ * `query`は空白で区切られた語の列として扱われ、それぞれの語が話者名かスタイル名のいずれかに近いスタイルが、近い順に並ぶ。`score`は検索語との近さで、0以上1以下の値をとる。
+ *
+ * 生成したJSON文字列を解放するには ::voicevox_json_free を使う。
+ *
+ * @param [in] synthesizer 音声シンセサイザ
+ * @param [in] query 検索語
+ * @param [out] output_candidates_json 生成先
+ *
+ * @returns 結果コード
+ *
+ * \example{
+ * ```json
+ * [
+ *   {
+ *     "speaker_name": "ずんだもん",
+ *     "speaker_uuid": "388f246b-8c41-4ac1-8e2d-5d79f3ff56d9",
+ *     "style_name": "あまあま",
+ *     "style_id": 1,
+ *     "score": 1.0
+ *   }
+ * ]
+ * ```
+ * }
+ *
+ * \safety{
+ * - `synthesizer`は ::voicevox_synthesizer_new で得たものでなければならず、また ::voicevox_synthesizer_delete で解放されていてはいけない。
+ * - `query`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+ * - `output_candidates_json`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_synthesizer_search_styles_json(const struct VoicevoxSynthesizer *synthesizer,
+                                                           const char *query,
+                                                           char **output_candidates_json);
+
 /**
  * このライブラリで利用可能なデバイスの情報を、JSONで取得する。
  *
@@ -978,6 +1525,12 @@ VoicevoxResultCode voicevox_synthesizer_tts(const struct VoicevoxSynthesizer *sy
  * - `json`は以下のAPIで得られたポインタでなくてはいけない。
  *     - ::voicevox_create_supported_devices_json
  *     - ::voicevox_synthesizer_create_metas_json
+ *     - ::voicevox_synthesizer_create_speaker_info_json
+ *     - ::voicevox_synthesizer_create_style_key_json
+ *     - ::voicevox_synthesizer_search_styles_json
+ *     - ::voicevox_voice_model_create_speaker_info_json
+ *     - ::voicevox_voice_model_create_style_key_json
+ *     - ::voicevox_voice_model_search_styles_json
  *     - ::voicevox_synthesizer_create_audio_query
  *     - ::voicevox_synthesizer_create_accent_phrases
  *     - ::voicevox_synthesizer_replace_mora_data
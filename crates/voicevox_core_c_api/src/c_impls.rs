@@ -3,7 +3,10 @@ use std::{ffi::CString, path::Path};
 use camino::Utf8Path;
 use voicevox_core::{InitializeOptions, Result, VoiceModelId};
 
-use crate::{helpers::CApiResult, OpenJtalkRc, VoicevoxSynthesizer, VoicevoxVoiceModel};
+use crate::{
+    helpers::CApiResult, OpenJtalkRc, VoicevoxSynthesizer, VoicevoxVoiceModel,
+    VoicevoxVoiceModelSet,
+};
 
 impl OpenJtalkRc {
     pub(crate) fn new(open_jtalk_dic_dir: impl AsRef<Utf8Path>) -> Result<Self> {
@@ -42,8 +45,38 @@ impl VoicevoxSynthesizer {
 impl VoicevoxVoiceModel {
     pub(crate) fn from_path(path: impl AsRef<Path>) -> Result<Self> {
         let model = voicevox_core::blocking::VoiceModel::from_path(path)?;
+        Ok(Self::new(model))
+    }
+
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let model = voicevox_core::blocking::VoiceModel::from_bytes(bytes)?;
+        Ok(Self::new(model))
+    }
+
+    fn new(model: voicevox_core::blocking::VoiceModel) -> Self {
         let id = CString::new(model.id().raw_voice_model_id().as_str()).unwrap();
         let metas = CString::new(serde_json::to_string(model.metas()).unwrap()).unwrap();
-        Ok(Self { model, id, metas })
+        Self { model, id, metas }
+    }
+}
+
+impl VoicevoxVoiceModelSet {
+    pub(crate) fn from_dirs<P: AsRef<Path>>(dirs: impl IntoIterator<Item = P>) -> Result<Self> {
+        let model_set = voicevox_core::blocking::VoiceModelSet::from_dirs(dirs)?;
+        let metas = CString::new(serde_json::to_string(model_set.metas()).unwrap()).unwrap();
+        let conflicts =
+            CString::new(serde_json::to_string(model_set.conflicts()).unwrap()).unwrap();
+        let errors = model_set
+            .errors()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let errors = CString::new(serde_json::to_string(&errors).unwrap()).unwrap();
+        Ok(Self {
+            model_set,
+            metas,
+            conflicts,
+            errors,
+        })
     }
 }
@@ -1,16 +1,14 @@
 use std::{
-    collections::BTreeMap,
     env,
     ffi::{c_char, CString},
+    path::PathBuf,
     sync::{Mutex, MutexGuard},
 };
 
 use libc::c_int;
 
 use once_cell::sync::Lazy;
-use voicevox_core::{
-    StyleId, SupportedDevices, VoiceModelId, __internal::interop::PerformInference as _,
-};
+use voicevox_core::{StyleId, SupportedDevices, __internal::interop::PerformInference as _};
 
 use crate::init_logger_once;
 
@@ -29,42 +27,20 @@ macro_rules! ensure_initialized {
 static ERROR_MESSAGE: Lazy<Mutex<String>> = Lazy::new(|| Mutex::new(String::new()));
 
 struct VoiceModelSet {
-    all_vvms: Vec<voicevox_core::blocking::VoiceModel>,
+    model_set: voicevox_core::blocking::VoiceModelSet,
     all_metas_json: CString,
-    style_model_map: BTreeMap<StyleId, VoiceModelId>,
-    model_map: BTreeMap<VoiceModelId, voicevox_core::blocking::VoiceModel>,
 }
 
 static VOICE_MODEL_SET: Lazy<VoiceModelSet> = Lazy::new(|| {
-    let all_vvms = get_all_models();
-    let model_map: BTreeMap<_, _> = all_vvms
-        .iter()
-        .map(|vvm| (vvm.id().clone(), vvm.clone()))
-        .collect();
-    let metas = voicevox_core::__internal::interop::merge_metas(
-        all_vvms.iter().flat_map(|vvm| vvm.metas()),
-    );
-    let mut style_model_map = BTreeMap::default();
-    for vvm in all_vvms.iter() {
-        for meta in vvm.metas().iter() {
-            for style in meta.styles().iter() {
-                style_model_map.insert(*style.id(), vvm.id().clone());
-            }
-        }
-    }
+    let model_set = voicevox_core::blocking::VoiceModelSet::from_dirs([root_dir()]).unwrap();
 
     return VoiceModelSet {
-        all_metas_json: CString::new(serde_json::to_string(&metas).unwrap()).unwrap(),
-        all_vvms,
-        style_model_map,
-        model_map,
+        all_metas_json: CString::new(serde_json::to_string(model_set.metas()).unwrap()).unwrap(),
+        model_set,
     };
 
-    /// # Panics
-    ///
-    /// 失敗したらパニックする
-    fn get_all_models() -> Vec<voicevox_core::blocking::VoiceModel> {
-        let root_dir = if let Some(root_dir) = env::var_os(ROOT_DIR_ENV_NAME) {
+    fn root_dir() -> PathBuf {
+        if let Some(root_dir) = env::var_os(ROOT_DIR_ENV_NAME) {
             root_dir.into()
         } else {
             process_path::get_dylib_path()
@@ -73,17 +49,7 @@ static VOICE_MODEL_SET: Lazy<VoiceModelSet> = Lazy::new(|| {
                 .parent()
                 .unwrap_or_else(|| "".as_ref())
                 .join("model")
-        };
-
-        root_dir
-            .read_dir()
-            .and_then(|entries| entries.collect::<std::result::Result<Vec<_>, _>>())
-            .unwrap_or_else(|e| panic!("{}が読めませんでした: {e}", root_dir.display()))
-            .into_iter()
-            .filter(|entry| entry.path().extension().map_or(false, |ext| ext == "vvm"))
-            .map(|entry| voicevox_core::blocking::VoiceModel::from_path(entry.path()))
-            .collect::<std::result::Result<_, _>>()
-            .unwrap()
+        }
     }
 
     const ROOT_DIR_ENV_NAME: &str = "VV_MODELS_ROOT_DIR";
@@ -122,11 +88,12 @@ pub extern "C" fn initialize(use_gpu: bool, cpu_num_threads: c_int, load_all_mod
                     voicevox_core::AccelerationMode::Cpu
                 },
                 cpu_num_threads: cpu_num_threads as u16,
+                ..Default::default()
             },
         )?;
 
         if load_all_models {
-            for model in &voice_model_set().all_vvms {
+            for (_, model) in voice_model_set().model_set.voice_models() {
                 synthesizer.load_voice_model(model)?;
             }
         }
@@ -151,8 +118,7 @@ pub extern "C" fn load_model(style_id: i64) -> bool {
     init_logger_once();
     let style_id = StyleId::new(style_id as u32);
     let model_set = voice_model_set();
-    if let Some(model_id) = model_set.style_model_map.get(&style_id) {
-        let vvm = model_set.model_map.get(model_id).unwrap();
+    if let Ok(vvm) = model_set.model_set.voice_model_by_style_id(style_id) {
         let synthesizer = &mut *lock_synthesizer();
         let result = ensure_initialized!(synthesizer).load_voice_model(vvm);
         if let Some(err) = result.err() {
@@ -8,7 +8,8 @@ use voicevox_core::AccentPhraseModel;
 
 use crate::{
     result_code::VoicevoxResultCode, VoicevoxAccelerationMode, VoicevoxInitializeOptions,
-    VoicevoxSynthesisOptions, VoicevoxTtsOptions, VoicevoxUserDictWord, VoicevoxUserDictWordType,
+    VoicevoxSignaturePolicy, VoicevoxSynthesisOptions, VoicevoxTtsOptions, VoicevoxUserDictWord,
+    VoicevoxUserDictWordType,
 };
 
 pub(crate) fn into_result_code_with_error(result: CApiResult<()>) -> VoicevoxResultCode {
@@ -35,15 +36,22 @@ pub(crate) fn into_result_code_with_error(result: CApiResult<()>) -> VoicevoxRes
             Err(RustApi(err)) => match err.kind() {
                 NotLoadedOpenjtalkDict => VOICEVOX_RESULT_NOT_LOADED_OPENJTALK_DICT_ERROR,
                 GpuSupport => VOICEVOX_RESULT_GPU_SUPPORT_ERROR,
+                GpuDeviceNotFound => VOICEVOX_RESULT_GPU_DEVICE_NOT_FOUND_ERROR,
                 OpenZipFile => VOICEVOX_RESULT_OPEN_ZIP_FILE_ERROR,
                 ReadZipEntry => VOICEVOX_RESULT_READ_ZIP_ENTRY_ERROR,
                 ModelAlreadyLoaded => VOICEVOX_RESULT_MODEL_ALREADY_LOADED_ERROR,
                 StyleAlreadyLoaded => VOICEVOX_RESULT_STYLE_ALREADY_LOADED_ERROR,
                 InvalidModelData => VOICEVOX_RESULT_INVALID_MODEL_DATA_ERROR,
+                UnsupportedManifestVersion => VOICEVOX_RESULT_UNSUPPORTED_MANIFEST_VERSION_ERROR,
+                UntrustedVoiceModel => VOICEVOX_RESULT_UNTRUSTED_VOICE_MODEL_ERROR,
+                ReadModelDir => VOICEVOX_RESULT_READ_MODEL_DIR_ERROR,
                 GetSupportedDevices => VOICEVOX_RESULT_GET_SUPPORTED_DEVICES_ERROR,
                 StyleNotFound => VOICEVOX_RESULT_STYLE_NOT_FOUND_ERROR,
                 ModelNotFound => VOICEVOX_RESULT_MODEL_NOT_FOUND_ERROR,
+                SpeakerNotFound => VOICEVOX_RESULT_SPEAKER_NOT_FOUND_ERROR,
+                OperationNotSupported => VOICEVOX_RESULT_OPERATION_NOT_SUPPORTED_ERROR,
                 InferenceFailed => VOICEVOX_RESULT_INFERENCE_ERROR,
+                WarmUpFailed => VOICEVOX_RESULT_WARM_UP_ERROR,
                 ExtractFullContextLabel => VOICEVOX_RESULT_EXTRACT_FULL_CONTEXT_LABEL_ERROR,
                 ParseKana => VOICEVOX_RESULT_PARSE_KANA_ERROR,
                 LoadUserDict => VOICEVOX_RESULT_LOAD_USER_DICT_ERROR,
@@ -51,6 +59,8 @@ pub(crate) fn into_result_code_with_error(result: CApiResult<()>) -> VoicevoxRes
                 WordNotFound => VOICEVOX_RESULT_USER_DICT_WORD_NOT_FOUND_ERROR,
                 UseUserDict => VOICEVOX_RESULT_USE_USER_DICT_ERROR,
                 InvalidWord => VOICEVOX_RESULT_INVALID_USER_DICT_WORD_ERROR,
+                WorkerCrashed => VOICEVOX_RESULT_WORKER_CRASHED_ERROR,
+                PackVvm => VOICEVOX_RESULT_PACK_VVM_ERROR,
             },
             Err(InvalidUtf8Input) => VOICEVOX_RESULT_INVALID_UTF8_INPUT_ERROR,
             Err(InvalidAudioQuery(_)) => VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR,
@@ -118,25 +128,73 @@ impl From<VoicevoxAccelerationMode> for voicevox_core::AccelerationMode {
     }
 }
 
+impl From<voicevox_core::SignaturePolicy> for VoicevoxSignaturePolicy {
+    fn from(policy: voicevox_core::SignaturePolicy) -> Self {
+        use voicevox_core::SignaturePolicy::*;
+        match policy {
+            Permissive => Self::VOICEVOX_SIGNATURE_POLICY_PERMISSIVE,
+            Strict => Self::VOICEVOX_SIGNATURE_POLICY_STRICT,
+        }
+    }
+}
+
+impl From<VoicevoxSignaturePolicy> for voicevox_core::SignaturePolicy {
+    fn from(policy: VoicevoxSignaturePolicy) -> Self {
+        use VoicevoxSignaturePolicy::*;
+        match policy {
+            VOICEVOX_SIGNATURE_POLICY_PERMISSIVE => Self::Permissive,
+            VOICEVOX_SIGNATURE_POLICY_STRICT => Self::Strict,
+        }
+    }
+}
+
 impl Default for VoicevoxInitializeOptions {
     fn default() -> Self {
         let options = voicevox_core::InitializeOptions::default();
         Self {
             acceleration_mode: options.acceleration_mode.into(),
             cpu_num_threads: options.cpu_num_threads,
+            gpu_device_index: options.gpu_device_index,
+            gpu_memory_limit: options.gpu_memory_limit,
+            max_sessions_per_operation: options.max_sessions_per_operation,
+            warm_up_on_load: options.warm_up_on_load,
+            signature_policy: options.signature_policy.into(),
+            trusted_public_keys: std::ptr::null(),
+            trusted_public_keys_length: 0,
         }
     }
 }
 
-impl From<VoicevoxInitializeOptions> for voicevox_core::InitializeOptions {
-    fn from(value: VoicevoxInitializeOptions) -> Self {
+impl VoicevoxInitializeOptions {
+    pub(crate) unsafe fn into_initialize_options(self) -> voicevox_core::InitializeOptions {
+        let trusted_public_keys = if self.trusted_public_keys_length == 0 {
+            vec![]
+        } else {
+            std::slice::from_raw_parts(
+                self.trusted_public_keys,
+                self.trusted_public_keys_length * PUBLIC_KEY_LENGTH,
+            )
+            .chunks_exact(PUBLIC_KEY_LENGTH)
+            .map(|key| key.try_into().expect("should be 32 bytes"))
+            .collect()
+        };
         voicevox_core::InitializeOptions {
-            acceleration_mode: value.acceleration_mode.into(),
-            cpu_num_threads: value.cpu_num_threads,
+            acceleration_mode: self.acceleration_mode.into(),
+            cpu_num_threads: self.cpu_num_threads,
+            gpu_device_index: self.gpu_device_index,
+            gpu_memory_limit: self.gpu_memory_limit,
+            max_sessions_per_operation: self.max_sessions_per_operation,
+            warm_up_on_load: self.warm_up_on_load,
+            signature_policy: self.signature_policy.into(),
+            trusted_public_keys,
+            ..Default::default()
         }
     }
 }
 
+/// Ed25519の公開鍵の長さ。
+const PUBLIC_KEY_LENGTH: usize = 32;
+
 impl From<voicevox_core::TtsOptions> for VoicevoxTtsOptions {
     fn from(options: voicevox_core::TtsOptions) -> Self {
         Self {
@@ -55,6 +55,24 @@ pub enum VoicevoxResultCode {
     VOICEVOX_RESULT_INVALID_USER_DICT_WORD_ERROR = 24,
     /// UUIDの変換に失敗した
     VOICEVOX_RESULT_INVALID_UUID_ERROR = 25,
+    /// 音声モデルのウォームアップに失敗した
+    VOICEVOX_RESULT_WARM_UP_ERROR = 28,
+    /// 音声モデルが推論操作のためのモデルを含んでいない、またはスタイルの種類が推論操作に対応していない
+    VOICEVOX_RESULT_OPERATION_NOT_SUPPORTED_ERROR = 29,
+    /// ワーカープロセスが異常終了した
+    VOICEVOX_RESULT_WORKER_CRASHED_ERROR = 30,
+    /// 音声モデルのマニフェストのバージョンに対応していない
+    VOICEVOX_RESULT_UNSUPPORTED_MANIFEST_VERSION_ERROR = 31,
+    /// 音声モデルの署名を検証できなかった、または署名者が信頼されていない
+    VOICEVOX_RESULT_UNTRUSTED_VOICE_MODEL_ERROR = 32,
+    /// 話者のUUIDに対する話者が見つからなかった
+    VOICEVOX_RESULT_SPEAKER_NOT_FOUND_ERROR = 33,
+    /// 音声モデルを探すディレクトリを読めなかった
+    VOICEVOX_RESULT_READ_MODEL_DIR_ERROR = 34,
+    /// 指定された番号のGPUが見つからなかった
+    VOICEVOX_RESULT_GPU_DEVICE_NOT_FOUND_ERROR = 35,
+    /// VVMを作成できなかった
+    VOICEVOX_RESULT_PACK_VVM_ERROR = 36,
 }
 
 pub(crate) const fn error_result_to_message(result_code: VoicevoxResultCode) -> &'static CStr {
@@ -107,5 +125,26 @@ pub(crate) const fn error_result_to_message(result_code: VoicevoxResultCode) ->
             cstr!("ユーザー辞書の単語のバリデーションに失敗しました")
         }
         VOICEVOX_RESULT_INVALID_UUID_ERROR => cstr!("UUIDの変換に失敗しました"),
+        VOICEVOX_RESULT_WARM_UP_ERROR => cstr!("音声モデルのウォームアップに失敗しました"),
+        VOICEVOX_RESULT_OPERATION_NOT_SUPPORTED_ERROR => {
+            cstr!("音声モデルが推論操作のためのモデルを含んでいないか、スタイルの種類が推論操作に対応していません")
+        }
+        VOICEVOX_RESULT_WORKER_CRASHED_ERROR => cstr!("ワーカープロセスが異常終了しました"),
+        VOICEVOX_RESULT_UNSUPPORTED_MANIFEST_VERSION_ERROR => {
+            cstr!("音声モデルのマニフェストのバージョンに対応していません")
+        }
+        VOICEVOX_RESULT_UNTRUSTED_VOICE_MODEL_ERROR => {
+            cstr!("音声モデルの署名を検証できないか、署名者が信頼されていません")
+        }
+        VOICEVOX_RESULT_SPEAKER_NOT_FOUND_ERROR => {
+            cstr!("指定されたUUIDに対する話者が見つかりませんでした")
+        }
+        VOICEVOX_RESULT_READ_MODEL_DIR_ERROR => {
+            cstr!("音声モデルを探すディレクトリを読めませんでした")
+        }
+        VOICEVOX_RESULT_GPU_DEVICE_NOT_FOUND_ERROR => {
+            cstr!("指定された番号のGPUが見つかりませんでした")
+        }
+        VOICEVOX_RESULT_PACK_VVM_ERROR => cstr!("VVMを作成できませんでした"),
     }
 }
@@ -22,12 +22,65 @@ pub(crate) struct Symbols<'lib> {
         'lib,
         unsafe extern "C" fn(*const c_char, *mut *mut VoicevoxVoiceModel) -> VoicevoxResultCode,
     >,
+    pub(crate) voicevox_voice_model_new_from_bytes: Symbol<
+        'lib,
+        unsafe extern "C" fn(*const u8, usize, *mut *mut VoicevoxVoiceModel) -> VoicevoxResultCode,
+    >,
     pub(crate) voicevox_voice_model_id:
         Symbol<'lib, unsafe extern "C" fn(*const VoicevoxVoiceModel) -> VoicevoxVoiceModelId>,
     pub(crate) voicevox_voice_model_get_metas_json:
         Symbol<'lib, unsafe extern "C" fn(*const VoicevoxVoiceModel) -> *const c_char>,
+    pub(crate) voicevox_voice_model_create_speaker_info_json: Symbol<
+        'lib,
+        unsafe extern "C" fn(
+            *const VoicevoxVoiceModel,
+            *const c_char,
+            *mut *mut c_char,
+        ) -> VoicevoxResultCode,
+    >,
+    pub(crate) voicevox_voice_model_resolve_style_id: Symbol<
+        'lib,
+        unsafe extern "C" fn(
+            *const VoicevoxVoiceModel,
+            *const c_char,
+            *const c_char,
+            *mut VoicevoxStyleId,
+        ) -> VoicevoxResultCode,
+    >,
+    pub(crate) voicevox_voice_model_create_style_key_json: Symbol<
+        'lib,
+        unsafe extern "C" fn(
+            *const VoicevoxVoiceModel,
+            VoicevoxStyleId,
+            *mut *mut c_char,
+        ) -> VoicevoxResultCode,
+    >,
+    pub(crate) voicevox_voice_model_search_styles_json: Symbol<
+        'lib,
+        unsafe extern "C" fn(
+            *const VoicevoxVoiceModel,
+            *const c_char,
+            *mut *mut c_char,
+        ) -> VoicevoxResultCode,
+    >,
     pub(crate) voicevox_voice_model_delete:
         Symbol<'lib, unsafe extern "C" fn(*mut VoicevoxVoiceModel)>,
+    pub(crate) voicevox_voice_model_set_new_from_dirs: Symbol<
+        'lib,
+        unsafe extern "C" fn(
+            *const *const c_char,
+            usize,
+            *mut *mut VoicevoxVoiceModelSet,
+        ) -> VoicevoxResultCode,
+    >,
+    pub(crate) voicevox_voice_model_set_get_metas_json:
+        Symbol<'lib, unsafe extern "C" fn(*const VoicevoxVoiceModelSet) -> *const c_char>,
+    pub(crate) voicevox_voice_model_set_get_conflicts_json:
+        Symbol<'lib, unsafe extern "C" fn(*const VoicevoxVoiceModelSet) -> *const c_char>,
+    pub(crate) voicevox_voice_model_set_get_errors_json:
+        Symbol<'lib, unsafe extern "C" fn(*const VoicevoxVoiceModelSet) -> *const c_char>,
+    pub(crate) voicevox_voice_model_set_delete:
+        Symbol<'lib, unsafe extern "C" fn(*mut VoicevoxVoiceModelSet)>,
     pub(crate) voicevox_synthesizer_new: Symbol<
         'lib,
         unsafe extern "C" fn(
@@ -45,10 +98,25 @@ pub(crate) struct Symbols<'lib> {
             *const VoicevoxVoiceModel,
         ) -> VoicevoxResultCode,
     >,
+    pub(crate) voicevox_synthesizer_load_voice_model_by_style_id: Symbol<
+        'lib,
+        unsafe extern "C" fn(
+            *mut VoicevoxSynthesizer,
+            *const VoicevoxVoiceModelSet,
+            VoicevoxStyleId,
+        ) -> VoicevoxResultCode,
+    >,
     pub(crate) voicevox_synthesizer_unload_voice_model: Symbol<
         'lib,
         unsafe extern "C" fn(*mut VoicevoxSynthesizer, VoicevoxVoiceModelId) -> VoicevoxResultCode,
     >,
+    pub(crate) voicevox_synthesizer_warm_up: Symbol<
+        'lib,
+        unsafe extern "C" fn(
+            *const VoicevoxSynthesizer,
+            VoicevoxVoiceModelId,
+        ) -> VoicevoxResultCode,
+    >,
     pub(crate) voicevox_synthesizer_is_gpu_mode:
         Symbol<'lib, unsafe extern "C" fn(*const VoicevoxSynthesizer) -> bool>,
     pub(crate) voicevox_synthesizer_is_loaded_voice_model: Symbol<
@@ -57,6 +125,39 @@ pub(crate) struct Symbols<'lib> {
     >,
     pub(crate) voicevox_synthesizer_create_metas_json:
         Symbol<'lib, unsafe extern "C" fn(*const VoicevoxSynthesizer) -> *mut c_char>,
+    pub(crate) voicevox_synthesizer_create_speaker_info_json: Symbol<
+        'lib,
+        unsafe extern "C" fn(
+            *const VoicevoxSynthesizer,
+            *const c_char,
+            *mut *mut c_char,
+        ) -> VoicevoxResultCode,
+    >,
+    pub(crate) voicevox_synthesizer_resolve_style_id: Symbol<
+        'lib,
+        unsafe extern "C" fn(
+            *const VoicevoxSynthesizer,
+            *const c_char,
+            *const c_char,
+            *mut VoicevoxStyleId,
+        ) -> VoicevoxResultCode,
+    >,
+    pub(crate) voicevox_synthesizer_create_style_key_json: Symbol<
+        'lib,
+        unsafe extern "C" fn(
+            *const VoicevoxSynthesizer,
+            VoicevoxStyleId,
+            *mut *mut c_char,
+        ) -> VoicevoxResultCode,
+    >,
+    pub(crate) voicevox_synthesizer_search_styles_json: Symbol<
+        'lib,
+        unsafe extern "C" fn(
+            *const VoicevoxSynthesizer,
+            *const c_char,
+            *mut *mut c_char,
+        ) -> VoicevoxResultCode,
+    >,
     pub(crate) voicevox_create_supported_devices_json:
         Symbol<'lib, unsafe extern "C" fn(*mut *mut c_char) -> VoicevoxResultCode>,
     pub(crate) voicevox_synthesizer_create_audio_query_from_kana: Symbol<
@@ -211,16 +312,32 @@ impl<'lib> Symbols<'lib> {
             voicevox_make_default_initialize_options,
             voicevox_get_version,
             voicevox_voice_model_new_from_path,
+            voicevox_voice_model_new_from_bytes,
             voicevox_voice_model_id,
             voicevox_voice_model_get_metas_json,
+            voicevox_voice_model_create_speaker_info_json,
+            voicevox_voice_model_resolve_style_id,
+            voicevox_voice_model_create_style_key_json,
+            voicevox_voice_model_search_styles_json,
             voicevox_voice_model_delete,
+            voicevox_voice_model_set_new_from_dirs,
+            voicevox_voice_model_set_get_metas_json,
+            voicevox_voice_model_set_get_conflicts_json,
+            voicevox_voice_model_set_get_errors_json,
+            voicevox_voice_model_set_delete,
             voicevox_synthesizer_new,
             voicevox_synthesizer_delete,
             voicevox_synthesizer_load_voice_model,
+            voicevox_synthesizer_load_voice_model_by_style_id,
             voicevox_synthesizer_unload_voice_model,
+            voicevox_synthesizer_warm_up,
             voicevox_synthesizer_is_gpu_mode,
             voicevox_synthesizer_is_loaded_voice_model,
             voicevox_synthesizer_create_metas_json,
+            voicevox_synthesizer_create_speaker_info_json,
+            voicevox_synthesizer_resolve_style_id,
+            voicevox_synthesizer_create_style_key_json,
+            voicevox_synthesizer_search_styles_json,
             voicevox_create_supported_devices_json,
             voicevox_synthesizer_create_audio_query_from_kana,
             voicevox_synthesizer_create_audio_query,
@@ -258,6 +375,7 @@ impl<'lib> Symbols<'lib> {
 
 type OpenJtalkRc = c_void;
 type VoicevoxVoiceModel = c_void;
+type VoicevoxVoiceModelSet = c_void;
 type VoicevoxVoiceModelId = *const c_char;
 type VoicevoxSynthesizer = c_void;
 type VoicevoxStyleId = u32;
@@ -289,6 +407,15 @@ pub(crate) enum VoicevoxResultCode {
     VOICEVOX_RESULT_USE_USER_DICT_ERROR = 23,
     VOICEVOX_RESULT_INVALID_USER_DICT_WORD_ERROR = 24,
     VOICEVOX_RESULT_INVALID_UUID_ERROR = 25,
+    VOICEVOX_RESULT_WARM_UP_ERROR = 28,
+    VOICEVOX_RESULT_OPERATION_NOT_SUPPORTED_ERROR = 29,
+    VOICEVOX_RESULT_WORKER_CRASHED_ERROR = 30,
+    VOICEVOX_RESULT_UNSUPPORTED_MANIFEST_VERSION_ERROR = 31,
+    VOICEVOX_RESULT_UNTRUSTED_VOICE_MODEL_ERROR = 32,
+    VOICEVOX_RESULT_SPEAKER_NOT_FOUND_ERROR = 33,
+    VOICEVOX_RESULT_READ_MODEL_DIR_ERROR = 34,
+    VOICEVOX_RESULT_GPU_DEVICE_NOT_FOUND_ERROR = 35,
+    VOICEVOX_RESULT_PACK_VVM_ERROR = 36,
 }
 
 #[repr(i32)]
@@ -297,10 +424,23 @@ pub(crate) enum VoicevoxAccelerationMode {
     VOICEVOX_ACCELERATION_MODE_CPU = 1,
 }
 
+#[repr(i32)]
+#[allow(non_camel_case_types)]
+pub(crate) enum VoicevoxSignaturePolicy {
+    _VOICEVOX_SIGNATURE_POLICY_PERMISSIVE = 0,
+}
+
 #[repr(C)]
 pub(crate) struct VoicevoxInitializeOptions {
     pub(crate) acceleration_mode: VoicevoxAccelerationMode,
     pub(crate) _cpu_num_threads: u16,
+    pub(crate) _gpu_device_index: u16,
+    pub(crate) _gpu_memory_limit: usize,
+    pub(crate) _max_sessions_per_operation: usize,
+    pub(crate) _warm_up_on_load: bool,
+    pub(crate) _signature_policy: VoicevoxSignaturePolicy,
+    pub(crate) _trusted_public_keys: *const u8,
+    pub(crate) _trusted_public_keys_length: usize,
 }
 
 #[repr(C)]
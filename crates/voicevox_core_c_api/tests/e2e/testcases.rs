@@ -2,6 +2,7 @@ mod compatible_engine;
 mod compatible_engine_load_model_before_initialize;
 mod global_info;
 mod simple_tts;
+mod style_resolver;
 mod synthesizer_new_output_json;
 mod tts_via_audio_query;
 mod user_dict_load;
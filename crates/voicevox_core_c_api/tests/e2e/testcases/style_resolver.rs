@@ -0,0 +1,212 @@
+// スタイルIDと、話者のUUIDとスタイル名の対応を引けることを確認するテスト。
+// 音声モデルと音声シンセサイザのそれぞれで、往復して同じスタイルに戻ることと、スタイル名が
+// 見つからないときのエラーに候補が挙げられることを確認する。
+
+use std::{
+    ffi::{c_char, CStr, CString},
+    mem::MaybeUninit,
+};
+
+use assert_cmd::assert::AssertResult;
+use cstr::cstr;
+use libloading::Library;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use test_util::OPEN_JTALK_DIC_DIR;
+
+use crate::{
+    assert_cdylib::{self, case, Utf8Output},
+    snapshots,
+    symbols::{Symbols, VoicevoxAccelerationMode, VoicevoxInitializeOptions, VoicevoxResultCode},
+};
+
+case!(TestCase);
+
+#[derive(Serialize, Deserialize)]
+struct TestCase;
+
+#[typetag::serde(name = "style_resolver")]
+impl assert_cdylib::TestCase for TestCase {
+    unsafe fn exec(&self, lib: &Library) -> anyhow::Result<()> {
+        let Symbols {
+            voicevox_make_default_initialize_options,
+            voicevox_open_jtalk_rc_new,
+            voicevox_open_jtalk_rc_delete,
+            voicevox_voice_model_new_from_path,
+            voicevox_voice_model_resolve_style_id,
+            voicevox_voice_model_create_style_key_json,
+            voicevox_voice_model_search_styles_json,
+            voicevox_voice_model_delete,
+            voicevox_synthesizer_new,
+            voicevox_synthesizer_delete,
+            voicevox_synthesizer_load_voice_model,
+            voicevox_synthesizer_resolve_style_id,
+            voicevox_synthesizer_create_style_key_json,
+            voicevox_synthesizer_search_styles_json,
+            voicevox_json_free,
+            ..
+        } = Symbols::new(lib)?;
+
+        let model = {
+            let mut model = MaybeUninit::uninit();
+            assert_ok(voicevox_voice_model_new_from_path(
+                cstr!("../../model/sample.vvm").as_ptr(),
+                model.as_mut_ptr(),
+            ));
+            model.assume_init()
+        };
+
+        let openjtalk = {
+            let mut openjtalk = MaybeUninit::uninit();
+            let open_jtalk_dic_dir = CString::new(OPEN_JTALK_DIC_DIR).unwrap();
+            assert_ok(voicevox_open_jtalk_rc_new(
+                open_jtalk_dic_dir.as_ptr(),
+                openjtalk.as_mut_ptr(),
+            ));
+            openjtalk.assume_init()
+        };
+
+        let synthesizer = {
+            let mut synthesizer = MaybeUninit::uninit();
+            assert_ok(voicevox_synthesizer_new(
+                openjtalk,
+                VoicevoxInitializeOptions {
+                    acceleration_mode: VoicevoxAccelerationMode::VOICEVOX_ACCELERATION_MODE_CPU,
+                    ..voicevox_make_default_initialize_options()
+                },
+                synthesizer.as_mut_ptr(),
+            ));
+            synthesizer.assume_init()
+        };
+
+        assert_ok(voicevox_synthesizer_load_voice_model(synthesizer, model));
+
+        // 音声モデル
+        {
+            let mut style_id = 0;
+            assert_ok(voicevox_voice_model_resolve_style_id(
+                model,
+                SPEAKER_UUID.as_ptr(),
+                cstr!("style3-2").as_ptr(),
+                &mut style_id,
+            ));
+            std::assert_eq!(303, style_id);
+
+            let mut style_key_json = std::ptr::null_mut();
+            assert_ok(voicevox_voice_model_create_style_key_json(
+                model,
+                style_id,
+                &mut style_key_json,
+            ));
+            std::assert_eq!(expected_style_key(), parse_json(style_key_json)?);
+            voicevox_json_free(style_key_json);
+
+            std::assert_eq!(
+                VoicevoxResultCode::VOICEVOX_RESULT_STYLE_NOT_FOUND_ERROR,
+                voicevox_voice_model_resolve_style_id(
+                    model,
+                    SPEAKER_UUID.as_ptr(),
+                    cstr!("style3-3").as_ptr(),
+                    &mut style_id,
+                ),
+            );
+
+            let mut candidates_json = std::ptr::null_mut();
+            assert_ok(voicevox_voice_model_search_styles_json(
+                model,
+                cstr!("style3-2").as_ptr(),
+                &mut candidates_json,
+            ));
+            assert_best_candidate(parse_json(candidates_json)?);
+            voicevox_json_free(candidates_json);
+        }
+
+        // 音声シンセサイザ
+        {
+            let mut style_id = 0;
+            assert_ok(voicevox_synthesizer_resolve_style_id(
+                synthesizer,
+                SPEAKER_UUID.as_ptr(),
+                cstr!("style3-2").as_ptr(),
+                &mut style_id,
+            ));
+            std::assert_eq!(303, style_id);
+
+            let mut style_key_json = std::ptr::null_mut();
+            assert_ok(voicevox_synthesizer_create_style_key_json(
+                synthesizer,
+                style_id,
+                &mut style_key_json,
+            ));
+            std::assert_eq!(expected_style_key(), parse_json(style_key_json)?);
+            voicevox_json_free(style_key_json);
+
+            std::assert_eq!(
+                VoicevoxResultCode::VOICEVOX_RESULT_STYLE_NOT_FOUND_ERROR,
+                voicevox_synthesizer_resolve_style_id(
+                    synthesizer,
+                    SPEAKER_UUID.as_ptr(),
+                    cstr!("style3-3").as_ptr(),
+                    &mut style_id,
+                ),
+            );
+
+            let mut candidates_json = std::ptr::null_mut();
+            assert_ok(voicevox_synthesizer_search_styles_json(
+                synthesizer,
+                cstr!("style3-2").as_ptr(),
+                &mut candidates_json,
+            ));
+            assert_best_candidate(parse_json(candidates_json)?);
+            voicevox_json_free(candidates_json);
+        }
+
+        voicevox_voice_model_delete(model);
+        voicevox_open_jtalk_rc_delete(openjtalk);
+        voicevox_synthesizer_delete(synthesizer);
+
+        return Ok(());
+
+        const SPEAKER_UUID: &CStr = cstr!("5d3d9aa9-88e5-4a96-8ef7-f13a3cad1cb3");
+
+        fn expected_style_key() -> serde_json::Value {
+            json!({
+                "speaker_uuid": "5d3d9aa9-88e5-4a96-8ef7-f13a3cad1cb3",
+                "style_name": "style3-2",
+            })
+        }
+
+        fn assert_best_candidate(candidates: serde_json::Value) {
+            let best = &candidates[0];
+            std::assert_eq!(303, best["style_id"]);
+            std::assert_eq!(1., best["score"]);
+        }
+
+        unsafe fn parse_json(json: *const c_char) -> anyhow::Result<serde_json::Value> {
+            Ok(serde_json::from_str(CStr::from_ptr(json).to_str()?)?)
+        }
+
+        fn assert_ok(result_code: VoicevoxResultCode) {
+            std::assert_eq!(VoicevoxResultCode::VOICEVOX_RESULT_OK, result_code);
+        }
+    }
+
+    fn assert_output(&self, output: Utf8Output) -> AssertResult {
+        output
+            .mask_timestamps()
+            .mask_windows_video_cards()
+            .assert()
+            .try_success()?
+            .try_stdout("")?
+            .try_stderr(&*SNAPSHOTS.stderr)
+    }
+}
+
+static SNAPSHOTS: Lazy<Snapshots> = snapshots::section!(style_resolver);
+
+#[derive(Deserialize)]
+struct Snapshots {
+    #[serde(deserialize_with = "snapshots::deserialize_platform_specific_snapshot")]
+    stderr: String,
+}
@@ -0,0 +1,99 @@
+package jp.hiroshiba.voicevoxcore;
+
+import com.google.gson.Gson;
+import com.google.gson.GsonBuilder;
+import com.google.gson.TypeAdapter;
+import com.google.gson.annotations.Expose;
+import com.google.gson.annotations.SerializedName;
+import com.google.gson.stream.JsonReader;
+import com.google.gson.stream.JsonToken;
+import com.google.gson.stream.JsonWriter;
+import jakarta.annotation.Nonnull;
+import jakarta.annotation.Nullable;
+import java.io.IOException;
+import java.util.Base64;
+
+/** 話者（speaker）の追加情報。 */
+public class SpeakerInfo {
+  /** 利用規約。Markdown形式。 */
+  @SerializedName("policy")
+  @Expose
+  @Nullable
+  public final String policy;
+
+  /** 立ち絵の画像。 */
+  @SerializedName("portrait")
+  @Expose
+  @Nullable
+  public final byte[] portrait;
+
+  /** スタイルごとの追加情報。{@link VoiceModel.SpeakerMeta#styles}と同じ順で並ぶ。 */
+  @SerializedName("style_infos")
+  @Expose
+  @Nonnull
+  public final StyleInfo[] styleInfos;
+
+  private SpeakerInfo() {
+    // GSONからコンストラクトするため、このメソッドは呼ばれることは無い。
+    // このメソッドは@Nonnullを満たすために必要。
+    this.policy = null;
+    this.portrait = null;
+    this.styleInfos = new StyleInfo[0];
+  }
+
+  @Nonnull
+  static SpeakerInfo parseJson(String speakerInfoJson) {
+    Gson gson = new GsonBuilder().registerTypeAdapter(byte[].class, new Base64Adapter()).create();
+    SpeakerInfo speakerInfo = gson.fromJson(speakerInfoJson, SpeakerInfo.class);
+    if (speakerInfo == null) {
+      throw new NullPointerException("speakerInfo");
+    }
+    return speakerInfo;
+  }
+
+  /** スタイル（style）の追加情報。 */
+  public static class StyleInfo {
+    /** スタイルID。 */
+    @SerializedName("id")
+    @Expose
+    public final int id;
+
+    /** アイコンの画像。 */
+    @SerializedName("icon")
+    @Expose
+    @Nullable
+    public final byte[] icon;
+
+    /** 音声サンプル。WAVの列。 */
+    @SerializedName("voice_samples")
+    @Expose
+    @Nonnull
+    public final byte[][] voiceSamples;
+
+    private StyleInfo() {
+      this.id = 0;
+      this.icon = null;
+      this.voiceSamples = new byte[0][];
+    }
+  }
+
+  private static class Base64Adapter extends TypeAdapter<byte[]> {
+    @Override
+    public void write(JsonWriter out, byte[] value) throws IOException {
+      if (value == null) {
+        out.nullValue();
+        return;
+      }
+      out.value(Base64.getEncoder().encodeToString(value));
+    }
+
+    @Override
+    public byte[] read(JsonReader in) throws IOException {
+      if (in.peek() == JsonToken.NULL) {
+        in.nextNull();
+        return null;
+      }
+      return Base64.getDecoder().decode(in.nextString());
+    }
+  }
+}
@@ -0,0 +1,57 @@
+package jp.hiroshiba.voicevoxcore;
+
+import com.google.gson.Gson;
+import com.google.gson.annotations.Expose;
+import com.google.gson.annotations.SerializedName;
+import jakarta.annotation.Nonnull;
+
+/** スタイルの検索結果。 */
+public class StyleCandidate {
+  /** 話者名。 */
+  @SerializedName("speaker_name")
+  @Expose
+  @Nonnull
+  public final String speakerName;
+
+  /** 話者のUUID。 */
+  @SerializedName("speaker_uuid")
+  @Expose
+  @Nonnull
+  public final String speakerUuid;
+
+  /** スタイル名。 */
+  @SerializedName("style_name")
+  @Expose
+  @Nonnull
+  public final String styleName;
+
+  /** スタイルID。 */
+  @SerializedName("style_id")
+  @Expose
+  public final int styleId;
+
+  /** 検索語との近さ。0以上1以下で、完全に一致するときに1となる。 */
+  @SerializedName("score")
+  @Expose
+  public final double score;
+
+  private StyleCandidate() {
+    // GSONからコンストラクトするため、このメソッドは呼ばれることは無い。
+    // このメソッドは@Nonnullを満たすために必要。
+    this.speakerName = "";
+    this.speakerUuid = "";
+    this.styleName = "";
+    this.styleId = 0;
+    this.score = 0;
+  }
+
+  @Nonnull
+  static StyleCandidate[] parseJson(String candidatesJson) {
+    Gson gson = new Gson();
+    StyleCandidate[] candidates = gson.fromJson(candidatesJson, StyleCandidate[].class);
+    if (candidates == null) {
+      throw new NullPointerException("candidates");
+    }
+    return candidates;
+  }
+}
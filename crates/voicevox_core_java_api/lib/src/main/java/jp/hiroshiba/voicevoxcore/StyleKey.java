@@ -0,0 +1,47 @@
+package jp.hiroshiba.voicevoxcore;
+
+import com.google.gson.Gson;
+import com.google.gson.annotations.Expose;
+import com.google.gson.annotations.SerializedName;
+import jakarta.annotation.Nonnull;
+
+/**
+ * 話者のUUIDとスタイル名の組。
+ *
+ * <p>スタイルIDと異なり、音声モデルの配布物によらずスタイルを指す。
+ */
+public class StyleKey {
+  /** 話者のUUID。 */
+  @SerializedName("speaker_uuid")
+  @Expose
+  @Nonnull
+  public final String speakerUuid;
+
+  /** スタイル名。 */
+  @SerializedName("style_name")
+  @Expose
+  @Nonnull
+  public final String styleName;
+
+  public StyleKey(@Nonnull String speakerUuid, @Nonnull String styleName) {
+    this.speakerUuid = speakerUuid;
+    this.styleName = styleName;
+  }
+
+  private StyleKey() {
+    // GSONからコンストラクトするため、このメソッドは呼ばれることは無い。
+    // このメソッドは@Nonnullを満たすために必要。
+    this.speakerUuid = "";
+    this.styleName = "";
+  }
+
+  @Nonnull
+  static StyleKey parseJson(String styleKeyJson) {
+    Gson gson = new Gson();
+    StyleKey styleKey = gson.fromJson(styleKeyJson, StyleKey.class);
+    if (styleKey == null) {
+      throw new NullPointerException("styleKey");
+    }
+    return styleKey;
+  }
+}
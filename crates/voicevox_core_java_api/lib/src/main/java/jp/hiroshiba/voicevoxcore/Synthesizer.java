@@ -7,6 +7,10 @@ import java.util.Arrays;
 import java.util.List;
 import jp.hiroshiba.voicevoxcore.exceptions.InferenceFailedException;
 import jp.hiroshiba.voicevoxcore.exceptions.InvalidModelDataException;
+import jp.hiroshiba.voicevoxcore.exceptions.SpeakerNotFoundException;
+import jp.hiroshiba.voicevoxcore.exceptions.StyleNotFoundException;
+import jp.hiroshiba.voicevoxcore.exceptions.UntrustedVoiceModelException;
+import jp.hiroshiba.voicevoxcore.exceptions.WarmUpFailedException;
 
 /**
  * 音声シンセサイザ。
@@ -37,6 +41,8 @@ public class Synthesizer extends Dll {
   /**
    * メタ情報を取得する。
    *
+   * <p>スタイルの種類で絞り込むには、各スタイルの {@link VoiceModel.StyleMeta#type} を見る。
+   *
    * @return メタ情報。
    */
   @Nonnull
@@ -50,9 +56,68 @@ public class Synthesizer extends Dll {
     return rawMetas;
   }
 
+  /**
+   * 話者の追加情報を取得する。
+   *
+   * <p>同じ話者を含む音声モデルが複数読み込まれている場合、それらの情報はまとめられる。
+   *
+   * @param speakerUuid 話者のUUID。
+   * @return 話者の追加情報。
+   * @throws SpeakerNotFoundException 話者が見つからなかった場合。
+   */
+  @Nonnull
+  public SpeakerInfo speakerInfo(String speakerUuid) {
+    return SpeakerInfo.parseJson(rsGetSpeakerInfoJson(speakerUuid));
+  }
+
+  /**
+   * 話者のUUIDとスタイル名から、スタイルIDを得る。
+   *
+   * <p>スタイル名は完全に一致する必要がある。
+   *
+   * @param speakerUuid 話者のUUID。
+   * @param styleName スタイル名。
+   * @return スタイルID。
+   * @throws SpeakerNotFoundException 話者が見つからなかった場合。
+   * @throws StyleNotFoundException スタイルが見つからなかった場合。エラーメッセージには候補となるスタイル名が
+   *     挙げられる。
+   */
+  public int resolveStyleId(String speakerUuid, String styleName) {
+    return rsResolveStyleId(speakerUuid, styleName);
+  }
+
+  /**
+   * スタイルIDから、話者のUUIDとスタイル名を得る。
+   *
+   * @param styleId スタイルID。
+   * @return 話者のUUIDとスタイル名の組。
+   * @throws StyleNotFoundException スタイルが見つからなかった場合。
+   */
+  @Nonnull
+  public StyleKey resolveStyleKey(int styleId) {
+    return StyleKey.parseJson(rsGetStyleKeyJson(styleId));
+  }
+
+  /**
+   * 話者名とスタイル名から、スタイルをあいまいに検索する。
+   *
+   * <p>{@code query}は空白で区切られた語の列として扱われ、それぞれの語が話者名かスタイル名のいずれかに近い
+   * スタイルが、近い順に返される。
+   *
+   * @param query 検索語。
+   * @return 検索結果。
+   */
+  @Nonnull
+  public StyleCandidate[] searchStyles(String query) {
+    return StyleCandidate.parseJson(rsSearchStylesJson(query));
+  }
+
   /**
    * モデルを読み込む。
    *
+   * <p>{@link SignaturePolicy#STRICT} の場合に信頼されていない音声モデルを読み込もうとすると、{@link
+   * UntrustedVoiceModelException} が投げられる。
+   *
    * @param voiceModel 読み込むモデル。
    * @throws InvalidModelDataException 無効なモデルデータの場合。
    */
@@ -60,6 +125,22 @@ public class Synthesizer extends Dll {
     rsLoadVoiceModel(voiceModel);
   }
 
+  /**
+   * 音声モデルの集合から、スタイルIDに対するスタイルを含む音声モデルを読み込む。
+   *
+   * <p>そのスタイルが既に読み込まれている場合は何もしない。他のスレッドが同時に同じ音声モデルを読み込んだ場合も
+   * 同様に扱う。
+   *
+   * @param voiceModelSet 音声モデルの集合。
+   * @param styleId スタイルID。
+   * @throws StyleNotFoundException 音声モデルの集合がそのスタイルを含まない場合。
+   * @throws InvalidModelDataException 無効なモデルデータの場合。
+   */
+  public void loadVoiceModelByStyleId(VoiceModelSet voiceModelSet, int styleId)
+      throws InvalidModelDataException {
+    rsLoadVoiceModelByStyleId(voiceModelSet, styleId);
+  }
+
   /**
    * 音声モデルの読み込みを解除する。
    *
@@ -69,6 +150,20 @@ public class Synthesizer extends Dll {
     rsUnloadVoiceModel(voiceModelId);
   }
 
+  /**
+   * 音声モデルのウォームアップを行う。
+   *
+   * <p>音声モデルのそれぞれの推論を代表的なダミーの入力で一度ずつ実行し、読み込み直後の音声合成の遅延を前もって済ませる。併せて、推論結果が期待される長さを持ち、かつ有限の値のみから成るかを検査する。
+   *
+   * <p>ここでの推論は統計には記録されない。
+   *
+   * @param voiceModelId 音声モデルのID。
+   * @throws WarmUpFailedException 推論に失敗したとき、または推論結果が不正だったとき。
+   */
+  public void warmUp(String voiceModelId) throws WarmUpFailedException {
+    rsWarmUp(voiceModelId);
+  }
+
   /**
    * 指定した音声モデルのIDが読み込まれているかどうかを返す。
    *
@@ -272,10 +367,26 @@ public class Synthesizer extends Dll {
   @Nonnull
   private native String rsGetMetasJson();
 
+  @Nonnull
+  private native String rsGetSpeakerInfoJson(String speakerUuid);
+
+  private native int rsResolveStyleId(String speakerUuid, String styleName);
+
+  @Nonnull
+  private native String rsGetStyleKeyJson(int styleId);
+
+  @Nonnull
+  private native String rsSearchStylesJson(String query);
+
   private native void rsLoadVoiceModel(VoiceModel voiceModel) throws InvalidModelDataException;
 
+  private native void rsLoadVoiceModelByStyleId(VoiceModelSet voiceModelSet, int styleId)
+      throws InvalidModelDataException;
+
   private native void rsUnloadVoiceModel(String voiceModelId);
 
+  private native void rsWarmUp(String voiceModelId) throws WarmUpFailedException;
+
   private native boolean rsIsLoadedVoiceModel(String voiceModelId);
 
   @Nonnull
@@ -337,6 +448,24 @@ public class Synthesizer extends Dll {
     @SuppressWarnings("unused")
     private int cpuNumThreads;
 
+    @SuppressWarnings("unused")
+    private int gpuDeviceIndex;
+
+    @SuppressWarnings("unused")
+    private long gpuMemoryLimit;
+
+    @SuppressWarnings("unused")
+    private int maxSessionsPerOperation;
+
+    @SuppressWarnings("unused")
+    private boolean warmUpOnLoad;
+
+    @SuppressWarnings("unused")
+    private SignaturePolicy signaturePolicy;
+
+    @SuppressWarnings("unused")
+    private byte[][] trustedPublicKeys;
+
     public Builder(OpenJtalk openJtalk) {
       this.openJtalk = openJtalk;
     }
@@ -366,6 +495,94 @@ public class Synthesizer extends Dll {
       return this;
     }
 
+    /**
+     * GPUを使うときの、GPUの番号を指定する。
+     *
+     * <p>CUDAではCUDAのデバイス番号、DirectMLではDXGIのアダプタの列挙順の番号となる。
+     *
+     * @param gpuDeviceIndex GPUの番号。
+     * @return ビルダー。
+     */
+    public Builder gpuDeviceIndex(int gpuDeviceIndex) {
+      if (!Utils.isU16(gpuDeviceIndex)) {
+        throw new IllegalArgumentException("gpuDeviceIndex");
+      }
+      this.gpuDeviceIndex = gpuDeviceIndex;
+      return this;
+    }
+
+    /**
+     * GPUを使うときの、GPUメモリの使用量の上限を指定する。0を指定すると上限を設けない。CUDAでのみ有効。
+     *
+     * @param gpuMemoryLimit GPUメモリの使用量の上限(バイト)。
+     * @return ビルダー。
+     */
+    public Builder gpuMemoryLimit(long gpuMemoryLimit) {
+      if (gpuMemoryLimit < 0) {
+        throw new IllegalArgumentException("gpuMemoryLimit");
+      }
+      this.gpuMemoryLimit = gpuMemoryLimit;
+      return this;
+    }
+
+    /**
+     * 一つの音声モデルの一つの推論操作につき、同時に保持するセッションの最大数を指定する。
+     *
+     * <p>同じ音声モデルに対する並列な音声合成は、この数まで同時に推論を行える。0を指定すると1として扱う。
+     *
+     * @param maxSessionsPerOperation セッションの最大数。
+     * @return ビルダー。
+     */
+    public Builder maxSessionsPerOperation(int maxSessionsPerOperation) {
+      if (maxSessionsPerOperation < 0) {
+        throw new IllegalArgumentException("maxSessionsPerOperation");
+      }
+      this.maxSessionsPerOperation = maxSessionsPerOperation;
+      return this;
+    }
+
+    /**
+     * 音声モデルの読み込み時に {@link Synthesizer#warmUp} を行うかどうかを指定する。
+     *
+     * <p>ウォームアップに失敗した場合、読み込みは取り消され、読み込みの関数から {@link WarmUpFailedException} が投げられる。
+     *
+     * @param warmUpOnLoad 読み込み時にウォームアップを行うかどうか。
+     * @return ビルダー。
+     */
+    public Builder warmUpOnLoad(boolean warmUpOnLoad) {
+      this.warmUpOnLoad = warmUpOnLoad;
+      return this;
+    }
+
+    /**
+     * 音声モデルの署名に対する方針を指定する。
+     *
+     * <p>方針によらず、署名のある音声モデルは常に検証され、内容が署名と合わない場合は読み込みに失敗する。
+     *
+     * @param signaturePolicy 音声モデルの署名に対する方針。
+     * @return ビルダー。
+     */
+    public Builder signaturePolicy(SignaturePolicy signaturePolicy) {
+      this.signaturePolicy = signaturePolicy;
+      return this;
+    }
+
+    /**
+     * 信頼する署名者を指定する。{@link SignaturePolicy#STRICT} の場合に使われる。
+     *
+     * @param trustedPublicKeys 信頼する署名者の、Ed25519の公開鍵(32バイト)。
+     * @return ビルダー。
+     */
+    public Builder trustedPublicKeys(byte[]... trustedPublicKeys) {
+      for (byte[] key : trustedPublicKeys) {
+        if (key.length != 32) {
+          throw new IllegalArgumentException("trustedPublicKeys");
+        }
+      }
+      this.trustedPublicKeys = trustedPublicKeys.clone();
+      return this;
+    }
+
     /**
      * {@link Synthesizer} を構築する。
      *
@@ -387,6 +604,23 @@ public class Synthesizer extends Dll {
     GPU,
   }
 
+  /**
+   * 音声モデルの署名に対する方針。
+   *
+   * <p>署名はVVMに同梱される。詳しくは<a
+   * href="https://github.com/VOICEVOX/voicevox_core/blob/main/docs/vvm.md">VVMのドキュメント</a>を参照。
+   */
+  public static enum SignaturePolicy {
+    /** 署名の無い音声モデルや、信頼されていない署名者による音声モデルも読み込む。 */
+    PERMISSIVE,
+    /**
+     * {@link Builder#trustedPublicKeys} のいずれかで署名された音声モデルのみを読み込む。
+     *
+     * <p>それ以外の音声モデルの読み込みは {@link UntrustedVoiceModelException} となる。
+     */
+    STRICT,
+  }
+
   /** {@link Synthesizer#synthesis} のオプション。 */
   public class SynthesisConfigurator {
     private Synthesizer synthesizer;
@@ -5,6 +5,10 @@ import com.google.gson.annotations.Expose;
 import com.google.gson.annotations.SerializedName;
 import jakarta.annotation.Nonnull;
 import jakarta.annotation.Nullable;
+import java.util.Collections;
+import java.util.Map;
+import jp.hiroshiba.voicevoxcore.exceptions.SpeakerNotFoundException;
+import jp.hiroshiba.voicevoxcore.exceptions.StyleNotFoundException;
 
 /** 音声モデル。 */
 public class VoiceModel extends Dll {
@@ -13,19 +17,106 @@ public class VoiceModel extends Dll {
   /** ID。 */
   @Nonnull public final String id;
 
-  /** メタ情報。 */
+  /**
+   * メタ情報。
+   *
+   * <p>スタイルの種類で絞り込むには、各スタイルの {@link StyleMeta#type} を見る。
+   */
   @Nonnull public final SpeakerMeta[] metas;
 
+  /**
+   * VVMファイルから音声モデルを作る。
+   *
+   * @param modelPath VVMファイルか、VVMファイルを展開したディレクトリへのパス。
+   */
   public VoiceModel(String modelPath) {
     rsFromPath(modelPath);
     id = rsGetId();
-    String metasJson = rsGetMetasJson();
+    metas = parseMetas(rsGetMetasJson());
+  }
+
+  /**
+   * メモリ上のVVMから音声モデルを作る。
+   *
+   * @param bytes VVMの内容。
+   */
+  public VoiceModel(byte[] bytes) {
+    rsFromBytes(bytes);
+    id = rsGetId();
+    metas = parseMetas(rsGetMetasJson());
+  }
+
+  /** 音声モデルの集合の、{@code index}番目の音声モデルを得る。 */
+  VoiceModel(VoiceModelSet voiceModelSet, int index) {
+    rsFromVoiceModelSet(voiceModelSet, index);
+    id = rsGetId();
+    metas = parseMetas(rsGetMetasJson());
+  }
+
+  @Nonnull
+  private static SpeakerMeta[] parseMetas(String metasJson) {
     Gson gson = new Gson();
     SpeakerMeta[] rawMetas = gson.fromJson(metasJson, SpeakerMeta[].class);
     if (rawMetas == null) {
       throw new RuntimeException("Failed to parse metasJson");
     }
-    metas = rawMetas;
+    return rawMetas;
+  }
+
+  /**
+   * 話者の追加情報を取得する。
+   *
+   * <p>利用規約や画像、音声サンプルはこのときにVVMから読まれる。
+   *
+   * @param speakerUuid 話者のUUID。
+   * @return 話者の追加情報。
+   * @throws SpeakerNotFoundException 話者が見つからなかった場合。
+   */
+  @Nonnull
+  public SpeakerInfo speakerInfo(String speakerUuid) {
+    return SpeakerInfo.parseJson(rsGetSpeakerInfoJson(speakerUuid));
+  }
+
+  /**
+   * 話者のUUIDとスタイル名から、スタイルIDを得る。
+   *
+   * <p>スタイル名は完全に一致する必要がある。
+   *
+   * @param speakerUuid 話者のUUID。
+   * @param styleName スタイル名。
+   * @return スタイルID。
+   * @throws SpeakerNotFoundException 話者が見つからなかった場合。
+   * @throws StyleNotFoundException スタイルが見つからなかった場合。エラーメッセージには候補となるスタイル名が
+   *     挙げられる。
+   */
+  public int resolveStyleId(String speakerUuid, String styleName) {
+    return rsResolveStyleId(speakerUuid, styleName);
+  }
+
+  /**
+   * スタイルIDから、話者のUUIDとスタイル名を得る。
+   *
+   * @param styleId スタイルID。
+   * @return 話者のUUIDとスタイル名の組。
+   * @throws StyleNotFoundException スタイルが見つからなかった場合。
+   */
+  @Nonnull
+  public StyleKey resolveStyleKey(int styleId) {
+    return StyleKey.parseJson(rsGetStyleKeyJson(styleId));
+  }
+
+  /**
+   * 話者名とスタイル名から、スタイルをあいまいに検索する。
+   *
+   * <p>{@code query}は空白で区切られた語の列として扱われ、それぞれの語が話者名かスタイル名のいずれかに近い
+   * スタイルが、近い順に返される。
+   *
+   * @param query 検索語。
+   * @return 検索結果。
+   */
+  @Nonnull
+  public StyleCandidate[] searchStyles(String query) {
+    return StyleCandidate.parseJson(rsSearchStylesJson(query));
   }
 
   protected void finalize() throws Throwable {
@@ -35,12 +126,27 @@ public class VoiceModel extends Dll {
 
   private native void rsFromPath(String modelPath);
 
+  private native void rsFromBytes(byte[] bytes);
+
+  private native void rsFromVoiceModelSet(VoiceModelSet voiceModelSet, int index);
+
   @Nonnull
   private native String rsGetId();
 
   @Nonnull
   private native String rsGetMetasJson();
 
+  @Nonnull
+  private native String rsGetSpeakerInfoJson(String speakerUuid);
+
+  private native int rsResolveStyleId(String speakerUuid, String styleName);
+
+  @Nonnull
+  private native String rsGetStyleKeyJson(int styleId);
+
+  @Nonnull
+  private native String rsSearchStylesJson(String query);
+
   private native void rsDrop();
 
   /** 話者（speaker）のメタ情報。 */
@@ -79,6 +185,30 @@ public class VoiceModel extends Dll {
     @Nullable
     public final Integer order;
 
+    /** 言語ごとの話者名。キーはBCP 47の言語タグ。 */
+    @SerializedName("localized_names")
+    @Expose
+    @Nonnull
+    public final Map<String, String> localizedNames;
+
+    /** クレジット表記。 */
+    @SerializedName("credit")
+    @Expose
+    @Nullable
+    public final String credit;
+
+    /** 利用規約のファイル名。VVM内のパス。 */
+    @SerializedName("policy_filename")
+    @Expose
+    @Nullable
+    public final String policyFilename;
+
+    /** 立ち絵のファイル名。VVM内のパス。 */
+    @SerializedName("portrait_filename")
+    @Expose
+    @Nullable
+    public final String portraitFilename;
+
     private SpeakerMeta() {
       // GSONからコンストラクトするため、このメソッドは呼ばれることは無い。
       // このメソッドは@Nonnullを満たすために必要。
@@ -87,6 +217,10 @@ public class VoiceModel extends Dll {
       this.speakerUuid = "";
       this.version = "";
       this.order = null;
+      this.localizedNames = Collections.emptyMap();
+      this.credit = null;
+      this.policyFilename = null;
+      this.portraitFilename = null;
     }
   }
 
@@ -113,10 +247,54 @@ public class VoiceModel extends Dll {
     @Nullable
     public final Integer order;
 
+    /** スタイルの種類。 */
+    @SerializedName("type")
+    @Expose
+    @Nonnull
+    public final StyleType type;
+
+    /** アイコンのファイル名。VVM内のパス。 */
+    @SerializedName("icon_filename")
+    @Expose
+    @Nullable
+    public final String iconFilename;
+
+    /** 音声サンプルのファイル名。VVM内のパス。 */
+    @SerializedName("voice_sample_filenames")
+    @Expose
+    @Nonnull
+    public final String[] voiceSampleFilenames;
+
     private StyleMeta() {
       this.name = "";
       this.id = 0;
       this.order = null;
+      this.type = StyleType.TALK;
+      this.iconFilename = null;
+      this.voiceSampleFilenames = new String[0];
     }
   }
+
+  /** スタイル（style）の種類。スタイルがどの操作に対応しているかを表わす。 */
+  public static enum StyleType {
+    /** 音声合成クエリの作成と音声合成が可能。 */
+    @SerializedName("talk")
+    @Expose
+    TALK,
+
+    /** 歌唱音声合成用のクエリの作成が可能。 */
+    @SerializedName("singing_teacher")
+    @Expose
+    SINGING_TEACHER,
+
+    /** 歌唱音声合成が可能。 */
+    @SerializedName("frame_decode")
+    @Expose
+    FRAME_DECODE,
+
+    /** 歌唱音声合成用のクエリの作成と歌唱音声合成が可能。 */
+    @SerializedName("sing")
+    @Expose
+    SING,
+  }
 }
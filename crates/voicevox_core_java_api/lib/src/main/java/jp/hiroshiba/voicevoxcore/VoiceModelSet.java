@@ -0,0 +1,149 @@
+package jp.hiroshiba.voicevoxcore;
+
+import com.google.gson.Gson;
+import com.google.gson.annotations.Expose;
+import com.google.gson.annotations.SerializedName;
+import jakarta.annotation.Nonnull;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import jp.hiroshiba.voicevoxcore.exceptions.ReadModelDirException;
+import jp.hiroshiba.voicevoxcore.exceptions.StyleNotFoundException;
+
+/**
+ * 音声モデルの集合。
+ *
+ * <p>ディレクトリ内のVVMを走査し、スタイルIDに対してそのスタイルを含む音声モデルを引けるようにする。走査の時点ではVVMの
+ * マニフェストとメタ情報のみが読まれる。
+ *
+ * <p>同じスタイルIDを含むVVMや、同じ音声モデルIDを持つVVMが複数見つかった場合、先に見つかったものが優先され、その衝突は
+ * {@link #conflicts}で得られる。読み込めなかったVVMは飛ばされ、そのエラーメッセージは{@link #errors}で得ら
+ * れる。
+ */
+public class VoiceModelSet extends Dll {
+  private long handle;
+
+  /** メタ情報。衝突により使われないスタイルは含まない。 */
+  @Nonnull public final VoiceModel.SpeakerMeta[] metas;
+
+  /** 走査時に見つかった衝突。 */
+  @Nonnull public final Conflict[] conflicts;
+
+  /** 走査時に読めず、飛ばされたVVMのエラーメッセージ。 */
+  @Nonnull public final String[] errors;
+
+  /**
+   * ディレクトリの直下にあるVVMを走査し、音声モデルの集合を作る。
+   *
+   * <p>VVMは{@code dirs}の順に、それぞれのディレクトリの中ではパスの順に見つかったものとする。名前が{@code .vvm}で終わる
+   * ディレクトリは、VVMを展開したものとして扱う。
+   *
+   * @param dirs ディレクトリへのパス。
+   * @throws ReadModelDirException ディレクトリを読めなかった場合。個々のVVMを読めなかった場合は例外とせ
+   *     ず、そのVVMを飛ばす。
+   */
+  public VoiceModelSet(String... dirs) {
+    rsFromDirs(dirs);
+    Gson gson = new Gson();
+    VoiceModel.SpeakerMeta[] rawMetas =
+        gson.fromJson(rsGetMetasJson(), VoiceModel.SpeakerMeta[].class);
+    if (rawMetas == null) {
+      throw new RuntimeException("Failed to parse metasJson");
+    }
+    metas = rawMetas;
+    Conflict[] rawConflicts = gson.fromJson(rsGetConflictsJson(), Conflict[].class);
+    if (rawConflicts == null) {
+      throw new RuntimeException("Failed to parse conflictsJson");
+    }
+    conflicts = rawConflicts;
+    String[] rawErrors = gson.fromJson(rsGetErrorsJson(), String[].class);
+    if (rawErrors == null) {
+      throw new RuntimeException("Failed to parse errorsJson");
+    }
+    errors = rawErrors;
+  }
+
+  /**
+   * 使われる音声モデルを得る。
+   *
+   * @return VVMのパスから音声モデルへの対応。走査時に見つかった順に並ぶ。
+   */
+  @Nonnull
+  public Map<String, VoiceModel> voiceModels() {
+    String[] paths = rsGetVoiceModelPaths();
+    Map<String, VoiceModel> voiceModels = new LinkedHashMap<>();
+    for (int i = 0; i < paths.length; i++) {
+      voiceModels.put(paths[i], new VoiceModel(this, i));
+    }
+    return voiceModels;
+  }
+
+  /**
+   * スタイルIDに対し、そのスタイルを含む音声モデルを得る。
+   *
+   * @param styleId スタイルID。
+   * @return 音声モデル。
+   * @throws StyleNotFoundException そのスタイルを含む音声モデルが無い場合。
+   */
+  @Nonnull
+  public VoiceModel voiceModelByStyleId(int styleId) {
+    return new VoiceModel(this, rsGetVoiceModelIndexByStyleId(styleId));
+  }
+
+  protected void finalize() throws Throwable {
+    rsDrop();
+    super.finalize();
+  }
+
+  private native void rsFromDirs(String[] dirs);
+
+  @Nonnull
+  private native String rsGetMetasJson();
+
+  @Nonnull
+  private native String rsGetConflictsJson();
+
+  @Nonnull
+  private native String rsGetErrorsJson();
+
+  @Nonnull
+  private native String[] rsGetVoiceModelPaths();
+
+  private native int rsGetVoiceModelIndexByStyleId(int styleId);
+
+  private native void rsDrop();
+
+  /** 音声モデルの集合を作るときに見つかった、二つのVVMの衝突。先に見つかったVVMが優先される。 */
+  public static class Conflict {
+    /** 優先されたVVMのパス。 */
+    @SerializedName("preferred")
+    @Expose
+    @Nonnull
+    public final String preferred;
+
+    /** 優先されなかったVVMのパス。 */
+    @SerializedName("shadowed")
+    @Expose
+    @Nonnull
+    public final String shadowed;
+
+    /** 両方のVVMに含まれるスタイルのID。{@link #shadowed}のこれらのスタイルは使われない。 */
+    @SerializedName("style_ids")
+    @Expose
+    @Nonnull
+    public final int[] styleIds;
+
+    /** 音声モデルIDが同じであるか。同じ場合、{@link #shadowed}のVVMは丸ごと使われない。 */
+    @SerializedName("same_voice_model_id")
+    @Expose
+    public final boolean sameVoiceModelId;
+
+    private Conflict() {
+      // GSONからコンストラクトするため、このメソッドは呼ばれることは無い。
+      // このメソッドは@Nonnullを満たすために必要。
+      this.preferred = "";
+      this.shadowed = "";
+      this.styleIds = new int[0];
+      this.sameVoiceModelId = false;
+    }
+  }
+}
@@ -0,0 +1,12 @@
+package jp.hiroshiba.voicevoxcore.exceptions;
+
+/** 指定された番号のGPUが見つからなかった。 */
+public class GpuDeviceNotFoundException extends RuntimeException {
+  public GpuDeviceNotFoundException(String message) {
+    super(message);
+  }
+
+  public GpuDeviceNotFoundException(String message, Throwable cause) {
+    super(message, cause);
+  }
+}
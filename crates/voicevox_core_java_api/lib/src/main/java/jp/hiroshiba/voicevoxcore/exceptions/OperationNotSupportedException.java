@@ -0,0 +1,12 @@
+package jp.hiroshiba.voicevoxcore.exceptions;
+
+/** 音声モデルが推論操作のためのモデルを含んでいなかった、またはスタイルの種類が推論操作に対応していなかった。 */
+public class OperationNotSupportedException extends IllegalStateException {
+  public OperationNotSupportedException(String message) {
+    super(message);
+  }
+
+  public OperationNotSupportedException(String message, Throwable cause) {
+    super(message, cause);
+  }
+}
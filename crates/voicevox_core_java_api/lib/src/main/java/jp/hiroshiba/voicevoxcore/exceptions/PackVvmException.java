@@ -0,0 +1,14 @@
+package jp.hiroshiba.voicevoxcore.exceptions;
+
+import java.io.IOException;
+
+/** VVMを作成できなかった。 */
+public class PackVvmException extends IOException {
+  public PackVvmException(String message) {
+    super(message);
+  }
+
+  public PackVvmException(String message, Throwable cause) {
+    super(message, cause);
+  }
+}
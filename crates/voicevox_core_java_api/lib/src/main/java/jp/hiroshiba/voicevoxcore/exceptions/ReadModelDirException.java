@@ -0,0 +1,14 @@
+package jp.hiroshiba.voicevoxcore.exceptions;
+
+import java.io.IOException;
+
+/** 音声モデルを探すディレクトリを読めなかった。 */
+public class ReadModelDirException extends IOException {
+  public ReadModelDirException(String message) {
+    super(message);
+  }
+
+  public ReadModelDirException(String message, Throwable cause) {
+    super(message, cause);
+  }
+}
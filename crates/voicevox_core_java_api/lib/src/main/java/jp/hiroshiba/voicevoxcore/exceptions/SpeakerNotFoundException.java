@@ -0,0 +1,13 @@
+package jp.hiroshiba.voicevoxcore.exceptions;
+
+/** 話者のUUIDに対する話者が見つからなかった。 */
+public class SpeakerNotFoundException extends IndexOutOfBoundsException {
+  public SpeakerNotFoundException(String message) {
+    super(message);
+  }
+
+  public SpeakerNotFoundException(String message, Throwable cause)
+      throws UnsupportedOperationException {
+    throw new UnsupportedOperationException();
+  }
+}
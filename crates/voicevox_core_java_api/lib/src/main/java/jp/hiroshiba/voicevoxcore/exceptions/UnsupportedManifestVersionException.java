@@ -0,0 +1,14 @@
+package jp.hiroshiba.voicevoxcore.exceptions;
+
+import java.io.IOException;
+
+/** 音声モデルのマニフェストのバージョンに対応していない。 */
+public class UnsupportedManifestVersionException extends IOException {
+  public UnsupportedManifestVersionException(String message) {
+    super(message);
+  }
+
+  public UnsupportedManifestVersionException(String message, Throwable cause) {
+    super(message, cause);
+  }
+}
@@ -0,0 +1,14 @@
+package jp.hiroshiba.voicevoxcore.exceptions;
+
+import java.io.IOException;
+
+/** 音声モデルの署名を検証できなかった、または署名者が信頼されていない。 */
+public class UntrustedVoiceModelException extends IOException {
+  public UntrustedVoiceModelException(String message) {
+    super(message);
+  }
+
+  public UntrustedVoiceModelException(String message, Throwable cause) {
+    super(message, cause);
+  }
+}
@@ -0,0 +1,14 @@
+package jp.hiroshiba.voicevoxcore.exceptions;
+
+import java.io.IOException;
+
+/** 音声モデルのウォームアップに失敗した。 */
+public class WarmUpFailedException extends IOException {
+  public WarmUpFailedException(String message) {
+    super(message);
+  }
+
+  public WarmUpFailedException(String message, Throwable cause) {
+    super(message, cause);
+  }
+}
@@ -0,0 +1,14 @@
+package jp.hiroshiba.voicevoxcore.exceptions;
+
+import java.io.IOException;
+
+/** ワーカープロセスが異常終了した。 */
+public class WorkerCrashedException extends IOException {
+  public WorkerCrashedException(String message) {
+    super(message);
+  }
+
+  public WorkerCrashedException(String message, Throwable cause) {
+    super(message, cause);
+  }
+}
@@ -0,0 +1,60 @@
+/*
+ * スタイルの解決と検索のテスト。
+ */
+package jp.hiroshiba.voicevoxcore;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+import jp.hiroshiba.voicevoxcore.exceptions.InvalidModelDataException;
+import jp.hiroshiba.voicevoxcore.exceptions.StyleNotFoundException;
+import org.junit.jupiter.api.Test;
+
+class StyleResolverTest extends TestUtils {
+  static final String SPEAKER_UUID = "5d3d9aa9-88e5-4a96-8ef7-f13a3cad1cb3";
+
+  @Test
+  void checkVoiceModel() {
+    VoiceModel model = loadModel();
+
+    int styleId = model.resolveStyleId(SPEAKER_UUID, "style3-2");
+    assertEquals(303, styleId);
+    StyleKey key = model.resolveStyleKey(styleId);
+    assertEquals(SPEAKER_UUID, key.speakerUuid);
+    assertEquals("style3-2", key.styleName);
+
+    StyleNotFoundException e =
+        assertThrows(
+            StyleNotFoundException.class, () -> model.resolveStyleId(SPEAKER_UUID, "style3-3"));
+    assertTrue(e.getMessage().endsWith("候補: `style3-1`, `style3-2`"));
+
+    StyleCandidate[] candidates = model.searchStyles("style3-2");
+    assertEquals(303, candidates[0].styleId);
+    assertEquals(1.0, candidates[0].score);
+  }
+
+  @Test
+  void checkSynthesizer() throws InvalidModelDataException {
+    VoiceModel model = loadModel();
+    OpenJtalk openJtalk = loadOpenJtalk();
+    Synthesizer synthesizer = Synthesizer.builder(openJtalk).build();
+    synthesizer.loadVoiceModel(model);
+
+    int styleId = synthesizer.resolveStyleId(SPEAKER_UUID, "style3-2");
+    assertEquals(303, styleId);
+    StyleKey key = synthesizer.resolveStyleKey(styleId);
+    assertEquals(SPEAKER_UUID, key.speakerUuid);
+    assertEquals("style3-2", key.styleName);
+
+    StyleNotFoundException e =
+        assertThrows(
+            StyleNotFoundException.class,
+            () -> synthesizer.resolveStyleId(SPEAKER_UUID, "style3-3"));
+    assertTrue(e.getMessage().endsWith("候補: `style3-1`, `style3-2`"));
+
+    StyleCandidate[] candidates = synthesizer.searchStyles("style3-2");
+    assertEquals(303, candidates[0].styleId);
+    assertEquals(1.0, candidates[0].score);
+  }
+}
@@ -5,11 +5,13 @@
 package jp.hiroshiba.voicevoxcore;
 
 import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertThrows;
 import static org.junit.jupiter.api.Assertions.assertTrue;
 
 import java.util.List;
 import jp.hiroshiba.voicevoxcore.exceptions.InferenceFailedException;
 import jp.hiroshiba.voicevoxcore.exceptions.InvalidModelDataException;
+import jp.hiroshiba.voicevoxcore.exceptions.UntrustedVoiceModelException;
 import org.junit.jupiter.api.Test;
 
 class SynthesizerTest extends TestUtils {
@@ -62,6 +64,23 @@ class SynthesizerTest extends TestUtils {
     assertFalse(synthesizer.isLoadedVoiceModel(model.id));
   }
 
+  @Test
+  void checkSignaturePolicy() {
+    VoiceModel model = loadModel();
+    OpenJtalk openJtalk = loadOpenJtalk();
+    Synthesizer synthesizer =
+        Synthesizer.builder(openJtalk)
+            .signaturePolicy(Synthesizer.SignaturePolicy.STRICT)
+            .trustedPublicKeys(new byte[32])
+            .build();
+
+    assertThrows(UntrustedVoiceModelException.class, () -> synthesizer.loadVoiceModel(model));
+    assertFalse(synthesizer.isLoadedVoiceModel(model.id));
+    assertThrows(
+        IllegalArgumentException.class,
+        () -> Synthesizer.builder(openJtalk).trustedPublicKeys(new byte[31]));
+  }
+
   @Test
   void checkAudioQuery() throws InferenceFailedException, InvalidModelDataException {
     VoiceModel model = loadModel();
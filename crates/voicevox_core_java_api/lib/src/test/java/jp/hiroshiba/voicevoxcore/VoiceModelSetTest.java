@@ -0,0 +1,42 @@
+/*
+ * 音声モデルの集合のテスト。
+ * 読めないVVMが飛ばされることと、スタイルIDから音声モデルを引けることを確かめる。
+ */
+package jp.hiroshiba.voicevoxcore;
+
+import static org.junit.jupiter.api.Assertions.assertArrayEquals;
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+import java.io.File;
+import java.io.IOException;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import jp.hiroshiba.voicevoxcore.exceptions.StyleNotFoundException;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+class VoiceModelSetTest extends TestUtils {
+  @Test
+  void checkFromDirs(@TempDir Path dir) throws IOException {
+    // cwdはvoicevox_core/crates/voicevox_core_java_api/lib
+    String cwd = System.getProperty("user.dir");
+    File sample = new File(cwd + "/../../../model/sample.vvm");
+    Path vvm = dir.resolve("a.vvm");
+    Files.createSymbolicLink(vvm, sample.getCanonicalFile().toPath());
+    Files.write(dir.resolve("b.vvm"), "not a zip".getBytes(StandardCharsets.UTF_8));
+
+    VoiceModelSet modelSet = new VoiceModelSet(dir.toString());
+
+    assertEquals(1, modelSet.errors.length);
+    assertTrue(modelSet.errors[0].contains("b.vvm"));
+    assertArrayEquals(
+        new String[] {vvm.toString()}, modelSet.voiceModels().keySet().toArray(new String[0]));
+
+    VoiceModel model = loadModel();
+    assertEquals(model.id, modelSet.voiceModelByStyleId(303).id);
+    assertThrows(StyleNotFoundException.class, () -> modelSet.voiceModelByStyleId(304));
+  }
+}
@@ -67,15 +67,22 @@ where
                         let class = class!(
                             NotLoadedOpenjtalkDict,
                             GpuSupport,
+                            GpuDeviceNotFound,
                             OpenZipFile,
                             ReadZipEntry,
                             ModelAlreadyLoaded,
                             StyleAlreadyLoaded,
                             InvalidModelData,
+                            UnsupportedManifestVersion,
+                            UntrustedVoiceModel,
+                            ReadModelDir,
                             GetSupportedDevices,
                             StyleNotFound,
                             ModelNotFound,
+                            SpeakerNotFound,
+                            OperationNotSupported,
                             InferenceFailed,
+                            WarmUpFailed,
                             ExtractFullContextLabel,
                             ParseKana,
                             LoadUserDict,
@@ -83,6 +90,8 @@ where
                             WordNotFound,
                             UseUserDict,
                             InvalidWord,
+                            WorkerCrashed,
+                            PackVvm,
                         );
 
                         let mut sources =
@@ -5,3 +5,4 @@ mod open_jtalk;
 mod synthesizer;
 mod user_dict;
 mod voice_model;
+mod voice_model_set;
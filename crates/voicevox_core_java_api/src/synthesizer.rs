@@ -4,7 +4,7 @@ use crate::{
 };
 
 use jni::{
-    objects::{JObject, JString},
+    objects::{JByteArray, JObject, JObjectArray, JString},
     sys::{jboolean, jint, jobject},
     JNIEnv,
 };
@@ -44,6 +44,51 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_Synthesizer_rsNew<'loca
         }
         let cpu_num_threads = env.get_field(&builder, "cpuNumThreads", "I")?;
         options.cpu_num_threads = cpu_num_threads.i().expect("cpuNumThreads is not integer") as u16;
+        let gpu_device_index = env.get_field(&builder, "gpuDeviceIndex", "I")?;
+        options.gpu_device_index =
+            gpu_device_index.i().expect("gpuDeviceIndex is not integer") as u16;
+        let gpu_memory_limit = env.get_field(&builder, "gpuMemoryLimit", "J")?;
+        options.gpu_memory_limit =
+            gpu_memory_limit.j().expect("gpuMemoryLimit is not long") as usize;
+        let max_sessions_per_operation = env.get_field(&builder, "maxSessionsPerOperation", "I")?;
+        options.max_sessions_per_operation = max_sessions_per_operation
+            .i()
+            .expect("maxSessionsPerOperation is not integer")
+            as usize;
+        let warm_up_on_load = env.get_field(&builder, "warmUpOnLoad", "Z")?;
+        options.warm_up_on_load = warm_up_on_load.z().expect("warmUpOnLoad is not boolean");
+        let signature_policy = env
+            .get_field(
+                &builder,
+                "signaturePolicy",
+                object_type!("Synthesizer$SignaturePolicy"),
+            )?
+            .l()?;
+        if !signature_policy.is_null() {
+            let permissive = enum_object!(env, "Synthesizer$SignaturePolicy", "PERMISSIVE")?;
+            let strict = enum_object!(env, "Synthesizer$SignaturePolicy", "STRICT")?;
+            options.signature_policy = if env.is_same_object(&signature_policy, permissive)? {
+                voicevox_core::SignaturePolicy::Permissive
+            } else if env.is_same_object(&signature_policy, strict)? {
+                voicevox_core::SignaturePolicy::Strict
+            } else {
+                panic!("予期しない`SignaturePolicy`です: {signature_policy:?}");
+            };
+        }
+        let trusted_public_keys =
+            JObjectArray::from(env.get_field(&builder, "trustedPublicKeys", "[[B")?.l()?);
+        if !trusted_public_keys.is_null() {
+            let len = env.get_array_length(&trusted_public_keys)?;
+            options.trusted_public_keys = (0..len)
+                .map(|i| {
+                    let key =
+                        JByteArray::from(env.get_object_array_element(&trusted_public_keys, i)?);
+                    let key = env.convert_byte_array(&key)?;
+                    Ok(<[u8; 32]>::try_from(key)
+                        .expect("trustedPublicKeys should be 32 bytes each"))
+                })
+                .collect::<jni::errors::Result<_>>()?;
+        }
 
         let open_jtalk = env
             .get_rust_field::<_, _, voicevox_core::blocking::OpenJtalk>(&open_jtalk, "handle")?
@@ -91,6 +136,106 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_Synthesizer_rsGetMetasJ
     })
 }
 
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_Synthesizer_rsGetSpeakerInfoJson<
+    'local,
+>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    speaker_uuid: JString<'local>,
+) -> jobject {
+    throw_if_err(env, std::ptr::null_mut(), |env| {
+        let speaker_uuid: String = env.get_string(&speaker_uuid)?.into();
+
+        let internal = env
+            .get_rust_field::<_, _, Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>>(
+                &this, "handle",
+            )?
+            .clone();
+
+        let speaker_info = internal.speaker_info(&speaker_uuid)?;
+
+        let speaker_info_json = serde_json::to_string(&speaker_info).expect("should not fail");
+
+        let j_speaker_info_json = env.new_string(speaker_info_json)?;
+
+        Ok(j_speaker_info_json.into_raw())
+    })
+}
+
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_Synthesizer_rsResolveStyleId<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    speaker_uuid: JString<'local>,
+    style_name: JString<'local>,
+) -> jint {
+    throw_if_err(env, 0, |env| {
+        let speaker_uuid: String = env.get_string(&speaker_uuid)?.into();
+        let style_name: String = env.get_string(&style_name)?.into();
+
+        let internal = env
+            .get_rust_field::<_, _, Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>>(
+                &this, "handle",
+            )?
+            .clone();
+
+        let style_id = internal.resolve_style_id(&speaker_uuid, &style_name)?;
+
+        Ok(style_id.raw_id() as jint)
+    })
+}
+
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_Synthesizer_rsGetStyleKeyJson<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    style_id: jint,
+) -> jobject {
+    throw_if_err(env, std::ptr::null_mut(), |env| {
+        let style_id = style_id as u32;
+
+        let internal = env
+            .get_rust_field::<_, _, Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>>(
+                &this, "handle",
+            )?
+            .clone();
+
+        let style_key = internal.resolve_style_key(voicevox_core::StyleId::new(style_id))?;
+
+        let style_key_json = serde_json::to_string(&style_key).expect("should not fail");
+
+        let j_style_key_json = env.new_string(style_key_json)?;
+
+        Ok(j_style_key_json.into_raw())
+    })
+}
+
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_Synthesizer_rsSearchStylesJson<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    query: JString<'local>,
+) -> jobject {
+    throw_if_err(env, std::ptr::null_mut(), |env| {
+        let query: String = env.get_string(&query)?.into();
+
+        let internal = env
+            .get_rust_field::<_, _, Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>>(
+                &this, "handle",
+            )?
+            .clone();
+
+        let candidates = internal.search_styles(&query);
+
+        let candidates_json = serde_json::to_string(&candidates).expect("should not fail");
+
+        let j_candidates_json = env.new_string(candidates_json)?;
+
+        Ok(j_candidates_json.into_raw())
+    })
+}
+
 #[no_mangle]
 unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_Synthesizer_rsLoadVoiceModel<'local>(
     env: JNIEnv<'local>,
@@ -111,6 +256,34 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_Synthesizer_rsLoadVoice
     })
 }
 
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_Synthesizer_rsLoadVoiceModelByStyleId<
+    'local,
+>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    model_set: JObject<'local>,
+    style_id: jint,
+) {
+    throw_if_err(env, (), |env| {
+        let model_set = env
+            .get_rust_field::<_, _, Arc<voicevox_core::blocking::VoiceModelSet>>(
+                &model_set, "handle",
+            )?
+            .clone();
+        let internal = env
+            .get_rust_field::<_, _, Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>>(
+                &this, "handle",
+            )?
+            .clone();
+        internal.load_voice_model_by_style_id(
+            &model_set,
+            voicevox_core::StyleId::new(style_id as u32),
+        )?;
+        Ok(())
+    })
+}
+
 #[no_mangle]
 unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_Synthesizer_rsUnloadVoiceModel<'local>(
     env: JNIEnv<'local>,
@@ -132,6 +305,27 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_Synthesizer_rsUnloadVoi
     })
 }
 
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_Synthesizer_rsWarmUp<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    model_id: JString<'local>,
+) {
+    throw_if_err(env, (), |env| {
+        let model_id: String = env.get_string(&model_id)?.into();
+
+        let internal = env
+            .get_rust_field::<_, _, Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>>(
+                &this, "handle",
+            )?
+            .clone();
+
+        internal.warm_up(&voicevox_core::VoiceModelId::new(model_id))?;
+
+        Ok(())
+    })
+}
+
 #[no_mangle]
 unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_Synthesizer_rsIsLoadedVoiceModel<
     'local,
@@ -2,10 +2,11 @@ use std::{borrow::Cow, sync::Arc};
 
 use crate::common::throw_if_err;
 use jni::{
-    objects::{JObject, JString},
-    sys::jobject,
+    objects::{JByteArray, JObject, JString},
+    sys::{jint, jobject},
     JNIEnv,
 };
+use voicevox_core::StyleResolver as _;
 
 #[no_mangle]
 unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModel_rsFromPath<'local>(
@@ -25,6 +26,48 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModel_rsFromPath<'
     })
 }
 
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModel_rsFromBytes<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    bytes: JByteArray<'local>,
+) {
+    throw_if_err(env, (), |env| {
+        let bytes = env.convert_byte_array(&bytes)?;
+
+        let internal = voicevox_core::blocking::VoiceModel::from_bytes(bytes)?;
+
+        env.set_rust_field(&this, "handle", Arc::new(internal))?;
+
+        Ok(())
+    })
+}
+
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModel_rsFromVoiceModelSet<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    model_set: JObject<'local>,
+    index: jint,
+) {
+    throw_if_err(env, (), |env| {
+        let model_set = env
+            .get_rust_field::<_, _, Arc<voicevox_core::blocking::VoiceModelSet>>(
+                &model_set, "handle",
+            )?
+            .clone();
+
+        let (_, internal) = model_set
+            .voice_models()
+            .nth(index as _)
+            .expect("should be in range");
+
+        env.set_rust_field(&this, "handle", Arc::new(internal.clone()))?;
+
+        Ok(())
+    })
+}
+
 #[no_mangle]
 unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModel_rsGetId<'local>(
     env: JNIEnv<'local>,
@@ -59,6 +102,87 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModel_rsGetMetasJs
     })
 }
 
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModel_rsGetSpeakerInfoJson<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    speaker_uuid: JString<'local>,
+) -> jobject {
+    throw_if_err(env, std::ptr::null_mut(), |env| {
+        let speaker_uuid: String = env.get_string(&speaker_uuid)?.into();
+
+        let internal = env
+            .get_rust_field::<_, _, Arc<voicevox_core::blocking::VoiceModel>>(&this, "handle")?
+            .clone();
+
+        let speaker_info = internal.speaker_info(&speaker_uuid)?;
+        let speaker_info_json = serde_json::to_string(&speaker_info).expect("should not fail");
+        Ok(env.new_string(speaker_info_json)?.into_raw())
+    })
+}
+
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModel_rsResolveStyleId<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    speaker_uuid: JString<'local>,
+    style_name: JString<'local>,
+) -> jint {
+    throw_if_err(env, 0, |env| {
+        let speaker_uuid: String = env.get_string(&speaker_uuid)?.into();
+        let style_name: String = env.get_string(&style_name)?.into();
+
+        let internal = env
+            .get_rust_field::<_, _, Arc<voicevox_core::blocking::VoiceModel>>(&this, "handle")?
+            .clone();
+
+        let style_id = internal
+            .metas()
+            .resolve_style_id(&speaker_uuid, &style_name)?;
+        Ok(style_id.raw_id() as jint)
+    })
+}
+
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModel_rsGetStyleKeyJson<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    style_id: jint,
+) -> jobject {
+    throw_if_err(env, std::ptr::null_mut(), |env| {
+        let style_id = style_id as u32;
+
+        let internal = env
+            .get_rust_field::<_, _, Arc<voicevox_core::blocking::VoiceModel>>(&this, "handle")?
+            .clone();
+
+        let style_key = internal
+            .metas()
+            .resolve_style_key(voicevox_core::StyleId::new(style_id))?;
+        let style_key_json = serde_json::to_string(&style_key).expect("should not fail");
+        Ok(env.new_string(style_key_json)?.into_raw())
+    })
+}
+
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModel_rsSearchStylesJson<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    query: JString<'local>,
+) -> jobject {
+    throw_if_err(env, std::ptr::null_mut(), |env| {
+        let query: String = env.get_string(&query)?.into();
+
+        let internal = env
+            .get_rust_field::<_, _, Arc<voicevox_core::blocking::VoiceModel>>(&this, "handle")?
+            .clone();
+
+        let candidates = internal.metas().search_styles(&query);
+        let candidates_json = serde_json::to_string(&candidates).expect("should not fail");
+        Ok(env.new_string(candidates_json)?.into_raw())
+    })
+}
+
 #[no_mangle]
 unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModel_rsDrop<'local>(
     env: JNIEnv<'local>,
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use crate::common::throw_if_err;
+use jni::{
+    objects::{JObject, JObjectArray, JString},
+    sys::{jint, jobject},
+    JNIEnv,
+};
+
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModelSet_rsFromDirs<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    dirs: JObjectArray<'local>,
+) {
+    throw_if_err(env, (), |env| {
+        let len = env.get_array_length(&dirs)?;
+        let dirs = (0..len)
+            .map(|i| {
+                let dir = JString::from(env.get_object_array_element(&dirs, i)?);
+                let dir: String = env.get_string(&dir)?.into();
+                Ok(dir)
+            })
+            .collect::<jni::errors::Result<Vec<_>>>()?;
+
+        let internal = voicevox_core::blocking::VoiceModelSet::from_dirs(dirs)?;
+
+        env.set_rust_field(&this, "handle", Arc::new(internal))?;
+
+        Ok(())
+    })
+}
+
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModelSet_rsGetMetasJson<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+) -> jobject {
+    throw_if_err(env, std::ptr::null_mut(), |env| {
+        let internal = env
+            .get_rust_field::<_, _, Arc<voicevox_core::blocking::VoiceModelSet>>(&this, "handle")?
+            .clone();
+
+        let metas = internal.metas();
+        let metas_json = serde_json::to_string(&metas).expect("should not fail");
+        Ok(env.new_string(metas_json)?.into_raw())
+    })
+}
+
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModelSet_rsGetConflictsJson<
+    'local,
+>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+) -> jobject {
+    throw_if_err(env, std::ptr::null_mut(), |env| {
+        let internal = env
+            .get_rust_field::<_, _, Arc<voicevox_core::blocking::VoiceModelSet>>(&this, "handle")?
+            .clone();
+
+        let conflicts = internal.conflicts();
+        let conflicts_json = serde_json::to_string(&conflicts).expect("should not fail");
+        Ok(env.new_string(conflicts_json)?.into_raw())
+    })
+}
+
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModelSet_rsGetErrorsJson<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+) -> jobject {
+    throw_if_err(env, std::ptr::null_mut(), |env| {
+        let internal = env
+            .get_rust_field::<_, _, Arc<voicevox_core::blocking::VoiceModelSet>>(&this, "handle")?
+            .clone();
+
+        let errors = internal
+            .errors()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let errors_json = serde_json::to_string(&errors).expect("should not fail");
+        Ok(env.new_string(errors_json)?.into_raw())
+    })
+}
+
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModelSet_rsGetVoiceModelPaths<
+    'local,
+>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+) -> jobject {
+    throw_if_err(env, std::ptr::null_mut(), |env| {
+        let internal = env
+            .get_rust_field::<_, _, Arc<voicevox_core::blocking::VoiceModelSet>>(&this, "handle")?
+            .clone();
+
+        let paths = internal
+            .voice_models()
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>();
+        let array = env.new_object_array(paths.len() as _, "java/lang/String", JObject::null())?;
+        for (i, path) in paths.into_iter().enumerate() {
+            let path = env.new_string(path.to_string_lossy())?;
+            env.set_object_array_element(&array, i as _, path)?;
+        }
+        Ok(array.into_raw())
+    })
+}
+
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModelSet_rsGetVoiceModelIndexByStyleId<
+    'local,
+>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    style_id: jint,
+) -> jint {
+    throw_if_err(env, -1, |env| {
+        let internal = env
+            .get_rust_field::<_, _, Arc<voicevox_core::blocking::VoiceModelSet>>(&this, "handle")?
+            .clone();
+
+        let model =
+            internal.voice_model_by_style_id(voicevox_core::StyleId::new(style_id as u32))?;
+        let index = internal
+            .voice_models()
+            .position(|(_, m)| std::ptr::eq(m, model))
+            .expect("should be contained");
+        Ok(index as _)
+    })
+}
+
+#[no_mangle]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_VoiceModelSet_rsDrop<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+) {
+    throw_if_err(env, (), |env| {
+        env.take_rust_field(&this, "handle")?;
+        Ok(())
+    })
+}
@@ -83,6 +83,9 @@ pub(crate) fn derive_inference_operation(
                     <#variant_names as crate::infer::InferenceSignature>::Output::PARAM_INFOS
                 )),*
             ]);
+
+            const NAMES: ::enum_map::EnumMap<Self, &'static str> =
+                ::enum_map::EnumMap::from_array([#(::std::stringify!(#variant_names)),*]);
         }
 
         #(#signatures)*
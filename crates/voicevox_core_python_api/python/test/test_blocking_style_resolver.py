@@ -0,0 +1,69 @@
+"""
+スタイルIDと、話者のUUIDとスタイル名の対応を引けるかをテストする。
+
+音声モデルと音声シンセサイザのそれぞれで、往復して同じスタイルに戻ることと、スタイル名が見つからない
+ときのエラーに候補が挙げられることを確かめる。
+"""
+
+from typing import Union
+
+import conftest
+import pytest
+import voicevox_core
+
+SPEAKER_UUID = "5d3d9aa9-88e5-4a96-8ef7-f13a3cad1cb3"
+
+
+@pytest.fixture
+def model() -> voicevox_core.blocking.VoiceModel:
+    return voicevox_core.blocking.VoiceModel.from_path(conftest.model_dir)
+
+
+@pytest.fixture
+def synthesizer(
+    model: voicevox_core.blocking.VoiceModel,
+) -> voicevox_core.blocking.Synthesizer:
+    open_jtalk = voicevox_core.blocking.OpenJtalk(conftest.open_jtalk_dic_dir)
+    synthesizer = voicevox_core.blocking.Synthesizer(open_jtalk)
+    synthesizer.load_voice_model(model)
+    return synthesizer
+
+
+@pytest.fixture(params=["model", "synthesizer"])
+def resolver(
+    request: pytest.FixtureRequest,
+) -> Union[voicevox_core.blocking.VoiceModel, voicevox_core.blocking.Synthesizer]:
+    return request.getfixturevalue(request.param)
+
+
+def test_resolve_round_trip(
+    resolver: Union[
+        voicevox_core.blocking.VoiceModel, voicevox_core.blocking.Synthesizer
+    ],
+) -> None:
+    style_id = resolver.resolve_style_id(SPEAKER_UUID, "style3-2")
+    assert style_id == voicevox_core.StyleId(303)
+    assert resolver.resolve_style_key(style_id) == voicevox_core.StyleKey(
+        speaker_uuid=SPEAKER_UUID, style_name="style3-2"
+    )
+
+
+def test_resolve_lists_candidates(
+    resolver: Union[
+        voicevox_core.blocking.VoiceModel, voicevox_core.blocking.Synthesizer
+    ],
+) -> None:
+    with pytest.raises(
+        voicevox_core.StyleNotFoundError, match="候補: `style3-1`, `style3-2`$"
+    ):
+        resolver.resolve_style_id(SPEAKER_UUID, "style3-3")
+
+
+def test_search_styles(
+    resolver: Union[
+        voicevox_core.blocking.VoiceModel, voicevox_core.blocking.Synthesizer
+    ],
+) -> None:
+    best = resolver.search_styles("style3-2")[0]
+    assert best.style_id == voicevox_core.StyleId(303)
+    assert best.score == 1.0
@@ -0,0 +1,35 @@
+"""
+ディレクトリを走査して作った音声モデルの集合をテストする。
+
+読めないVVMが飛ばされてエラーとして得られることと、スタイルIDから音声モデルを引けることを確かめる。
+"""
+
+from pathlib import Path
+
+import conftest
+import pytest
+import voicevox_core
+
+
+@pytest.fixture
+def model_set(tmp_path: Path) -> voicevox_core.blocking.VoiceModelSet:
+    (tmp_path / "a.vvm").symlink_to(conftest.model_dir)
+    (tmp_path / "b.vvm").write_bytes(b"not a zip")
+    return voicevox_core.blocking.VoiceModelSet.from_dirs([tmp_path])
+
+
+def test_skips_unreadable_vvm(
+    model_set: voicevox_core.blocking.VoiceModelSet, tmp_path: Path
+) -> None:
+    assert [Path(path) for path, _ in model_set.voice_models()] == [tmp_path / "a.vvm"]
+    assert len(model_set.errors) == 1
+    assert isinstance(model_set.errors[0], voicevox_core.OpenZipFileError)
+
+
+def test_voice_model_by_style_id(
+    model_set: voicevox_core.blocking.VoiceModelSet,
+) -> None:
+    model = voicevox_core.blocking.VoiceModel.from_path(conftest.model_dir)
+    assert model_set.voice_model_by_style_id(voicevox_core.StyleId(303)).id == model.id
+    with pytest.raises(voicevox_core.StyleNotFoundError):
+        model_set.voice_model_by_style_id(voicevox_core.StyleId(304))
@@ -0,0 +1,28 @@
+"""
+``signature_policy`` と ``trusted_public_keys`` が反映されるかをテストする。
+"""
+
+import conftest
+import pytest
+import voicevox_core
+
+
+def test_strict_policy_rejects_unsigned_model() -> None:
+    open_jtalk = voicevox_core.blocking.OpenJtalk(conftest.open_jtalk_dic_dir)
+    model = voicevox_core.blocking.VoiceModel.from_path(conftest.model_dir)
+    synthesizer = voicevox_core.blocking.Synthesizer(
+        open_jtalk,
+        signature_policy=voicevox_core.SignaturePolicy.STRICT,
+        trusted_public_keys=[bytes(32)],
+    )
+
+    with pytest.raises(voicevox_core.UntrustedVoiceModelError):
+        synthesizer.load_voice_model(model)
+    assert not synthesizer.is_loaded_voice_model(model.id)
+
+
+def test_public_keys_must_be_32_bytes() -> None:
+    open_jtalk = voicevox_core.blocking.OpenJtalk(conftest.open_jtalk_dic_dir)
+
+    with pytest.raises(ValueError):
+        voicevox_core.blocking.Synthesizer(open_jtalk, trusted_public_keys=[bytes(31)])
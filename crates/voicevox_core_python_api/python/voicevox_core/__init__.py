@@ -6,17 +6,25 @@ from ._models import (  # noqa: F401
     AccentPhrase,
     AudioQuery,
     Mora,
+    SignaturePolicy,
+    SpeakerInfo,
     SpeakerMeta,
+    StyleCandidate,
     StyleId,
+    StyleInfo,
+    StyleKey,
+    StyleType,
     StyleVersion,
     SupportedDevices,
     UserDictWord,
     UserDictWordType,
+    VoiceModelConflict,
     VoiceModelId,
 )
 from ._rust import (  # noqa: F401
     ExtractFullContextLabelError,
     GetSupportedDevicesError,
+    GpuDeviceNotFoundError,
     GpuSupportError,
     InferenceFailedError,
     InvalidModelDataError,
@@ -26,13 +34,21 @@ from ._rust import (  # noqa: F401
     ModelNotFoundError,
     NotLoadedOpenjtalkDictError,
     OpenZipFileError,
+    OperationNotSupportedError,
+    PackVvmError,
     ParseKanaError,
+    ReadModelDirError,
     ReadZipEntryError,
     SaveUserDictError,
+    SpeakerNotFoundError,
     StyleAlreadyLoadedError,
     StyleNotFoundError,
+    UnsupportedManifestVersionError,
+    UntrustedVoiceModelError,
     UseUserDictError,
+    WarmUpFailedError,
     WordNotFoundError,
+    WorkerCrashedError,
     __version__,
     supported_devices,
 )
@@ -48,6 +64,7 @@ __all__ = [
     "blocking",
     "ExtractFullContextLabelError",
     "GetSupportedDevicesError",
+    "GpuDeviceNotFoundError",
     "GpuSupportError",
     "InferenceFailedError",
     "InvalidModelDataError",
@@ -58,19 +75,34 @@ __all__ = [
     "Mora",
     "NotLoadedOpenjtalkDictError",
     "OpenZipFileError",
+    "OperationNotSupportedError",
+    "PackVvmError",
     "ParseKanaError",
+    "ReadModelDirError",
     "ReadZipEntryError",
     "SaveUserDictError",
+    "SignaturePolicy",
+    "SpeakerInfo",
     "SpeakerMeta",
+    "SpeakerNotFoundError",
     "StyleAlreadyLoadedError",
+    "StyleCandidate",
     "StyleId",
+    "StyleInfo",
+    "StyleKey",
     "StyleNotFoundError",
+    "StyleType",
     "StyleVersion",
     "SupportedDevices",
     "supported_devices",
+    "UnsupportedManifestVersionError",
+    "UntrustedVoiceModelError",
     "UseUserDictError",
     "UserDictWord",
     "UserDictWordType",
+    "VoiceModelConflict",
     "VoiceModelId",
+    "WarmUpFailedError",
     "WordNotFoundError",
+    "WorkerCrashedError",
 ]
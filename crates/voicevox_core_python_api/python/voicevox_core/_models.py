@@ -1,6 +1,6 @@
 import dataclasses
 from enum import Enum
-from typing import List, NewType, Optional
+from typing import Dict, List, NewType, Optional
 
 import pydantic
 
@@ -34,6 +34,22 @@ x : str
 """
 
 
+class StyleType(str, Enum):
+    """**スタイル** (_style_)の種類。スタイルがどの操作に対応しているかを表わす。"""
+
+    TALK = "talk"
+    """音声合成クエリの作成と音声合成が可能。"""
+
+    SINGING_TEACHER = "singing_teacher"
+    """歌唱音声合成用のクエリの作成が可能。"""
+
+    FRAME_DECODE = "frame_decode"
+    """歌唱音声合成が可能。"""
+
+    SING = "sing"
+    """歌唱音声合成用のクエリの作成と歌唱音声合成が可能。"""
+
+
 @pydantic.dataclasses.dataclass
 class StyleMeta:
     """**スタイル** (_style_)のメタ情報。"""
@@ -51,6 +67,23 @@ class StyleMeta:
     :attr:`SpeakerMeta.styles` は、この値に対して昇順に並んでいるべきである。
     """
 
+    type: StyleType = StyleType.TALK
+    """スタイルの種類。"""
+
+    icon_filename: Optional[str] = None
+    """
+    アイコンの画像ファイルの、VVM内でのファイル名。
+
+    中身は :attr:`StyleInfo.icon` で得られる。
+    """
+
+    voice_sample_filenames: List[str] = dataclasses.field(default_factory=list)
+    """
+    音声サンプルのWAVファイルの、VVM内でのファイル名。
+
+    中身は :attr:`StyleInfo.voice_samples` で得られる。
+    """
+
 
 @pydantic.dataclasses.dataclass
 class SpeakerMeta:
@@ -75,6 +108,110 @@ class SpeakerMeta:
     ``SpeakerMeta`` の列は、この値に対して昇順に並んでいるべきである。
     """
 
+    localized_names: Dict[str, str] = dataclasses.field(default_factory=dict)
+    """言語ごとの話者名。キーはBCP 47の言語タグ。"""
+
+    credit: Optional[str] = None
+    """話者を利用する際に求められるクレジット表記。"""
+
+    policy_filename: Optional[str] = None
+    """
+    利用規約が書かれたMarkdownファイルの、VVM内でのファイル名。
+
+    中身は :attr:`SpeakerInfo.policy` で得られる。
+    """
+
+    portrait_filename: Optional[str] = None
+    """
+    立ち絵の画像ファイルの、VVM内でのファイル名。
+
+    中身は :attr:`SpeakerInfo.portrait` で得られる。
+    """
+
+
+@pydantic.dataclasses.dataclass
+class StyleInfo:
+    """スタイルの追加情報。"""
+
+    id: StyleId
+    """スタイルID。"""
+
+    icon: Optional[pydantic.Base64Bytes]
+    """アイコンの画像。"""
+
+    voice_samples: List[pydantic.Base64Bytes]
+    """音声サンプル。WAVの列。"""
+
+
+@pydantic.dataclasses.dataclass
+class SpeakerInfo:
+    """話者の追加情報。"""
+
+    policy: Optional[str]
+    """利用規約。Markdown形式。"""
+
+    portrait: Optional[pydantic.Base64Bytes]
+    """立ち絵の画像。"""
+
+    style_infos: List[StyleInfo]
+    """スタイルごとの追加情報。 :attr:`SpeakerMeta.styles` と同じ順で並ぶ。"""
+
+
+@pydantic.dataclasses.dataclass
+class StyleKey:
+    """
+    話者のUUIDとスタイル名の組。
+
+    :class:`StyleId` と異なり、音声モデルの配布物によらずスタイルを指す。
+    """
+
+    speaker_uuid: str
+    """話者のUUID。"""
+
+    style_name: str
+    """スタイル名。"""
+
+
+@pydantic.dataclasses.dataclass
+class StyleCandidate:
+    """スタイルの検索結果。"""
+
+    speaker_name: str
+    """話者名。"""
+
+    speaker_uuid: str
+    """話者のUUID。"""
+
+    style_name: str
+    """スタイル名。"""
+
+    style_id: StyleId
+    """スタイルID。"""
+
+    score: float
+    """検索語との近さ。0以上1以下で、完全に一致するときに1となる。"""
+
+
+@pydantic.dataclasses.dataclass
+class VoiceModelConflict:
+    """
+    音声モデルの集合を作るときに見つかった、二つのVVMの衝突。
+
+    先に見つかったVVMが優先される。
+    """
+
+    preferred: str
+    """優先されたVVMのパス。"""
+
+    shadowed: str
+    """優先されなかったVVMのパス。"""
+
+    style_ids: List[StyleId]
+    """両方のVVMに含まれるスタイルのID。 ``shadowed`` のこれらのスタイルは使われない。"""
+
+    same_voice_model_id: bool
+    """音声モデルIDが同じであるか。同じ場合、 ``shadowed`` のVVMは丸ごと使われない。"""
+
 
 @pydantic.dataclasses.dataclass
 class SupportedDevices:
@@ -126,6 +263,24 @@ class AccelerationMode(str, Enum):
     """ハードウェアアクセラレーションモードを"GPU"に設定する。"""
 
 
+class SignaturePolicy(str, Enum):
+    """
+    音声モデルの署名に対する方針。
+
+    署名はVVMに同梱される。詳しくは `VVMのドキュメント <https://github.com/VOICEVOX/voicevox_core/blob/main/docs/vvm.md>`_ を参照。
+    """
+
+    PERMISSIVE = "PERMISSIVE"
+    """署名の無い音声モデルや、信頼されていない署名者による音声モデルも読み込む。"""
+
+    STRICT = "STRICT"
+    """
+    ``trusted_public_keys`` のいずれかで署名された音声モデルのみを読み込む。
+
+    それ以外の音声モデルの読み込みは :class:`UntrustedVoiceModelError` となる。
+    """
+
+
 @pydantic.dataclasses.dataclass
 class Mora:
     """モーラ（子音＋母音）ごとの情報。"""
@@ -27,6 +27,11 @@ class GpuSupportError(Exception):
 
     ...
 
+class GpuDeviceNotFoundError(Exception):
+    """指定された番号のGPUが見つからなかった。"""
+
+    ...
+
 class OpenZipFileError(Exception):
     """ZIPファイルを開くことに失敗した。"""
 
@@ -52,6 +57,21 @@ class InvalidModelDataError(Exception):
 
     ...
 
+class UnsupportedManifestVersionError(Exception):
+    """音声モデルのマニフェストのバージョンに対応していない。"""
+
+    ...
+
+class UntrustedVoiceModelError(Exception):
+    """音声モデルの署名を検証できなかった、または署名者が信頼されていない。"""
+
+    ...
+
+class ReadModelDirError(Exception):
+    """音声モデルを探すディレクトリを読めなかった。"""
+
+    ...
+
 class GetSupportedDevicesError(Exception):
     """サポートされているデバイス情報取得に失敗した。"""
 
@@ -62,16 +82,31 @@ class StyleNotFoundError(KeyError):
 
     ...
 
+class SpeakerNotFoundError(KeyError):
+    """話者のUUIDに対する話者が見つからなかった。"""
+
+    ...
+
 class ModelNotFoundError(KeyError):
     """音声モデルIDに対する音声モデルが見つからなかった。"""
 
     ...
 
+class OperationNotSupportedError(Exception):
+    """音声モデルが推論操作のためのモデルを含んでいなかった、またはスタイルの種類が推論操作に対応していなかった。"""
+
+    ...
+
 class InferenceFailedError(Exception):
     """推論に失敗した。"""
 
     ...
 
+class WarmUpFailedError(Exception):
+    """音声モデルのウォームアップに失敗した。"""
+
+    ...
+
 class ExtractFullContextLabelError(Exception):
     """コンテキストラベル出力に失敗した。"""
 
@@ -107,5 +142,15 @@ class InvalidWordError(ValueError):
 
     ...
 
+class WorkerCrashedError(Exception):
+    """ワーカープロセスが異常終了した。"""
+
+    ...
+
+class PackVvmError(Exception):
+    """VVMを作成できなかった。"""
+
+    ...
+
 def _validate_pronunciation(pronunciation: str) -> None: ...
 def _to_zenkaku(text: str) -> str: ...
@@ -1,5 +1,5 @@
 from os import PathLike
-from typing import TYPE_CHECKING, Dict, List, Literal, Union
+from typing import TYPE_CHECKING, Dict, List, Literal, Tuple, Union
 from uuid import UUID
 
 if TYPE_CHECKING:
@@ -7,9 +7,14 @@ if TYPE_CHECKING:
         AccelerationMode,
         AccentPhrase,
         AudioQuery,
+        SignaturePolicy,
+        SpeakerInfo,
         SpeakerMeta,
+        StyleCandidate,
         StyleId,
+        StyleKey,
         UserDictWord,
+        VoiceModelConflict,
         VoiceModelId,
     )
 
@@ -25,7 +30,18 @@ class VoiceModel:
         Parameters
         ----------
         path
-            VVMファイルへのパス。
+            VVMファイルか、VVMファイルを展開したディレクトリへのパス。
+        """
+        ...
+    @staticmethod
+    def from_bytes(bytes: bytes) -> VoiceModel:
+        """
+        メモリ上のVVMから ``VoiceModel`` を生成する。
+
+        Parameters
+        ----------
+        bytes
+            VVMの内容。
         """
         ...
     @property
@@ -34,7 +50,138 @@ class VoiceModel:
         ...
     @property
     def metas(self) -> List[SpeakerMeta]:
-        """メタ情報。"""
+        """
+        メタ情報。
+
+        スタイルの種類で絞り込むには、各スタイルの ``type`` を見る。
+        """
+        ...
+    def resolve_style_id(self, speaker_uuid: str, style_name: str) -> StyleId:
+        """
+        メタ情報から、話者のUUIDとスタイル名に対応するスタイルIDを得る。
+
+        スタイル名は完全に一致する必要がある。
+
+        Parameters
+        ----------
+        speaker_uuid
+            話者のUUID。
+        style_name
+            スタイル名。
+
+        Returns
+        -------
+        スタイルID。
+
+        Raises
+        ------
+        SpeakerNotFoundError
+            話者が見つからなかった場合。
+        StyleNotFoundError
+            スタイルが見つからなかった場合。エラーメッセージには、その話者のスタイル名が近い順に候補として挙げられる。
+        """
+        ...
+    def resolve_style_key(self, style_id: Union[StyleId, int]) -> StyleKey:
+        """
+        メタ情報から、スタイルIDに対応する話者のUUIDとスタイル名を得る。
+
+        Parameters
+        ----------
+        style_id
+            スタイルID。
+
+        Returns
+        -------
+        話者のUUIDとスタイル名。
+        """
+        ...
+    def search_styles(self, query: str) -> List[StyleCandidate]:
+        """
+        メタ情報から、話者名とスタイル名でスタイルをあいまいに検索する。
+
+        ``query`` は空白で区切られた語の列として扱われ、それぞれの語が話者名かスタイル名のいずれかに近いスタイルが、近い順に返される。
+
+        Parameters
+        ----------
+        query
+            検索語。
+
+        Returns
+        -------
+        検索結果。
+        """
+        ...
+    def speaker_info(self, speaker_uuid: str) -> SpeakerInfo:
+        """
+        話者の追加情報を、VVMから読む。
+
+        Parameters
+        ----------
+        speaker_uuid
+            話者のUUID。
+
+        Returns
+        -------
+        話者の追加情報。
+        """
+        ...
+
+class VoiceModelSet:
+    """
+    音声モデルの集合。
+
+    ディレクトリ内のVVMを走査し、スタイルIDに対してそのスタイルを含む音声モデルを引けるようにする。走査の時点ではVVMのマニフェストとメタ情報のみが読まれる。
+
+    同じスタイルIDを含むVVMや、同じ音声モデルIDを持つVVMが複数見つかった場合、先に見つかったものが優先され、その衝突は :attr:`conflicts` で得られる。読み込めなかったVVMは飛ばされ、そのエラーは :attr:`errors` で得られる。
+    """
+
+    @staticmethod
+    def from_dirs(dirs: List[Union[str, PathLike[str]]]) -> VoiceModelSet:
+        """
+        ディレクトリの直下にあるVVMを走査し、 ``VoiceModelSet`` を生成する。
+
+        VVMは ``dirs`` の順に、それぞれのディレクトリの中ではパスの順に見つかったものとする。名前が ``.vvm`` で終わるディレクトリは、VVMを展開したものとして扱う。
+
+        Parameters
+        ----------
+        dirs
+            ディレクトリへのパスのリスト。
+
+        Raises
+        ------
+        ReadModelDirError
+            ディレクトリを読めなかった場合。個々のVVMを読めなかった場合は例外とせず、そのVVMを飛ばす。
+        """
+        ...
+    @property
+    def metas(self) -> List[SpeakerMeta]:
+        """メタ情報。衝突により使われないスタイルは含まない。"""
+        ...
+    @property
+    def conflicts(self) -> List[VoiceModelConflict]:
+        """走査時に見つかった衝突。"""
+        ...
+    @property
+    def errors(self) -> List[Exception]:
+        """走査時に読めず、飛ばされたVVMのエラー。"""
+        ...
+    def voice_models(self) -> List[Tuple[str, VoiceModel]]:
+        """使われる音声モデルと、そのVVMのパスの組のリスト。"""
+        ...
+    def voice_model_by_style_id(self, style_id: Union[StyleId, int]) -> VoiceModel:
+        """
+        スタイルIDに対し、そのスタイルを含む音声モデルを返す。
+
+        Parameters
+        ----------
+        style_id
+            スタイルID。
+
+        Raises
+        ------
+        StyleNotFoundError
+            そのスタイルを含む音声モデルが無い場合。
+        """
         ...
 
 class OpenJtalk:
@@ -73,6 +220,18 @@ class Synthesizer:
         ハードウェアアクセラレーションモード。
     cpu_num_threads
         CPU利用数を指定。0を指定すると環境に合わせたCPUが利用される。
+    gpu_device_index
+        GPUを使うときの、GPUの番号。存在しない番号の場合は :class:`GpuDeviceNotFoundError` となる。
+    gpu_memory_limit
+        GPUを使うときの、GPUメモリの使用量の上限(バイト)。0を指定すると上限を設けない。CUDAでのみ有効。
+    max_sessions_per_operation
+        一つの音声モデルの一つの推論操作につき、同時に保持するセッションの最大数。同じ音声モデルに対する並列な音声合成は、この数まで同時に推論を行える。0を指定すると1として扱う。
+    warm_up_on_load
+        音声モデルの読み込み時に :func:`warm_up` を行うかどうか。ウォームアップに失敗した場合、読み込みは取り消される。
+    signature_policy
+        音声モデルの署名に対する方針。方針によらず、署名のある音声モデルは常に検証され、内容が署名と合わない場合は読み込みに失敗する。
+    trusted_public_keys
+        信頼する署名者の、Ed25519の公開鍵(32バイト)。 ``signature_policy`` が :attr:`SignaturePolicy.STRICT` の場合に使われる。
     """
 
     def __init__(
@@ -82,6 +241,14 @@ class Synthesizer:
             AccelerationMode, Literal["AUTO", "CPU", "GPU"]
         ] = AccelerationMode.AUTO,
         cpu_num_threads: int = 0,
+        gpu_device_index: int = 0,
+        gpu_memory_limit: int = 0,
+        max_sessions_per_operation: int = 0,
+        warm_up_on_load: bool = False,
+        signature_policy: Union[
+            SignaturePolicy, Literal["PERMISSIVE", "STRICT"]
+        ] = SignaturePolicy.PERMISSIVE,
+        trusted_public_keys: List[bytes] = [],
     ) -> None: ...
     def __repr__(self) -> str: ...
     def __enter__(self) -> "Synthesizer": ...
@@ -92,7 +259,82 @@ class Synthesizer:
         ...
     @property
     def metas(self) -> List[SpeakerMeta]:
-        """メタ情報。"""
+        """
+        メタ情報。
+
+        スタイルの種類で絞り込むには、各スタイルの ``type`` を見る。
+        """
+        ...
+    def resolve_style_id(self, speaker_uuid: str, style_name: str) -> StyleId:
+        """
+        今読み込んでいる音声モデルのメタ情報から、話者のUUIDとスタイル名に対応するスタイルIDを得る。
+
+        スタイル名は完全に一致する必要がある。
+
+        Parameters
+        ----------
+        speaker_uuid
+            話者のUUID。
+        style_name
+            スタイル名。
+
+        Returns
+        -------
+        スタイルID。
+
+        Raises
+        ------
+        SpeakerNotFoundError
+            話者が見つからなかった場合。
+        StyleNotFoundError
+            スタイルが見つからなかった場合。エラーメッセージには、その話者のスタイル名が近い順に候補として挙げられる。
+        """
+        ...
+    def resolve_style_key(self, style_id: Union[StyleId, int]) -> StyleKey:
+        """
+        今読み込んでいる音声モデルのメタ情報から、スタイルIDに対応する話者のUUIDとスタイル名を得る。
+
+        Parameters
+        ----------
+        style_id
+            スタイルID。
+
+        Returns
+        -------
+        話者のUUIDとスタイル名。
+        """
+        ...
+    def search_styles(self, query: str) -> List[StyleCandidate]:
+        """
+        今読み込んでいる音声モデルのメタ情報から、話者名とスタイル名でスタイルをあいまいに検索する。
+
+        ``query`` は空白で区切られた語の列として扱われ、それぞれの語が話者名かスタイル名のいずれかに近いスタイルが、近い順に返される。
+
+        Parameters
+        ----------
+        query
+            検索語。
+
+        Returns
+        -------
+        検索結果。
+        """
+        ...
+    def speaker_info(self, speaker_uuid: str) -> SpeakerInfo:
+        """
+        今読み込んでいる音声モデルから、話者の追加情報を読む。
+
+        話者のスタイルが複数の音声モデルにまたがる場合は、それらから読んだものをまとめて返す。
+
+        Parameters
+        ----------
+        speaker_uuid
+            話者のUUID。
+
+        Returns
+        -------
+        話者の追加情報。
+        """
         ...
     def load_voice_model(self, model: VoiceModel) -> None:
         """
@@ -104,6 +346,22 @@ class Synthesizer:
             読み込むモデルのスタイルID。
         """
         ...
+    def load_voice_model_by_style_id(
+        self, model_set: VoiceModelSet, style_id: Union[StyleId, int]
+    ) -> None:
+        """
+        音声モデルの集合から、 ``style_id`` のスタイルを含む音声モデルを読み込む。
+
+        そのスタイルが既に読み込まれている場合は何もしない。他のスレッドが同時に同じ音声モデルを読み込んだ場合も同様に扱う。
+
+        Parameters
+        ----------
+        model_set
+            音声モデルの集合。
+        style_id
+            スタイルID。
+        """
+        ...
     def unload_voice_model(self, voice_model_id: Union[VoiceModelId, str]) -> None:
         """
         音声モデルの読み込みを解除する。
@@ -114,6 +372,25 @@ class Synthesizer:
             音声モデルID。
         """
         ...
+    def warm_up(self, voice_model_id: Union[VoiceModelId, str]) -> None:
+        """
+        音声モデルのウォームアップを行う。
+
+        音声モデルのそれぞれの推論を代表的なダミーの入力で一度ずつ実行し、読み込み直後の音声合成の遅延を前もって済ませる。併せて、推論結果が期待される長さを持ち、かつ有限の値のみから成るかを検査する。
+
+        ここでの推論は統計には記録されない。
+
+        Parameters
+        ----------
+        voice_model_id
+            音声モデルID。
+
+        Raises
+        ------
+        WarmUpFailedError
+            推論に失敗したとき、または推論結果が不正だったとき。
+        """
+        ...
     def is_loaded_voice_model(self, voice_model_id: Union[VoiceModelId, str]) -> bool:
         """
         指定したvoice_model_idのモデルが読み込まれているか判定する。
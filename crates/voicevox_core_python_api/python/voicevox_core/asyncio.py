@@ -1,4 +1,4 @@
 # pyright: reportMissingModuleSource=false
-from ._rust.asyncio import OpenJtalk, Synthesizer, UserDict, VoiceModel
+from ._rust.asyncio import OpenJtalk, Synthesizer, UserDict, VoiceModel, VoiceModelSet
 
-__all__ = ["OpenJtalk", "Synthesizer", "UserDict", "VoiceModel"]
+__all__ = ["OpenJtalk", "Synthesizer", "UserDict", "VoiceModel", "VoiceModelSet"]
@@ -5,21 +5,24 @@ use easy_ext::ext;
 use pyo3::{
     exceptions::{PyException, PyValueError},
     types::PyList,
-    FromPyObject as _, PyAny, PyObject, PyResult, Python, ToPyObject,
+    FromPyObject as _, PyAny, PyErr, PyObject, PyResult, Python, ToPyObject,
 };
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::json;
 use uuid::Uuid;
 use voicevox_core::{
-    AccelerationMode, AccentPhraseModel, StyleId, UserDictWordType, VoiceModelMeta,
+    AccelerationMode, AccentPhraseModel, SignaturePolicy, StyleCandidate, StyleId, StyleKey,
+    UserDictWordType, VoiceModelConflict, VoiceModelMeta,
 };
 
 use crate::{
-    ExtractFullContextLabelError, GetSupportedDevicesError, GpuSupportError, InferenceFailedError,
-    InvalidModelDataError, InvalidWordError, LoadUserDictError, ModelAlreadyLoadedError,
-    ModelNotFoundError, NotLoadedOpenjtalkDictError, OpenZipFileError, ParseKanaError,
-    ReadZipEntryError, SaveUserDictError, StyleAlreadyLoadedError, StyleNotFoundError,
-    UseUserDictError, WordNotFoundError,
+    ExtractFullContextLabelError, GetSupportedDevicesError, GpuDeviceNotFoundError,
+    GpuSupportError, InferenceFailedError, InvalidModelDataError, InvalidWordError,
+    LoadUserDictError, ModelAlreadyLoadedError, ModelNotFoundError, NotLoadedOpenjtalkDictError,
+    OpenZipFileError, OperationNotSupportedError, PackVvmError, ParseKanaError, ReadModelDirError,
+    ReadZipEntryError, SaveUserDictError, SpeakerNotFoundError, StyleAlreadyLoadedError,
+    StyleNotFoundError, UnsupportedManifestVersionError, UntrustedVoiceModelError,
+    UseUserDictError, WarmUpFailedError, WordNotFoundError, WorkerCrashedError,
 };
 
 pub(crate) fn from_acceleration_mode(ob: &PyAny) -> PyResult<AccelerationMode> {
@@ -39,6 +42,35 @@ pub(crate) fn from_acceleration_mode(ob: &PyAny) -> PyResult<AccelerationMode> {
     }
 }
 
+pub(crate) fn from_signature_policy(ob: &PyAny) -> PyResult<SignaturePolicy> {
+    let py = ob.py();
+
+    let class = py.import("voicevox_core")?.getattr("SignaturePolicy")?;
+    let policy = class.get_item(ob)?;
+
+    if policy.eq(class.getattr("PERMISSIVE")?)? {
+        Ok(SignaturePolicy::Permissive)
+    } else if policy.eq(class.getattr("STRICT")?)? {
+        Ok(SignaturePolicy::Strict)
+    } else {
+        unreachable!("{} should be one of {{PERMISSIVE, STRICT}}", policy.repr()?,);
+    }
+}
+
+pub(crate) fn from_public_keys(ob: &PyAny) -> PyResult<Vec<[u8; 32]>> {
+    ob.iter()?
+        .map(|key| {
+            let key = key?.extract::<&[u8]>()?;
+            <[u8; 32]>::try_from(key).map_err(|_| {
+                PyValueError::new_err(format!(
+                    "公開鍵は32バイトでなければなりません (actual: {} bytes)",
+                    key.len(),
+                ))
+            })
+        })
+        .collect()
+}
+
 // FIXME: `UserDict`についてはこれではなく、`PathBuf::extract`を直接使うようにする
 pub(crate) fn from_utf8_path(ob: &PyAny) -> PyResult<Utf8PathBuf> {
     PathBuf::extract(ob)?
@@ -74,6 +106,36 @@ pub(crate) fn to_pydantic_voice_model_meta<'py>(
         .collect::<PyResult<Vec<_>>>()
 }
 
+pub(crate) fn to_pydantic_style_key<'py>(
+    style_key: &StyleKey,
+    py: Python<'py>,
+) -> PyResult<&'py PyAny> {
+    let class = py.import("voicevox_core")?.getattr("StyleKey")?;
+    to_pydantic_dataclass(style_key, class)
+}
+
+pub(crate) fn to_pydantic_style_candidates<'py>(
+    candidates: &[StyleCandidate],
+    py: Python<'py>,
+) -> PyResult<Vec<&'py PyAny>> {
+    let class = py.import("voicevox_core")?.getattr("StyleCandidate")?;
+    candidates
+        .iter()
+        .map(|candidate| to_pydantic_dataclass(candidate, class))
+        .collect()
+}
+
+pub(crate) fn to_pydantic_voice_model_conflicts<'py>(
+    conflicts: &[VoiceModelConflict],
+    py: Python<'py>,
+) -> PyResult<Vec<&'py PyAny>> {
+    let class = py.import("voicevox_core")?.getattr("VoiceModelConflict")?;
+    conflicts
+        .iter()
+        .map(|conflict| to_pydantic_dataclass(conflict, class))
+        .collect()
+}
+
 pub(crate) fn to_pydantic_dataclass(x: impl Serialize, class: &PyAny) -> PyResult<&PyAny> {
     let py = class.py();
 
@@ -183,49 +245,60 @@ pub(crate) fn to_rust_word_type(word_type: &PyAny) -> PyResult<UserDictWordType>
 #[ext(VoicevoxCoreResultExt)]
 pub(crate) impl<T> voicevox_core::Result<T> {
     fn into_py_result(self, py: Python<'_>) -> PyResult<T> {
-        use voicevox_core::ErrorKind;
-
-        self.map_err(|err| {
-            let msg = err.to_string();
-            let top = match err.kind() {
-                ErrorKind::NotLoadedOpenjtalkDict => NotLoadedOpenjtalkDictError::new_err(msg),
-                ErrorKind::GpuSupport => GpuSupportError::new_err(msg),
-                ErrorKind::OpenZipFile => OpenZipFileError::new_err(msg),
-                ErrorKind::ReadZipEntry => ReadZipEntryError::new_err(msg),
-                ErrorKind::ModelAlreadyLoaded => ModelAlreadyLoadedError::new_err(msg),
-                ErrorKind::StyleAlreadyLoaded => StyleAlreadyLoadedError::new_err(msg),
-                ErrorKind::InvalidModelData => InvalidModelDataError::new_err(msg),
-                ErrorKind::GetSupportedDevices => GetSupportedDevicesError::new_err(msg),
-                ErrorKind::StyleNotFound => StyleNotFoundError::new_err(msg),
-                ErrorKind::ModelNotFound => ModelNotFoundError::new_err(msg),
-                ErrorKind::InferenceFailed => InferenceFailedError::new_err(msg),
-                ErrorKind::ExtractFullContextLabel => ExtractFullContextLabelError::new_err(msg),
-                ErrorKind::ParseKana => ParseKanaError::new_err(msg),
-                ErrorKind::LoadUserDict => LoadUserDictError::new_err(msg),
-                ErrorKind::SaveUserDict => SaveUserDictError::new_err(msg),
-                ErrorKind::WordNotFound => WordNotFoundError::new_err(msg),
-                ErrorKind::UseUserDict => UseUserDictError::new_err(msg),
-                ErrorKind::InvalidWord => InvalidWordError::new_err(msg),
-            };
-
-            [top]
-                .into_iter()
-                .chain(
-                    iter::successors(err.source(), |&source| source.source())
-                        .map(|source| PyException::new_err(source.to_string())),
-                )
-                .collect::<Vec<_>>()
-                .into_iter()
-                .rev()
-                .reduce(|prev, source| {
-                    source.set_cause(py, Some(prev));
-                    source
-                })
-                .expect("should not be empty")
-        })
+        self.map_err(|err| to_py_err(&err, py))
     }
 }
 
+pub(crate) fn to_py_err(err: &voicevox_core::Error, py: Python<'_>) -> PyErr {
+    use voicevox_core::ErrorKind;
+
+    let msg = err.to_string();
+    let top = match err.kind() {
+        ErrorKind::NotLoadedOpenjtalkDict => NotLoadedOpenjtalkDictError::new_err(msg),
+        ErrorKind::GpuSupport => GpuSupportError::new_err(msg),
+        ErrorKind::GpuDeviceNotFound => GpuDeviceNotFoundError::new_err(msg),
+        ErrorKind::OpenZipFile => OpenZipFileError::new_err(msg),
+        ErrorKind::ReadZipEntry => ReadZipEntryError::new_err(msg),
+        ErrorKind::ModelAlreadyLoaded => ModelAlreadyLoadedError::new_err(msg),
+        ErrorKind::StyleAlreadyLoaded => StyleAlreadyLoadedError::new_err(msg),
+        ErrorKind::InvalidModelData => InvalidModelDataError::new_err(msg),
+        ErrorKind::UnsupportedManifestVersion => UnsupportedManifestVersionError::new_err(msg),
+        ErrorKind::UntrustedVoiceModel => UntrustedVoiceModelError::new_err(msg),
+        ErrorKind::ReadModelDir => ReadModelDirError::new_err(msg),
+        ErrorKind::GetSupportedDevices => GetSupportedDevicesError::new_err(msg),
+        ErrorKind::StyleNotFound => StyleNotFoundError::new_err(msg),
+        ErrorKind::ModelNotFound => ModelNotFoundError::new_err(msg),
+        ErrorKind::SpeakerNotFound => SpeakerNotFoundError::new_err(msg),
+        ErrorKind::OperationNotSupported => OperationNotSupportedError::new_err(msg),
+        ErrorKind::InferenceFailed => InferenceFailedError::new_err(msg),
+        ErrorKind::WarmUpFailed => WarmUpFailedError::new_err(msg),
+        ErrorKind::ExtractFullContextLabel => ExtractFullContextLabelError::new_err(msg),
+        ErrorKind::ParseKana => ParseKanaError::new_err(msg),
+        ErrorKind::LoadUserDict => LoadUserDictError::new_err(msg),
+        ErrorKind::SaveUserDict => SaveUserDictError::new_err(msg),
+        ErrorKind::WordNotFound => WordNotFoundError::new_err(msg),
+        ErrorKind::UseUserDict => UseUserDictError::new_err(msg),
+        ErrorKind::InvalidWord => InvalidWordError::new_err(msg),
+        ErrorKind::WorkerCrashed => WorkerCrashedError::new_err(msg),
+        ErrorKind::PackVvm => PackVvmError::new_err(msg),
+    };
+
+    [top]
+        .into_iter()
+        .chain(
+            iter::successors(err.source(), |&source| source.source())
+                .map(|source| PyException::new_err(source.to_string())),
+        )
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .reduce(|prev, source| {
+            source.set_cause(py, Some(prev));
+            source
+        })
+        .expect("should not be empty")
+}
+
 #[ext]
 impl<T> std::result::Result<T, uuid::Error> {
     fn into_py_value_result(self) -> PyResult<T> {
@@ -28,6 +28,7 @@ fn rust(py: Python<'_>, module: &PyModule) -> PyResult<()> {
     blocking_module.add_class::<self::blocking::Synthesizer>()?;
     blocking_module.add_class::<self::blocking::OpenJtalk>()?;
     blocking_module.add_class::<self::blocking::VoiceModel>()?;
+    blocking_module.add_class::<self::blocking::VoiceModelSet>()?;
     blocking_module.add_class::<self::blocking::UserDict>()?;
     module.add_and_register_submodule(blocking_module)?;
 
@@ -35,6 +36,7 @@ fn rust(py: Python<'_>, module: &PyModule) -> PyResult<()> {
     asyncio_module.add_class::<self::asyncio::Synthesizer>()?;
     asyncio_module.add_class::<self::asyncio::OpenJtalk>()?;
     asyncio_module.add_class::<self::asyncio::VoiceModel>()?;
+    asyncio_module.add_class::<self::asyncio::VoiceModelSet>()?;
     asyncio_module.add_class::<self::asyncio::UserDict>()?;
     module.add_and_register_submodule(asyncio_module)
 }
@@ -67,15 +69,22 @@ macro_rules! exceptions {
 exceptions! {
     NotLoadedOpenjtalkDictError: PyException;
     GpuSupportError: PyException;
+    GpuDeviceNotFoundError: PyException;
     OpenZipFileError: PyException;
     ReadZipEntryError: PyException;
     ModelAlreadyLoadedError: PyException;
     StyleAlreadyLoadedError: PyException;
     InvalidModelDataError: PyException;
+    UnsupportedManifestVersionError: PyException;
+    UntrustedVoiceModelError: PyException;
+    ReadModelDirError: PyException;
     GetSupportedDevicesError: PyException;
     StyleNotFoundError: PyKeyError;
     ModelNotFoundError: PyKeyError;
+    SpeakerNotFoundError: PyKeyError;
+    OperationNotSupportedError: PyException;
     InferenceFailedError: PyException;
+    WarmUpFailedError: PyException;
     ExtractFullContextLabelError: PyException;
     ParseKanaError: PyValueError;
     LoadUserDictError: PyException;
@@ -83,6 +92,8 @@ exceptions! {
     WordNotFoundError: PyKeyError;
     UseUserDictError: PyException;
     InvalidWordError: PyValueError;
+    WorkerCrashedError: PyException;
+    PackVvmError: PyException;
 }
 
 #[pyfunction]
@@ -158,8 +169,8 @@ mod blocking {
     };
     use uuid::Uuid;
     use voicevox_core::{
-        AccelerationMode, AudioQueryModel, InitializeOptions, StyleId, SynthesisOptions,
-        TtsOptions, UserDictWord, VoiceModelId,
+        AccelerationMode, AudioQueryModel, InitializeOptions, SignaturePolicy, StyleId,
+        StyleResolver as _, SynthesisOptions, TtsOptions, UserDictWord, VoiceModelId,
     };
 
     use crate::{convert::VoicevoxCoreResultExt as _, Closable};
@@ -178,6 +189,13 @@ mod blocking {
             Ok(Self { model })
         }
 
+        #[staticmethod]
+        fn from_bytes(py: Python<'_>, bytes: &[u8]) -> PyResult<Self> {
+            let model =
+                voicevox_core::blocking::VoiceModel::from_bytes(bytes).into_py_result(py)?;
+            Ok(Self { model })
+        }
+
         #[getter]
         fn id(&self) -> &str {
             self.model.id().raw_voice_model_id()
@@ -187,6 +205,97 @@ mod blocking {
         fn metas<'py>(&self, py: Python<'py>) -> Vec<&'py PyAny> {
             crate::convert::to_pydantic_voice_model_meta(self.model.metas(), py).unwrap()
         }
+
+        fn resolve_style_id(
+            &self,
+            speaker_uuid: &str,
+            style_name: &str,
+            py: Python<'_>,
+        ) -> PyResult<u32> {
+            self.model
+                .metas()
+                .resolve_style_id(speaker_uuid, style_name)
+                .map(StyleId::raw_id)
+                .into_py_result(py)
+        }
+
+        fn resolve_style_key<'py>(&self, style_id: u32, py: Python<'py>) -> PyResult<&'py PyAny> {
+            let style_key = self
+                .model
+                .metas()
+                .resolve_style_key(StyleId::new(style_id))
+                .into_py_result(py)?;
+            crate::convert::to_pydantic_style_key(&style_key, py)
+        }
+
+        fn search_styles<'py>(&self, query: &str, py: Python<'py>) -> PyResult<Vec<&'py PyAny>> {
+            crate::convert::to_pydantic_style_candidates(
+                &self.model.metas().search_styles(query),
+                py,
+            )
+        }
+
+        fn speaker_info<'py>(&self, speaker_uuid: &str, py: Python<'py>) -> PyResult<&'py PyAny> {
+            let speaker_info = self.model.speaker_info(speaker_uuid).into_py_result(py)?;
+            let class = py.import("voicevox_core")?.getattr("SpeakerInfo")?;
+            crate::convert::to_pydantic_dataclass(speaker_info, class)
+        }
+    }
+
+    #[pyclass]
+    #[derive(Clone)]
+    pub(crate) struct VoiceModelSet {
+        model_set: Arc<voicevox_core::blocking::VoiceModelSet>,
+    }
+
+    #[pymethods]
+    impl VoiceModelSet {
+        #[staticmethod]
+        fn from_dirs(py: Python<'_>, dirs: Vec<PathBuf>) -> PyResult<Self> {
+            let model_set =
+                voicevox_core::blocking::VoiceModelSet::from_dirs(dirs).into_py_result(py)?;
+            Ok(Self {
+                model_set: model_set.into(),
+            })
+        }
+
+        #[getter]
+        fn metas<'py>(&self, py: Python<'py>) -> Vec<&'py PyAny> {
+            crate::convert::to_pydantic_voice_model_meta(self.model_set.metas(), py).unwrap()
+        }
+
+        #[getter]
+        fn conflicts<'py>(&self, py: Python<'py>) -> PyResult<Vec<&'py PyAny>> {
+            crate::convert::to_pydantic_voice_model_conflicts(self.model_set.conflicts(), py)
+        }
+
+        #[getter]
+        fn errors(&self, py: Python<'_>) -> Vec<PyObject> {
+            self.model_set
+                .errors()
+                .iter()
+                .map(|err| crate::convert::to_py_err(err, py).into_value(py).into())
+                .collect()
+        }
+
+        fn voice_models(&self) -> Vec<(PathBuf, VoiceModel)> {
+            self.model_set
+                .voice_models()
+                .map(|(path, model)| {
+                    let model = model.clone();
+                    (path.to_owned(), VoiceModel { model })
+                })
+                .collect()
+        }
+
+        fn voice_model_by_style_id(&self, style_id: u32, py: Python<'_>) -> PyResult<VoiceModel> {
+            let model = self
+                .model_set
+                .voice_model_by_style_id(StyleId::new(style_id))
+                .into_py_result(py)?
+                .clone();
+            Ok(VoiceModel { model })
+        }
     }
 
     #[pyclass]
@@ -229,12 +338,27 @@ mod blocking {
             open_jtalk,
             acceleration_mode = InitializeOptions::default().acceleration_mode,
             cpu_num_threads = InitializeOptions::default().cpu_num_threads,
+            gpu_device_index = InitializeOptions::default().gpu_device_index,
+            gpu_memory_limit = InitializeOptions::default().gpu_memory_limit,
+            max_sessions_per_operation = InitializeOptions::default().max_sessions_per_operation,
+            warm_up_on_load = InitializeOptions::default().warm_up_on_load,
+            signature_policy = InitializeOptions::default().signature_policy,
+            trusted_public_keys = InitializeOptions::default().trusted_public_keys,
         ))]
         fn new(
             open_jtalk: OpenJtalk,
             #[pyo3(from_py_with = "crate::convert::from_acceleration_mode")]
             acceleration_mode: AccelerationMode,
             cpu_num_threads: u16,
+            gpu_device_index: u16,
+            gpu_memory_limit: usize,
+            max_sessions_per_operation: usize,
+            warm_up_on_load: bool,
+            #[pyo3(from_py_with = "crate::convert::from_signature_policy")]
+            signature_policy: SignaturePolicy,
+            #[pyo3(from_py_with = "crate::convert::from_public_keys")] trusted_public_keys: Vec<
+                [u8; 32],
+            >,
             py: Python<'_>,
         ) -> PyResult<Self> {
             let inner = voicevox_core::blocking::Synthesizer::new(
@@ -242,6 +366,13 @@ mod blocking {
                 &InitializeOptions {
                     acceleration_mode,
                     cpu_num_threads,
+                    gpu_device_index,
+                    gpu_memory_limit,
+                    max_sessions_per_operation,
+                    warm_up_on_load,
+                    signature_policy,
+                    trusted_public_keys,
+                    ..Default::default()
                 },
             )
             .into_py_result(py)?;
@@ -280,6 +411,43 @@ mod blocking {
             crate::convert::to_pydantic_voice_model_meta(&synthesizer.metas(), py)
         }
 
+        fn resolve_style_id(
+            &self,
+            speaker_uuid: &str,
+            style_name: &str,
+            py: Python<'_>,
+        ) -> PyResult<u32> {
+            self.synthesizer
+                .get()?
+                .resolve_style_id(speaker_uuid, style_name)
+                .map(StyleId::raw_id)
+                .into_py_result(py)
+        }
+
+        fn resolve_style_key<'py>(&self, style_id: u32, py: Python<'py>) -> PyResult<&'py PyAny> {
+            let style_key = self
+                .synthesizer
+                .get()?
+                .resolve_style_key(StyleId::new(style_id))
+                .into_py_result(py)?;
+            crate::convert::to_pydantic_style_key(&style_key, py)
+        }
+
+        fn search_styles<'py>(&self, query: &str, py: Python<'py>) -> PyResult<Vec<&'py PyAny>> {
+            let candidates = self.synthesizer.get()?.search_styles(query);
+            crate::convert::to_pydantic_style_candidates(&candidates, py)
+        }
+
+        fn speaker_info<'py>(&self, speaker_uuid: &str, py: Python<'py>) -> PyResult<&'py PyAny> {
+            let speaker_info = self
+                .synthesizer
+                .get()?
+                .speaker_info(speaker_uuid)
+                .into_py_result(py)?;
+            let class = py.import("voicevox_core")?.getattr("SpeakerInfo")?;
+            crate::convert::to_pydantic_dataclass(speaker_info, class)
+        }
+
         fn load_voice_model(&mut self, model: &PyAny, py: Python<'_>) -> PyResult<()> {
             let model: VoiceModel = model.extract()?;
             self.synthesizer
@@ -288,6 +456,19 @@ mod blocking {
                 .into_py_result(py)
         }
 
+        fn load_voice_model_by_style_id(
+            &mut self,
+            model_set: &PyAny,
+            style_id: u32,
+            py: Python<'_>,
+        ) -> PyResult<()> {
+            let model_set: VoiceModelSet = model_set.extract()?;
+            self.synthesizer
+                .get()?
+                .load_voice_model_by_style_id(&model_set.model_set, StyleId::new(style_id))
+                .into_py_result(py)
+        }
+
         fn unload_voice_model(&mut self, voice_model_id: &str, py: Python<'_>) -> PyResult<()> {
             self.synthesizer
                 .get()?
@@ -295,6 +476,13 @@ mod blocking {
                 .into_py_result(py)
         }
 
+        fn warm_up(&self, voice_model_id: &str, py: Python<'_>) -> PyResult<()> {
+            self.synthesizer
+                .get()?
+                .warm_up(&VoiceModelId::new(voice_model_id.to_string()))
+                .into_py_result(py)
+        }
+
         // C APIの挙動と一貫性を持たせる。
         fn is_loaded_voice_model(&self, voice_model_id: &PyString) -> PyResult<bool> {
             let Ok(voice_model_id) = voice_model_id.to_str() else {
@@ -583,8 +771,8 @@ mod asyncio {
     };
     use uuid::Uuid;
     use voicevox_core::{
-        AccelerationMode, AudioQueryModel, InitializeOptions, StyleId, SynthesisOptions,
-        TtsOptions, UserDictWord, VoiceModelId,
+        AccelerationMode, AudioQueryModel, InitializeOptions, SignaturePolicy, StyleId,
+        StyleResolver as _, SynthesisOptions, TtsOptions, UserDictWord, VoiceModelId,
     };
 
     use crate::{convert::VoicevoxCoreResultExt as _, Closable};
@@ -606,6 +794,16 @@ mod asyncio {
             })
         }
 
+        #[staticmethod]
+        fn from_bytes<'py>(py: Python<'py>, bytes: &[u8]) -> PyResult<&'py PyAny> {
+            let bytes = Arc::<[u8]>::from(bytes);
+            pyo3_asyncio::tokio::future_into_py(py, async move {
+                let model = voicevox_core::tokio::VoiceModel::from_bytes(bytes).await;
+                let model = Python::with_gil(|py| model.into_py_result(py))?;
+                Ok(Self { model })
+            })
+        }
+
         #[getter]
         fn id(&self) -> &str {
             self.model.id().raw_voice_model_id()
@@ -615,6 +813,112 @@ mod asyncio {
         fn metas<'py>(&self, py: Python<'py>) -> Vec<&'py PyAny> {
             crate::convert::to_pydantic_voice_model_meta(self.model.metas(), py).unwrap()
         }
+
+        fn resolve_style_id(
+            &self,
+            speaker_uuid: &str,
+            style_name: &str,
+            py: Python<'_>,
+        ) -> PyResult<u32> {
+            self.model
+                .metas()
+                .resolve_style_id(speaker_uuid, style_name)
+                .map(StyleId::raw_id)
+                .into_py_result(py)
+        }
+
+        fn resolve_style_key<'py>(&self, style_id: u32, py: Python<'py>) -> PyResult<&'py PyAny> {
+            let style_key = self
+                .model
+                .metas()
+                .resolve_style_key(StyleId::new(style_id))
+                .into_py_result(py)?;
+            crate::convert::to_pydantic_style_key(&style_key, py)
+        }
+
+        fn search_styles<'py>(&self, query: &str, py: Python<'py>) -> PyResult<Vec<&'py PyAny>> {
+            crate::convert::to_pydantic_style_candidates(
+                &self.model.metas().search_styles(query),
+                py,
+            )
+        }
+
+        fn speaker_info<'py>(&self, speaker_uuid: &str, py: Python<'py>) -> PyResult<&'py PyAny> {
+            let model = self.model.clone();
+            let speaker_uuid = speaker_uuid.to_owned();
+            pyo3_asyncio::tokio::future_into_py_with_locals(
+                py,
+                pyo3_asyncio::tokio::get_current_locals(py)?,
+                async move {
+                    let speaker_info = model.speaker_info(&speaker_uuid).await;
+
+                    Python::with_gil(|py| {
+                        let speaker_info = speaker_info.into_py_result(py)?;
+                        let class = py.import("voicevox_core")?.getattr("SpeakerInfo")?;
+                        let ret = crate::convert::to_pydantic_dataclass(speaker_info, class)?;
+                        Ok(ret.to_object(py))
+                    })
+                },
+            )
+        }
+    }
+
+    #[pyclass]
+    #[derive(Clone)]
+    pub(crate) struct VoiceModelSet {
+        model_set: Arc<voicevox_core::tokio::VoiceModelSet>,
+    }
+
+    #[pymethods]
+    impl VoiceModelSet {
+        #[staticmethod]
+        fn from_dirs(py: Python<'_>, dirs: Vec<PathBuf>) -> PyResult<&PyAny> {
+            pyo3_asyncio::tokio::future_into_py(py, async move {
+                let model_set = voicevox_core::tokio::VoiceModelSet::from_dirs(dirs).await;
+                let model_set = Python::with_gil(|py| model_set.into_py_result(py))?;
+                Ok(Self {
+                    model_set: model_set.into(),
+                })
+            })
+        }
+
+        #[getter]
+        fn metas<'py>(&self, py: Python<'py>) -> Vec<&'py PyAny> {
+            crate::convert::to_pydantic_voice_model_meta(self.model_set.metas(), py).unwrap()
+        }
+
+        #[getter]
+        fn conflicts<'py>(&self, py: Python<'py>) -> PyResult<Vec<&'py PyAny>> {
+            crate::convert::to_pydantic_voice_model_conflicts(self.model_set.conflicts(), py)
+        }
+
+        #[getter]
+        fn errors(&self, py: Python<'_>) -> Vec<PyObject> {
+            self.model_set
+                .errors()
+                .iter()
+                .map(|err| crate::convert::to_py_err(err, py).into_value(py).into())
+                .collect()
+        }
+
+        fn voice_models(&self) -> Vec<(PathBuf, VoiceModel)> {
+            self.model_set
+                .voice_models()
+                .map(|(path, model)| {
+                    let model = model.clone();
+                    (path.to_owned(), VoiceModel { model })
+                })
+                .collect()
+        }
+
+        fn voice_model_by_style_id(&self, style_id: u32, py: Python<'_>) -> PyResult<VoiceModel> {
+            let model = self
+                .model_set
+                .voice_model_by_style_id(StyleId::new(style_id))
+                .into_py_result(py)?
+                .clone();
+            Ok(VoiceModel { model })
+        }
     }
 
     #[pyclass]
@@ -662,18 +966,40 @@ mod asyncio {
             open_jtalk,
             acceleration_mode = InitializeOptions::default().acceleration_mode,
             cpu_num_threads = InitializeOptions::default().cpu_num_threads,
+            gpu_device_index = InitializeOptions::default().gpu_device_index,
+            gpu_memory_limit = InitializeOptions::default().gpu_memory_limit,
+            max_sessions_per_operation = InitializeOptions::default().max_sessions_per_operation,
+            warm_up_on_load = InitializeOptions::default().warm_up_on_load,
+            signature_policy = InitializeOptions::default().signature_policy,
+            trusted_public_keys = InitializeOptions::default().trusted_public_keys,
         ))]
         fn new(
             open_jtalk: OpenJtalk,
             #[pyo3(from_py_with = "crate::convert::from_acceleration_mode")]
             acceleration_mode: AccelerationMode,
             cpu_num_threads: u16,
+            gpu_device_index: u16,
+            gpu_memory_limit: usize,
+            max_sessions_per_operation: usize,
+            warm_up_on_load: bool,
+            #[pyo3(from_py_with = "crate::convert::from_signature_policy")]
+            signature_policy: SignaturePolicy,
+            #[pyo3(from_py_with = "crate::convert::from_public_keys")] trusted_public_keys: Vec<
+                [u8; 32],
+            >,
         ) -> PyResult<Self> {
             let synthesizer = voicevox_core::tokio::Synthesizer::new(
                 open_jtalk.open_jtalk.clone(),
                 &InitializeOptions {
                     acceleration_mode,
                     cpu_num_threads,
+                    gpu_device_index,
+                    gpu_memory_limit,
+                    max_sessions_per_operation,
+                    warm_up_on_load,
+                    signature_policy,
+                    trusted_public_keys,
+                    ..Default::default()
                 },
             );
             let synthesizer = Python::with_gil(|py| synthesizer.into_py_result(py))?;
@@ -711,6 +1037,52 @@ mod asyncio {
             crate::convert::to_pydantic_voice_model_meta(&synthesizer.metas(), py)
         }
 
+        fn resolve_style_id(
+            &self,
+            speaker_uuid: &str,
+            style_name: &str,
+            py: Python<'_>,
+        ) -> PyResult<u32> {
+            self.synthesizer
+                .get()?
+                .resolve_style_id(speaker_uuid, style_name)
+                .map(StyleId::raw_id)
+                .into_py_result(py)
+        }
+
+        fn resolve_style_key<'py>(&self, style_id: u32, py: Python<'py>) -> PyResult<&'py PyAny> {
+            let style_key = self
+                .synthesizer
+                .get()?
+                .resolve_style_key(StyleId::new(style_id))
+                .into_py_result(py)?;
+            crate::convert::to_pydantic_style_key(&style_key, py)
+        }
+
+        fn search_styles<'py>(&self, query: &str, py: Python<'py>) -> PyResult<Vec<&'py PyAny>> {
+            let candidates = self.synthesizer.get()?.search_styles(query);
+            crate::convert::to_pydantic_style_candidates(&candidates, py)
+        }
+
+        fn speaker_info<'py>(&self, speaker_uuid: &str, py: Python<'py>) -> PyResult<&'py PyAny> {
+            let synthesizer = self.synthesizer.get()?.clone();
+            let speaker_uuid = speaker_uuid.to_owned();
+            pyo3_asyncio::tokio::future_into_py_with_locals(
+                py,
+                pyo3_asyncio::tokio::get_current_locals(py)?,
+                async move {
+                    let speaker_info = synthesizer.speaker_info(&speaker_uuid).await;
+
+                    Python::with_gil(|py| {
+                        let speaker_info = speaker_info.into_py_result(py)?;
+                        let class = py.import("voicevox_core")?.getattr("SpeakerInfo")?;
+                        let ret = crate::convert::to_pydantic_dataclass(speaker_info, class)?;
+                        Ok(ret.to_object(py))
+                    })
+                },
+            )
+        }
+
         fn load_voice_model<'py>(
             &mut self,
             model: &'py PyAny,
@@ -724,6 +1096,22 @@ mod asyncio {
             })
         }
 
+        fn load_voice_model_by_style_id<'py>(
+            &mut self,
+            model_set: &'py PyAny,
+            style_id: u32,
+            py: Python<'py>,
+        ) -> PyResult<&'py PyAny> {
+            let model_set: VoiceModelSet = model_set.extract()?;
+            let synthesizer = self.synthesizer.get()?.clone();
+            pyo3_asyncio::tokio::future_into_py(py, async move {
+                let result = synthesizer
+                    .load_voice_model_by_style_id(&model_set.model_set, StyleId::new(style_id))
+                    .await;
+                Python::with_gil(|py| result.into_py_result(py))
+            })
+        }
+
         fn unload_voice_model(&mut self, voice_model_id: &str, py: Python<'_>) -> PyResult<()> {
             self.synthesizer
                 .get()?
@@ -731,6 +1119,15 @@ mod asyncio {
                 .into_py_result(py)
         }
 
+        fn warm_up<'py>(&self, voice_model_id: &str, py: Python<'py>) -> PyResult<&'py PyAny> {
+            let voice_model_id = VoiceModelId::new(voice_model_id.to_string());
+            let synthesizer = self.synthesizer.get()?.clone();
+            pyo3_asyncio::tokio::future_into_py(py, async move {
+                let result = synthesizer.warm_up(&voice_model_id).await;
+                Python::with_gil(|py| result.into_py_result(py))
+            })
+        }
+
         // C APIの挙動と一貫性を持たせる。
         fn is_loaded_voice_model(&self, voice_model_id: &PyString) -> PyResult<bool> {
             let Ok(voice_model_id) = voice_model_id.to_str() else {
@@ -992,6 +1389,88 @@ mod asyncio {
             )
         }
 
+        fn predict_duration<'py>(
+            &self,
+            phoneme_vector: Vec<i64>,
+            style_id: u32,
+            py: Python<'py>,
+        ) -> PyResult<&'py PyAny> {
+            let style_id = StyleId::new(style_id);
+            let synthesizer = self.synthesizer.get()?.clone();
+            pyo3_asyncio::tokio::future_into_py_with_locals(
+                py,
+                pyo3_asyncio::tokio::get_current_locals(py)?,
+                async move {
+                    let phoneme_length = synthesizer
+                        .predict_duration(&phoneme_vector, style_id)
+                        .await;
+
+                    Python::with_gil(|py| Ok(phoneme_length.into_py_result(py)?.to_object(py)))
+                },
+            )
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn predict_intonation<'py>(
+            &self,
+            length: usize,
+            vowel_phoneme_vector: Vec<i64>,
+            consonant_phoneme_vector: Vec<i64>,
+            start_accent_vector: Vec<i64>,
+            end_accent_vector: Vec<i64>,
+            start_accent_phrase_vector: Vec<i64>,
+            end_accent_phrase_vector: Vec<i64>,
+            style_id: u32,
+            py: Python<'py>,
+        ) -> PyResult<&'py PyAny> {
+            let style_id = StyleId::new(style_id);
+            let synthesizer = self.synthesizer.get()?.clone();
+            pyo3_asyncio::tokio::future_into_py_with_locals(
+                py,
+                pyo3_asyncio::tokio::get_current_locals(py)?,
+                async move {
+                    let f0 = synthesizer
+                        .predict_intonation(
+                            length,
+                            &vowel_phoneme_vector,
+                            &consonant_phoneme_vector,
+                            &start_accent_vector,
+                            &end_accent_vector,
+                            &start_accent_phrase_vector,
+                            &end_accent_phrase_vector,
+                            style_id,
+                        )
+                        .await;
+
+                    Python::with_gil(|py| Ok(f0.into_py_result(py)?.to_object(py)))
+                },
+            )
+        }
+
+        fn decode<'py>(
+            &self,
+            length: usize,
+            phoneme_size: usize,
+            f0: Vec<f32>,
+            phoneme_vector: Vec<f32>,
+            style_id: u32,
+            py: Python<'py>,
+        ) -> PyResult<&'py PyAny> {
+            let style_id = StyleId::new(style_id);
+            let synthesizer = self.synthesizer.get()?.clone();
+            pyo3_asyncio::tokio::future_into_py_with_locals(
+                py,
+                pyo3_asyncio::tokio::get_current_locals(py)?,
+                async move {
+                    let wave = synthesizer
+                        .decode(length, phoneme_size, &f0, &phoneme_vector, style_id)
+                        .await;
+
+                    Python::with_gil(|py| Ok(wave.into_py_result(py)?.to_object(py)))
+                },
+            )
+        }
+
         fn close(&mut self) {
             self.synthesizer.close()
         }
@@ -0,0 +1,152 @@
+use std::{collections::BTreeMap, path::PathBuf, process::ExitCode};
+
+use anyhow::{anyhow, Context as _};
+use clap::Parser as _;
+use ed25519_dalek::{SigningKey, SECRET_KEY_LENGTH};
+use voicevox_core::{__internal::vvm, blocking::VoiceModel, StyleId, VvmPackOptions};
+
+/// VVMを扱うツール。
+#[derive(clap::Parser)]
+enum Args {
+    /// VVMの中身を表示し、検証する
+    ///
+    /// 問題が見つかった場合は非ゼロで終了する。
+    Inspect {
+        /// VVMファイル、もしくは展開されたVVMのディレクトリ
+        path: PathBuf,
+
+        /// 推論操作の`Session`を作っての検証を行わない
+        #[arg(long)]
+        no_sessions: bool,
+    },
+
+    /// ONNXファイルとメタ情報からVVMファイルを作る
+    Pack {
+        /// 書き出すVVMファイル
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// メタ情報のJSONファイル
+        #[arg(long)]
+        metas: PathBuf,
+
+        /// `predict_duration`のモデル
+        #[arg(long)]
+        predict_duration: Option<PathBuf>,
+
+        /// `predict_intonation`のモデル
+        #[arg(long)]
+        predict_intonation: Option<PathBuf>,
+
+        /// `decode`のモデル
+        #[arg(long)]
+        decode: Option<PathBuf>,
+
+        /// スタイルIDとモデル内IDの対応
+        #[arg(
+            long,
+            value_name("STYLE_ID=MODEL_INNER_ID"),
+            value_parser(parse_inner_id)
+        )]
+        inner_id: Vec<(StyleId, u32)>,
+
+        /// 音声モデルID。指定しない場合はすべてのエントリの内容から導出される
+        #[arg(long)]
+        id: Option<String>,
+
+        /// 署名に使う秘密鍵のファイル。中身は32バイトの16進表記
+        #[arg(long)]
+        signing_key: Option<PathBuf>,
+    },
+}
+
+fn main() -> anyhow::Result<ExitCode> {
+    match Args::parse() {
+        Args::Inspect { path, no_sessions } => inspect(path, !no_sessions),
+        Args::Pack {
+            output,
+            metas,
+            predict_duration,
+            predict_intonation,
+            decode,
+            inner_id,
+            id,
+            signing_key,
+        } => {
+            let signing_key = signing_key.map(read_signing_key).transpose()?;
+            let public_key = signing_key
+                .as_ref()
+                .map(|key| hex::encode(key.verifying_key().as_bytes()));
+
+            let model = VoiceModel::pack(
+                &metas,
+                &output,
+                VvmPackOptions {
+                    id,
+                    predict_duration,
+                    predict_intonation,
+                    decode,
+                    style_id_to_model_inner_id: inner_id.into_iter().collect::<BTreeMap<_, _>>(),
+                    signing_key,
+                },
+            )?;
+
+            println!("wrote `{}` (id: {})", output.display(), model.id());
+            if let Some(public_key) = public_key {
+                println!("public key: {public_key}");
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+fn inspect(path: PathBuf, check_sessions: bool) -> anyhow::Result<ExitCode> {
+    let model = VoiceModel::from_path(&path)
+        .with_context(|| format!("could not open `{}`", path.display()))?;
+    let report = vvm::inspect(&model, check_sessions);
+
+    println!("id: {}", model.id());
+    match report.signer {
+        Some(public_key) => println!("signed by: {}", hex::encode(public_key)),
+        None => println!("unsigned"),
+    }
+    for speaker in model.metas() {
+        println!(
+            "speaker: {} (uuid: {}, version: {})",
+            speaker.name(),
+            speaker.speaker_uuid(),
+            speaker.version(),
+        );
+        for style in speaker.styles() {
+            let model_inner_id = report.model_inner_ids[style.id()];
+            println!(
+                "    style {}: {} (type: {}, model inner id: {model_inner_id})",
+                style.id(),
+                style.name(),
+                style.r#type(),
+            );
+        }
+    }
+
+    if report.problems.is_empty() {
+        return Ok(ExitCode::SUCCESS);
+    }
+    for problem in report.problems {
+        eprintln!("error: {:?}", anyhow::Error::from(problem));
+    }
+    Ok(ExitCode::FAILURE)
+}
+
+fn parse_inner_id(s: &str) -> anyhow::Result<(StyleId, u32)> {
+    let (style_id, model_inner_id) = s
+        .split_once('=')
+        .ok_or_else(|| anyhow!("expected `STYLE_ID=MODEL_INNER_ID`"))?;
+    Ok((StyleId::new(style_id.parse()?), model_inner_id.parse()?))
+}
+
+fn read_signing_key(path: PathBuf) -> anyhow::Result<SigningKey> {
+    let key = fs_err::read_to_string(&path)?;
+    let key = <[u8; SECRET_KEY_LENGTH]>::try_from(hex::decode(key.trim())?.as_slice())
+        .with_context(|| format!("`{}` is not a {SECRET_KEY_LENGTH}-byte key", path.display()))?;
+    Ok(SigningKey::from_bytes(&key))
+}